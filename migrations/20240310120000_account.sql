@@ -0,0 +1,9 @@
+create table account (
+	id uuid primary key not null,
+	user_id uuid not null unique references "user"(id),
+	balance bigint not null default 0,
+	credit_limit bigint not null check (credit_limit >= 0),
+	status varchar(255) not null,
+	created_at timestamp not null,
+	updated_at timestamp not null
+);
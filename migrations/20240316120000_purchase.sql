@@ -0,0 +1,11 @@
+create table purchase (
+	id uuid primary key not null,
+	account_id uuid not null references account(id),
+	amount bigint not null check (amount > 0),
+	description varchar(255) not null,
+	merchant_reference varchar(255) not null,
+	purchased_at timestamp not null,
+	created_at timestamp not null
+);
+
+create index purchase_account_id_purchased_at_idx on purchase (account_id, purchased_at);
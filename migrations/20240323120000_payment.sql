@@ -0,0 +1,21 @@
+alter table purchase add column paid_amount bigint not null default 0;
+alter table purchase add constraint purchase_paid_amount_check check (paid_amount >= 0 and paid_amount <= amount);
+
+create table payment (
+	id uuid primary key not null,
+	account_id uuid not null references account(id),
+	amount bigint not null check (amount > 0),
+	method varchar(255) not null,
+	operator varchar(255) not null,
+	carried_credit bigint not null default 0,
+	paid_at timestamp not null
+);
+
+create index payment_account_id_idx on payment (account_id, paid_at);
+
+create table payment_allocation (
+	payment_id uuid not null references payment(id),
+	purchase_id uuid not null references purchase(id),
+	amount bigint not null check (amount > 0),
+	primary key (payment_id, purchase_id)
+);
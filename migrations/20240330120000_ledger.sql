@@ -0,0 +1,80 @@
+create table ledger_transaction (
+	id uuid primary key not null,
+	account_id uuid not null references account(id),
+	kind varchar(255) not null,
+	reference_id uuid,
+	description varchar(255) not null default '',
+	created_at timestamp not null
+);
+
+create index ledger_transaction_account_id_idx on ledger_transaction (account_id, created_at);
+
+create table ledger_entry (
+	transaction_id uuid not null references ledger_transaction(id),
+	line smallint not null,
+	ledger_account varchar(255) not null,
+	direction varchar(6) not null check (direction in ('DEBIT', 'CREDIT')),
+	amount bigint not null check (amount > 0),
+	primary key (transaction_id, line)
+);
+
+-- ledger records are append only: corrections must be posted as adjustments
+create function ledger_reject_changes() returns trigger as $$
+begin
+	raise exception 'ledger records are immutable';
+end;
+$$ language plpgsql;
+
+create trigger ledger_transaction_immutable
+	before update or delete on ledger_transaction
+	for each row execute function ledger_reject_changes();
+
+create trigger ledger_entry_immutable
+	before update or delete on ledger_entry
+	for each row execute function ledger_reject_changes();
+
+-- debits and credits of a transaction must match once it commits
+create function ledger_check_balanced() returns trigger as $$
+begin
+	if (
+		select coalesce(sum(case when direction = 'DEBIT' then amount else -amount end), 0)
+		from ledger_entry
+		where transaction_id = new.transaction_id
+	) <> 0 then
+		raise exception 'ledger transaction % is not balanced', new.transaction_id;
+	end if;
+	return null;
+end;
+$$ language plpgsql;
+
+create constraint trigger ledger_entry_balanced
+	after insert on ledger_entry
+	deferrable initially deferred
+	for each row execute function ledger_check_balanced();
+
+-- backfill the history recorded before the ledger existed
+insert into ledger_transaction (id, account_id, kind, reference_id, description, created_at)
+select gen_random_uuid(), account_id, 'PURCHASE', id, description, purchased_at from purchase;
+
+insert into ledger_transaction (id, account_id, kind, reference_id, description, created_at)
+select gen_random_uuid(), account_id, 'PAYMENT', id, method, paid_at from payment;
+
+insert into ledger_entry (transaction_id, line, ledger_account, direction, amount)
+select t.id, 0, 'RECEIVABLE', 'DEBIT', p.amount
+from ledger_transaction t join purchase p on p.id = t.reference_id
+where t.kind = 'PURCHASE';
+
+insert into ledger_entry (transaction_id, line, ledger_account, direction, amount)
+select t.id, 1, 'SALES', 'CREDIT', p.amount
+from ledger_transaction t join purchase p on p.id = t.reference_id
+where t.kind = 'PURCHASE';
+
+insert into ledger_entry (transaction_id, line, ledger_account, direction, amount)
+select t.id, 0, 'CASH', 'DEBIT', p.amount
+from ledger_transaction t join payment p on p.id = t.reference_id
+where t.kind = 'PAYMENT';
+
+insert into ledger_entry (transaction_id, line, ledger_account, direction, amount)
+select t.id, 1, 'RECEIVABLE', 'CREDIT', p.amount
+from ledger_transaction t join payment p on p.id = t.reference_id
+where t.kind = 'PAYMENT';
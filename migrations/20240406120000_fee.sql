@@ -0,0 +1,18 @@
+alter table purchase add column due_date date;
+update purchase set due_date = (purchased_at + interval '30 days')::date;
+alter table purchase alter column due_date set not null;
+
+create index purchase_overdue_idx on purchase (due_date) where paid_amount < amount;
+
+create table fee (
+	id uuid primary key not null,
+	account_id uuid not null references account(id),
+	purchase_id uuid references purchase(id),
+	kind varchar(255) not null,
+	period varchar(7) not null,
+	amount bigint not null check (amount > 0),
+	idempotency_key varchar(255) not null unique,
+	created_at timestamp not null
+);
+
+create index fee_account_id_idx on fee (account_id, created_at);
@@ -0,0 +1,40 @@
+alter table account add column closing_day smallint not null default 5 check (closing_day between 1 and 28);
+alter table account add column due_day smallint not null default 15 check (due_day between 1 and 28 and due_day <> closing_day);
+
+create table statement (
+	id uuid primary key not null,
+	account_id uuid not null references account(id),
+	period_start date not null,
+	closing_date date not null,
+	due_date date not null,
+	opening_balance bigint not null,
+	closing_balance bigint not null,
+	created_at timestamp not null,
+	unique (account_id, closing_date)
+);
+
+create table statement_line (
+	statement_id uuid not null references statement(id),
+	line integer not null,
+	transaction_id uuid not null references ledger_transaction(id),
+	kind varchar(255) not null,
+	description varchar(255) not null,
+	amount bigint not null,
+	posted_at timestamp not null,
+	primary key (statement_id, line)
+);
+
+-- closed statements are snapshots: they must not change once generated
+create function statement_reject_changes() returns trigger as $$
+begin
+	raise exception 'statements are immutable';
+end;
+$$ language plpgsql;
+
+create trigger statement_immutable
+	before update or delete on statement
+	for each row execute function statement_reject_changes();
+
+create trigger statement_line_immutable
+	before update or delete on statement_line
+	for each row execute function statement_reject_changes();
@@ -0,0 +1,32 @@
+create table installment (
+	id uuid primary key not null,
+	purchase_id uuid not null references purchase(id),
+	account_id uuid not null references account(id),
+	number smallint not null check (number > 0),
+	amount bigint not null check (amount > 0),
+	paid_amount bigint not null default 0,
+	due_date date not null,
+	created_at timestamp not null,
+	check (paid_amount >= 0 and paid_amount <= amount),
+	unique (purchase_id, number)
+);
+
+create index installment_unsettled_idx on installment (account_id, due_date) where paid_amount < amount;
+
+-- purchases made before installments existed are paid in a single installment that
+-- keeps the purchase id, so payment allocations and late fees still point to it
+insert into installment (id, purchase_id, account_id, number, amount, paid_amount, due_date, created_at)
+select id, id, account_id, 1, amount, paid_amount, due_date, created_at from purchase;
+
+drop index purchase_overdue_idx;
+alter table purchase drop column due_date;
+
+alter table payment_allocation add column installment_id uuid references installment(id);
+update payment_allocation set installment_id = purchase_id;
+alter table payment_allocation alter column installment_id set not null;
+alter table payment_allocation drop constraint payment_allocation_pkey;
+alter table payment_allocation add primary key (payment_id, installment_id);
+
+alter table fee add column installment_id uuid references installment(id);
+update fee set installment_id = purchase_id;
+alter table fee drop column purchase_id;
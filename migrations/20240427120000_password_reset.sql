@@ -0,0 +1,10 @@
+create table password_reset (
+	id uuid primary key not null,
+	user_id uuid not null references "user"(id),
+	token_hash varchar(255) not null,
+	expires_at timestamp not null,
+	used_at timestamp,
+	created_at timestamp not null
+);
+
+create index password_reset_user_id_idx on password_reset (user_id);
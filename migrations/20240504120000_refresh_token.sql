@@ -0,0 +1,19 @@
+create table refresh_token (
+	id uuid primary key not null,
+	family_id uuid not null,
+	subject varchar(255) not null,
+	role varchar(255) not null,
+	token_hash varchar(255) not null,
+	expires_at timestamp not null,
+	revoked_at timestamp,
+	created_at timestamp not null
+);
+
+create index refresh_token_family_id_idx on refresh_token (family_id);
+
+-- access tokens revoked before expiring, rows can be dropped once expires_at passes
+create table revoked_token (
+	jti varchar(255) primary key not null,
+	expires_at timestamp not null,
+	revoked_at timestamp not null
+);
@@ -0,0 +1,12 @@
+create table api_key (
+	id uuid primary key not null,
+	prefix varchar(32) not null unique,
+	secret_hash varchar(255) not null,
+	name varchar(255) not null,
+	store_id varchar(255) not null,
+	permissions text[] not null,
+	revoked_at timestamp,
+	created_at timestamp not null
+);
+
+create index api_key_store_id_idx on api_key (store_id);
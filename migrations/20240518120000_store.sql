@@ -0,0 +1,14 @@
+create table store (
+	id uuid primary key not null,
+	name varchar(255) not null,
+	document char(14) not null unique,
+	created_at timestamp not null,
+	updated_at timestamp not null
+);
+
+-- accounts opened before stores existed keep a null store
+alter table account add column store_id uuid references store(id);
+alter table purchase add column store_id uuid references store(id);
+
+create index account_store_id_idx on account (store_id);
+create index purchase_store_id_idx on purchase (store_id);
@@ -0,0 +1,21 @@
+-- customers registered before stores existed keep a null store, only platform staff reach them
+alter table "user" add column store_id uuid references store(id);
+alter table refresh_token add column store_id uuid references store(id);
+
+create index user_store_id_idx on "user" (store_id);
+
+-- every query on customers runs in a transaction setting app.platform and app.store_id,
+-- a connection that did not set them sees no rows at all. The owner of the table is
+-- bound by the policy as well, superusers are not, so the service must not connect as one.
+alter table "user" enable row level security;
+alter table "user" force row level security;
+
+create policy user_tenant_isolation on "user"
+	using (
+		current_setting('app.platform', true) = 'on'
+		or store_id = nullif(current_setting('app.store_id', true), '')::uuid
+	)
+	with check (
+		current_setting('app.platform', true) = 'on'
+		or store_id = nullif(current_setting('app.store_id', true), '')::uuid
+	);
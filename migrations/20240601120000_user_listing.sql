@@ -0,0 +1,9 @@
+-- keyset pagination walks these in both directions, the id breaks ties
+create index user_created_at_id_idx on "user" (created_at, id);
+create index user_name_id_idx on "user" (name, id);
+
+-- prefix search ignoring case
+create index user_lower_name_idx on "user" (lower(name) text_pattern_ops);
+
+create index user_birth_date_idx on "user" (birth_date);
+create index user_status_idx on "user" (status);
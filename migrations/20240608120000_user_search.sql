@@ -0,0 +1,10 @@
+create extension if not exists pg_trgm;
+create extension if not exists unaccent;
+
+-- unaccent is only stable since its dictionary can change, pinning the dictionary makes
+-- it safe to index
+create function immutable_unaccent(text) returns text
+	language sql immutable parallel safe strict
+	as $$ select public.unaccent('public.unaccent'::regdictionary, $1) $$;
+
+create index user_name_trgm_idx on "user" using gin (immutable_unaccent(lower(name)) gin_trgm_ops);
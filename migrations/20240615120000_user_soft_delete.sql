@@ -0,0 +1,5 @@
+alter table "user" add column deleted_at timestamp;
+
+-- deleted customers keep their row, the cpf is only unique among the ones still around
+alter table "user" drop constraint user_document_key;
+create unique index user_document_active_idx on "user" (document) where deleted_at is null;
@@ -0,0 +1,12 @@
+create table user_status_change (
+	id uuid primary key not null,
+	user_id uuid not null references "user"(id),
+	from_status varchar(255) not null,
+	to_status varchar(255) not null,
+	reason varchar(255) not null,
+	note varchar(255),
+	changed_by varchar(255) not null,
+	changed_at timestamp not null
+);
+
+create index user_status_change_user_id_idx on user_status_change (user_id, changed_at);
@@ -0,0 +1,4 @@
+-- stores without rates charge the platform default
+alter table store add column late_fee_bps integer check (late_fee_bps between 0 and 200);
+alter table store add column monthly_interest_bps integer check (monthly_interest_bps between 0 and 100);
+alter table store add constraint store_fee_policy_check check ((late_fee_bps is null) = (monthly_interest_bps is null));
@@ -0,0 +1,9 @@
+-- a customer holds one account per store, and only in the store they are registered in
+alter table account drop constraint account_user_id_key;
+alter table account add constraint account_user_id_store_id_key unique (user_id, store_id);
+
+-- accounts opened before stores existed are still one per customer
+create unique index account_user_id_without_store_idx on account (user_id) where store_id is null;
+
+alter table "user" add constraint user_id_store_id_key unique (id, store_id);
+alter table account add constraint account_user_id_store_id_fkey foreign key (user_id, store_id) references "user" (id, store_id);
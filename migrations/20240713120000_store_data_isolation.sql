@@ -0,0 +1,83 @@
+-- the rule of the customer policy, shared by every table holding the data of a store
+create function tenant_allows(store_id uuid) returns boolean as $$
+	select current_setting('app.platform', true) = 'on'
+		or store_id = nullif(current_setting('app.store_id', true), '')::uuid;
+$$ language sql stable;
+
+alter table account enable row level security;
+alter table account force row level security;
+
+create policy account_tenant_isolation on account
+	using (tenant_allows(store_id))
+	with check (tenant_allows(store_id));
+
+alter table purchase enable row level security;
+alter table purchase force row level security;
+
+create policy purchase_tenant_isolation on purchase
+	using (tenant_allows(store_id))
+	with check (tenant_allows(store_id));
+
+alter table refresh_token enable row level security;
+alter table refresh_token force row level security;
+
+create policy refresh_token_tenant_isolation on refresh_token
+	using (tenant_allows(store_id))
+	with check (tenant_allows(store_id));
+
+-- the remaining tables belong to the store of their account
+alter table installment enable row level security;
+alter table installment force row level security;
+
+create policy installment_tenant_isolation on installment
+	using (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)));
+
+alter table payment enable row level security;
+alter table payment force row level security;
+
+create policy payment_tenant_isolation on payment
+	using (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)));
+
+alter table payment_allocation enable row level security;
+alter table payment_allocation force row level security;
+
+create policy payment_allocation_tenant_isolation on payment_allocation
+	using (exists (select 1 from payment p join account a on a.id = p.account_id where p.id = payment_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from payment p join account a on a.id = p.account_id where p.id = payment_id and tenant_allows(a.store_id)));
+
+alter table ledger_transaction enable row level security;
+alter table ledger_transaction force row level security;
+
+create policy ledger_transaction_tenant_isolation on ledger_transaction
+	using (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)));
+
+alter table ledger_entry enable row level security;
+alter table ledger_entry force row level security;
+
+create policy ledger_entry_tenant_isolation on ledger_entry
+	using (exists (select 1 from ledger_transaction t join account a on a.id = t.account_id where t.id = transaction_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from ledger_transaction t join account a on a.id = t.account_id where t.id = transaction_id and tenant_allows(a.store_id)));
+
+alter table fee enable row level security;
+alter table fee force row level security;
+
+create policy fee_tenant_isolation on fee
+	using (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)));
+
+alter table statement enable row level security;
+alter table statement force row level security;
+
+create policy statement_tenant_isolation on statement
+	using (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from account a where a.id = account_id and tenant_allows(a.store_id)));
+
+alter table statement_line enable row level security;
+alter table statement_line force row level security;
+
+create policy statement_line_tenant_isolation on statement_line
+	using (exists (select 1 from statement s join account a on a.id = s.account_id where s.id = statement_id and tenant_allows(a.store_id)))
+	with check (exists (select 1 from statement s join account a on a.id = s.account_id where s.id = statement_id and tenant_allows(a.store_id)));
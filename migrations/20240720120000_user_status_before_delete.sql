@@ -0,0 +1,15 @@
+-- restored users come back with the status they had when deleted
+alter table "user" add column status_before_delete varchar(255);
+
+-- the backfill must reach the customers of every store through the row level security policy
+select set_config('app.platform', 'on', true);
+
+-- users deleted before the column existed come back with their last recorded status
+update "user" u set status_before_delete = coalesce((
+	select c.to_status
+	from user_status_change c
+	where c.user_id = u.id
+	order by c.changed_at desc
+	limit 1
+), 'ACTIVE')
+where deleted_at is not null;
@@ -0,0 +1,4 @@
+create table accrual_run (
+	reference_date date primary key not null,
+	created_at timestamp not null
+);
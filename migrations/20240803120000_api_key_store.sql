@@ -0,0 +1,9 @@
+-- keys bound to a malformed or unknown store never authorized anything, they cannot be kept under the foreign key
+delete from api_key
+where case
+	when store_id ~* '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$' then not exists (select 1 from store s where s.id = store_id::uuid)
+	else true
+end;
+
+alter table api_key alter column store_id type uuid using store_id::uuid;
+alter table api_key add constraint api_key_store_id_fkey foreign key (store_id) references store(id);
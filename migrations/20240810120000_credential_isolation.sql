@@ -0,0 +1,21 @@
+-- status history and password resets belong to the store of their customer
+alter table user_status_change enable row level security;
+alter table user_status_change force row level security;
+
+create policy user_status_change_tenant_isolation on user_status_change
+	using (exists (select 1 from "user" u where u.id = user_id and tenant_allows(u.store_id)))
+	with check (exists (select 1 from "user" u where u.id = user_id and tenant_allows(u.store_id)));
+
+alter table password_reset enable row level security;
+alter table password_reset force row level security;
+
+create policy password_reset_tenant_isolation on password_reset
+	using (exists (select 1 from "user" u where u.id = user_id and tenant_allows(u.store_id)))
+	with check (exists (select 1 from "user" u where u.id = user_id and tenant_allows(u.store_id)));
+
+alter table api_key enable row level security;
+alter table api_key force row level security;
+
+create policy api_key_tenant_isolation on api_key
+	using (tenant_allows(store_id))
+	with check (tenant_allows(store_id));
@@ -0,0 +1,6 @@
+-- the same person may keep a tab at several stores, so a cpf is only unique within its store
+drop index user_document_active_idx;
+create unique index user_store_id_document_active_idx on "user" (store_id, document) where deleted_at is null;
+
+-- customers registered before stores existed are still told apart by their cpf alone
+create unique index user_document_without_store_active_idx on "user" (document) where store_id is null and deleted_at is null;
@@ -1,14 +1,49 @@
 use crate::domain::usecases::user::UserUseCase;
 use crate::domain::usecases::admin::AdminUseCase;
-use crate::data::usecases::user;
-use crate::data::usecases::admin;
+use crate::domain::usecases::account::AccountUseCase;
+use crate::domain::usecases::purchase::PurchaseUseCase;
+use crate::domain::usecases::payment::PaymentUseCase;
+use crate::domain::usecases::ledger::LedgerUseCase;
+use crate::domain::usecases::accrual::AccrualUseCase;
+use crate::domain::usecases::statement::StatementUseCase;
+use crate::domain::usecases::auth::AuthUseCase;
+use crate::domain::usecases::password::PasswordUseCase;
+use crate::domain::usecases::api_key::ApiKeyUseCase;
+use crate::domain::usecases::store::StoreUseCase;
+use crate::domain::entities::FeePolicy;
+use crate::data::usecases::user::{self, protocols::hash::Hash};
+use crate::data::usecases::admin::{self, keys::KeySet};
+use crate::data::usecases::account;
+use crate::data::usecases::purchase;
+use crate::data::usecases::payment;
+use crate::data::usecases::ledger;
+use crate::data::usecases::accrual;
+use crate::data::usecases::statement;
+use crate::data::usecases::auth;
+use crate::data::usecases::password;
+use crate::data::usecases::api_key;
+use crate::data::usecases::store;
 use crate::infrastructure::logger;
 use crate::infrastructure::{
     user::PostgresRepository,
-    hash::Hasher,
+    account::PostgresRepository as AccountPostgresRepository,
+    purchase::PostgresRepository as PurchasePostgresRepository,
+    payment::PostgresRepository as PaymentPostgresRepository,
+    ledger::PostgresRepository as LedgerPostgresRepository,
+    accrual::PostgresRepository as AccrualPostgresRepository,
+    statement::PostgresRepository as StatementPostgresRepository,
+    password::PostgresRepository as PasswordPostgresRepository,
+    admin::PostgresRepository as AdminPostgresRepository,
+    api_key::PostgresRepository as ApiKeyPostgresRepository,
+    store::PostgresRepository as StorePostgresRepository,
+    hash::{Hasher, Argon2Hasher, Pepper},
     uuid::Generator,
     tracer
 };
+use std::sync::Arc;
+use std::{fs, str::FromStr};
+use jsonwebtoken::Algorithm;
+use std::time::Duration;
 use sqlx::{Pool, Postgres};
 use opentelemetry_sdk::trace::Tracer;
 use opentelemetry::global::shutdown_tracer_provider;
@@ -19,32 +54,187 @@ pub struct Container{
     pub pg_pool: Pool<Postgres>,
     pub tracer: Tracer,
     pub admin_use_case: Box<dyn AdminUseCase + Send + Sync + 'static>,
-    pub user_use_case: Box<dyn UserUseCase + Send + Sync + 'static>
+    pub auth_use_case: Box<dyn AuthUseCase + Send + Sync + 'static>,
+    pub password_use_case: Box<dyn PasswordUseCase + Send + Sync + 'static>,
+    pub api_key_use_case: Box<dyn ApiKeyUseCase + Send + Sync + 'static>,
+    pub store_use_case: Box<dyn StoreUseCase + Send + Sync + 'static>,
+    pub user_use_case: Box<dyn UserUseCase + Send + Sync + 'static>,
+    pub account_use_case: Box<dyn AccountUseCase + Send + Sync + 'static>,
+    pub purchase_use_case: Box<dyn PurchaseUseCase + Send + Sync + 'static>,
+    pub payment_use_case: Box<dyn PaymentUseCase + Send + Sync + 'static>,
+    pub ledger_use_case: Box<dyn LedgerUseCase + Send + Sync + 'static>,
+    pub accrual_use_case: Box<dyn AccrualUseCase + Send + Sync + 'static>,
+    pub accrual_interval: Duration,
+    pub accrual_dry_run: bool,
+    pub statement_use_case: Box<dyn StatementUseCase + Send + Sync + 'static>,
+    pub statement_interval: Duration
 }
 
 impl Container {
-    pub async fn load_dependencies() -> Container {
-        let vars = env::Vars::load();
+    pub async fn load_dependencies() -> Result<Container, String> {
+        let vars = env::Vars::load()?;
         let conn_string = format!("postgresql://{}:{}@{}:{}/{}", vars.db_user, vars.db_password, vars.db_host, vars.db_port, vars.db_name);
-        let pg_pool: Pool<Postgres> = Pool::<Postgres>::connect(&conn_string).await.unwrap();
+        let pg_pool: Pool<Postgres> = match Pool::<Postgres>::connect(&conn_string).await {
+            Ok(p) => p,
+            Err(e) => return Err(format!("Cannot connect to the database: {}", e))
+        };
 
         let user_repository = Box::new(PostgresRepository::new(pg_pool.clone()));
-        let hash_provider = Box::new(Hasher::new(String::from("12345678"), 5));
+        let hasher = match Container::load_hasher(&vars) {
+            Ok(h) => h,
+            Err(e) => return Err(format!("Invalid password hashing config: {}", e))
+        };
+        let hash_provider = Box::new(hasher.clone());
         let uuid_generator = Box::new(Generator::new());
 
         let user_use_case = Box::new(user::UseCase::new(user_repository, uuid_generator, hash_provider));
-        let admin_use_case = Box::new(admin::UseCase::new(vars.admin_jwt_secret, vars.admin_role_name, vars.admin_token_duration));
+        let account_use_case = Box::new(account::UseCase::new(
+            Box::new(AccountPostgresRepository::new(pg_pool.clone())),
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(StorePostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+        let purchase_use_case = Box::new(purchase::UseCase::new(
+            Box::new(PurchasePostgresRepository::new(pg_pool.clone())),
+            Box::new(AccountPostgresRepository::new(pg_pool.clone())),
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+        let payment_use_case = Box::new(payment::UseCase::new(
+            Box::new(PaymentPostgresRepository::new(pg_pool.clone())),
+            Box::new(AccountPostgresRepository::new(pg_pool.clone())),
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+        let ledger_use_case = Box::new(ledger::UseCase::new(
+            Box::new(LedgerPostgresRepository::new(pg_pool.clone())),
+            Box::new(AccountPostgresRepository::new(pg_pool.clone())),
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+        let default_fee_policy = match FeePolicy::new(vars.late_fee_bps, vars.monthly_interest_bps) {
+            Ok(p) => p,
+            Err(e) => return Err(format!("Invalid fee policy: {}", e))
+        };
+        let accrual_use_case = Box::new(accrual::UseCase::new(
+            Box::new(AccrualPostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new()),
+            default_fee_policy
+        ));
+        let statement_use_case = Box::new(statement::UseCase::new(
+            Box::new(StatementPostgresRepository::new(pg_pool.clone())),
+            Box::new(AccountPostgresRepository::new(pg_pool.clone())),
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+        let key_set = match Container::load_key_set(&vars) {
+            Ok(k) => k,
+            Err(e) => return Err(format!("Invalid token signing config: {}", e))
+        };
+        let admin_use_case = Box::new(admin::UseCase::new(
+            Box::new(AdminPostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new()),
+            Box::new(hasher.clone()),
+            key_set,
+            vars.admin_role_name,
+            vars.admin_access_token_duration,
+            vars.admin_token_duration
+        ));
+        let auth_use_case = Box::new(auth::UseCase::new(
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(hasher.clone()),
+            vars.user_jwt_secret,
+            vars.user_token_duration
+        ));
+        let password_use_case = Box::new(password::UseCase::new(
+            Box::new(PasswordPostgresRepository::new(pg_pool.clone())),
+            Box::new(PostgresRepository::new(pg_pool.clone())),
+            Box::new(hasher.clone()),
+            Box::new(Generator::new()),
+            vars.password_reset_token_duration
+        ));
 
-        let tracer = tracer::init_tracer(&vars.otlp_endpoint,&vars.service_name).unwrap();
+        let api_key_use_case = Box::new(api_key::UseCase::new(
+            Box::new(ApiKeyPostgresRepository::new(pg_pool.clone())),
+            Box::new(StorePostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+        let store_use_case = Box::new(store::UseCase::new(
+            Box::new(StorePostgresRepository::new(pg_pool.clone())),
+            Box::new(Generator::new())
+        ));
+
+        let tracer = match tracer::init_tracer(&vars.otlp_endpoint,&vars.service_name) {
+            Ok(t) => t,
+            Err(e) => return Err(format!("Cannot start the tracer: {}", e))
+        };
 
         logger::init();
 
-        Container{
+        Ok(Container{
             tracer,
             user_use_case,
+            account_use_case,
+            purchase_use_case,
+            payment_use_case,
+            ledger_use_case,
+            accrual_use_case,
+            accrual_interval: Duration::from_secs(vars.accrual_interval_in_seconds),
+            accrual_dry_run: vars.accrual_dry_run,
+            statement_use_case,
+            statement_interval: Duration::from_secs(vars.statement_interval_in_seconds),
             admin_use_case, 
+            auth_use_case,
+            password_use_case,
+            api_key_use_case,
+            store_use_case,
             pg_pool
-        }    
+        })
+    }
+
+    /// Builds the configured password hasher, it verifies hashes of every supported algorithm.
+    pub fn load_hasher(vars: &env::Vars) -> Result<Arc<dyn Hash + Send + Sync>, String> {
+        let peppers = Pepper::parse_list(&vars.password_peppers)?;
+        match vars.password_hash_algorithm.as_str() {
+            "bcrypt" => Ok(Arc::new(Hasher::new(peppers, vars.password_hash_cost)?)),
+            "argon2id" => Ok(Arc::new(Argon2Hasher::new(peppers, vars.argon2_memory_in_kib, vars.argon2_iterations, vars.argon2_parallelism)?)),
+            algorithm => Err(format!("Unknown password hash algorithm '{}'", algorithm))
+        }
+    }
+
+    /// Builds the keys signing staff tokens, public keys are given as `kid:path` pairs.
+    pub fn load_key_set(vars: &env::Vars) -> Result<KeySet, String> {
+        let algorithm = match Algorithm::from_str(&vars.admin_jwt_algorithm) {
+            Ok(a) => a,
+            Err(_) => return Err(format!("Unknown token signing algorithm '{}'", vars.admin_jwt_algorithm))
+        };
+
+        if algorithm == Algorithm::HS512 {
+            return match vars.admin_jwt_secret.is_empty() {
+                true => Err(String::from("ADMIN_JWT_SECRET is not set")),
+                false => Ok(KeySet::new_hmac(&vars.admin_jwt_secret))
+            };
+        }
+
+        let private_key = match fs::read(&vars.admin_jwt_private_key_path) {
+            Ok(k) => k,
+            Err(e) => return Err(format!("Cannot read private key '{}': {}", vars.admin_jwt_private_key_path, e))
+        };
+
+        let mut public_keys: Vec<(String, Vec<u8>)> = Vec::new();
+        for entry in vars.admin_jwt_public_keys.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+            let (id, path) = match entry.split_once(':') {
+                Some(pair) => pair,
+                None => return Err(String::from("Public keys must be given as kid:path"))
+            };
+
+            match fs::read(path) {
+                Ok(key) => public_keys.push((String::from(id), key)),
+                Err(e) => return Err(format!("Cannot read public key '{}': {}", path, e))
+            }
+        }
+
+        KeySet::new_asymmetric(algorithm, &vars.admin_jwt_key_id, &private_key, &public_keys)
     }
 
     pub async fn destroy(&mut self) {
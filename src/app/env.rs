@@ -7,32 +7,51 @@ pub struct Vars {
     pub db_host: String,
     pub db_port: String,
     pub admin_jwt_secret: String,
+    pub admin_jwt_algorithm: String,
+    pub admin_jwt_key_id: String,
+    pub admin_jwt_private_key_path: String,
+    pub admin_jwt_public_keys: String,
     pub admin_role_name: String,
+    pub admin_access_token_duration: u64,
     pub admin_token_duration: u64,
+    pub user_jwt_secret: String,
+    pub user_token_duration: u64,
+    pub password_reset_token_duration: u64,
+    pub password_peppers: String,
+    pub password_hash_cost: u32,
+    pub password_hash_algorithm: String,
+    pub argon2_memory_in_kib: u32,
+    pub argon2_iterations: u32,
+    pub argon2_parallelism: u32,
     pub otlp_endpoint: String,
     pub service_name: String,
+    pub late_fee_bps: u32,
+    pub monthly_interest_bps: u32,
+    pub accrual_interval_in_seconds: u64,
+    pub accrual_dry_run: bool,
+    pub statement_interval_in_seconds: u64,
 }
 
 impl Vars {
-    pub fn load() -> Vars {
+    pub fn load() -> Result<Vars, String> {
         let db_name: String = match env::var("DB_NAME") {
             Ok(v) => v,
-            Err(e) => panic!("DB_NAME is not set: {}", e)
+            Err(e) => return Err(format!("DB_NAME is not set: {}", e))
         };
 
         let db_user: String = match env::var("DB_USER") {
             Ok(v) => v,
-            Err(e) => panic!("DB_USER is not set: {}", e)
+            Err(e) => return Err(format!("DB_USER is not set: {}", e))
         };
 
         let db_password: String = match env::var("DB_PASSWORD") {
             Ok(v) => v,
-            Err(e) => panic!("DB_PASSWORD is not set: {}", e)
+            Err(e) => return Err(format!("DB_PASSWORD is not set: {}", e))
         };
 
         let db_host: String = match env::var("DB_HOST") {
             Ok(v) => v,
-            Err(e) => panic!("DB_HOST is not set: {}", e)
+            Err(e) => return Err(format!("DB_HOST is not set: {}", e))
         };
 
         let db_port: String = match env::var("DB_PORT") {
@@ -40,24 +59,104 @@ impl Vars {
             Err(_) => String::from("5432")
         };
 
-        let admin_jwt_secret: String = match env::var("ADMIN_JWT_SECRET") {
+        // only required while tokens are signed with HS512
+        let admin_jwt_secret: String = env::var("ADMIN_JWT_SECRET").unwrap_or_default();
+
+        let admin_jwt_algorithm: String = match env::var("ADMIN_JWT_ALGORITHM") {
             Ok(v) => v,
-            Err(e) => panic!("ADMIN_JWT_SECRET is not set: {}", e)
+            Err(_) => String::from("HS512")
         };
 
+        let admin_jwt_key_id: String = env::var("ADMIN_JWT_KEY_ID").unwrap_or_default();
+
+        let admin_jwt_private_key_path: String = env::var("ADMIN_JWT_PRIVATE_KEY_PATH").unwrap_or_default();
+
+        let admin_jwt_public_keys: String = env::var("ADMIN_JWT_PUBLIC_KEYS").unwrap_or_default();
+
         let admin_role_name: String = match env::var("ADMIN_ROLE_NAME") {
             Ok(v) => v,
             Err(_) => String::from("ADMIN")
         };
 
+        let admin_access_token_duration: u64 = match env::var("ADMIN_ACCESS_TOKEN_DURATION_IN_MINUTES") {
+            Ok(v) => match v.parse::<u64>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for ADMIN_ACCESS_TOKEN_DURATION_IN_MINUTES"))
+            },
+            Err(_) => 15
+        };
+
         let admin_token_duration: u64 = match env::var("ADMIN_TOKEN_DURATION_IN_DAYS") {
             Ok(v) => match v.parse::<u64>() {
                 Ok(v) => v,
-                Err(_) => panic!("Invalid type for ADMIN_TOKEN_DURATION_IN_DAYS")
+                Err(_) => return Err(String::from("Invalid type for ADMIN_TOKEN_DURATION_IN_DAYS"))
             },
             Err(_) => 1 
         };
 
+        let user_jwt_secret: String = match env::var("USER_JWT_SECRET") {
+            Ok(v) => v,
+            Err(e) => return Err(format!("USER_JWT_SECRET is not set: {}", e))
+        };
+
+        let user_token_duration: u64 = match env::var("USER_TOKEN_DURATION_IN_HOURS") {
+            Ok(v) => match v.parse::<u64>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for USER_TOKEN_DURATION_IN_HOURS"))
+            },
+            Err(_) => 12
+        };
+
+        let password_reset_token_duration: u64 = match env::var("PASSWORD_RESET_TOKEN_DURATION_IN_MINUTES") {
+            Ok(v) => match v.parse::<u64>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for PASSWORD_RESET_TOKEN_DURATION_IN_MINUTES"))
+            },
+            Err(_) => 30
+        };
+
+        let password_peppers: String = match env::var("PASSWORD_PEPPERS") {
+            Ok(v) => v,
+            Err(e) => return Err(format!("PASSWORD_PEPPERS is not set: {}", e))
+        };
+
+        let password_hash_cost: u32 = match env::var("PASSWORD_HASH_COST") {
+            Ok(v) => match v.parse::<u32>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for PASSWORD_HASH_COST"))
+            },
+            Err(_) => 12
+        };
+
+        let password_hash_algorithm: String = match env::var("PASSWORD_HASH_ALGORITHM") {
+            Ok(v) => v,
+            Err(_) => String::from("bcrypt")
+        };
+
+        let argon2_memory_in_kib: u32 = match env::var("ARGON2_MEMORY_IN_KIB") {
+            Ok(v) => match v.parse::<u32>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for ARGON2_MEMORY_IN_KIB"))
+            },
+            Err(_) => 19456
+        };
+
+        let argon2_iterations: u32 = match env::var("ARGON2_ITERATIONS") {
+            Ok(v) => match v.parse::<u32>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for ARGON2_ITERATIONS"))
+            },
+            Err(_) => 2
+        };
+
+        let argon2_parallelism: u32 = match env::var("ARGON2_PARALLELISM") {
+            Ok(v) => match v.parse::<u32>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for ARGON2_PARALLELISM"))
+            },
+            Err(_) => 1
+        };
+
         let service_name: String = match env::var("SERVICE_NAME") {
             Ok(v) => v,
             Err(_) => String::from("fiadors")
@@ -68,18 +167,76 @@ impl Vars {
             Err(_) => String::from("http://localhost:4317")
         };
 
+        let late_fee_bps: u32 = match env::var("LATE_FEE_BPS") {
+            Ok(v) => match v.parse::<u32>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for LATE_FEE_BPS"))
+            },
+            Err(_) => 200
+        };
+
+        let monthly_interest_bps: u32 = match env::var("MONTHLY_INTEREST_BPS") {
+            Ok(v) => match v.parse::<u32>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for MONTHLY_INTEREST_BPS"))
+            },
+            Err(_) => 100
+        };
+
+        let accrual_interval_in_seconds: u64 = match env::var("ACCRUAL_INTERVAL_IN_SECONDS") {
+            Ok(v) => match v.parse::<u64>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for ACCRUAL_INTERVAL_IN_SECONDS"))
+            },
+            Err(_) => 3600
+        };
+
+        let accrual_dry_run: bool = match env::var("ACCRUAL_DRY_RUN") {
+            Ok(v) => match v.parse::<bool>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for ACCRUAL_DRY_RUN"))
+            },
+            Err(_) => false
+        };
+
+        let statement_interval_in_seconds: u64 = match env::var("STATEMENT_INTERVAL_IN_SECONDS") {
+            Ok(v) => match v.parse::<u64>() {
+                Ok(v) => v,
+                Err(_) => return Err(String::from("Invalid type for STATEMENT_INTERVAL_IN_SECONDS"))
+            },
+            Err(_) => 3600
+        };
 
-        Vars {
+        Ok(Vars {
             db_name,
             db_user,
             db_password,
             db_host,
             db_port,
             admin_jwt_secret,
+            admin_jwt_algorithm,
+            admin_jwt_key_id,
+            admin_jwt_private_key_path,
+            admin_jwt_public_keys,
             admin_role_name,
+            admin_access_token_duration,
             admin_token_duration,
+            user_jwt_secret,
+            user_token_duration,
+            password_reset_token_duration,
+            password_peppers,
+            password_hash_cost,
+            password_hash_algorithm,
+            argon2_memory_in_kib,
+            argon2_iterations,
+            argon2_parallelism,
             service_name,
-            otlp_endpoint
-        }
+            otlp_endpoint,
+            late_fee_bps,
+            monthly_interest_bps,
+            accrual_interval_in_seconds,
+            accrual_dry_run,
+            statement_interval_in_seconds
+        })
     }
 }
\ No newline at end of file
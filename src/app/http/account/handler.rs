@@ -0,0 +1,86 @@
+use axum::{Json, Extension, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::{entities::Tenant, usecases::account::{AccountCreateRequestDTO, AccountUpdateRequestDTO, AccountResponseDTO}},
+    app::http::error::AppError
+};
+
+pub async fn create_account(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Json(payload): Json<AccountCreateRequestDTO>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("create.account");
+    let result = match state.account_use_case.create(&tenant, payload).await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "create_account_error", "error creating account {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn update_account(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>, Json(payload): Json<AccountUpdateRequestDTO>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("update.account");
+    let result = match state.account_use_case.update(&tenant, document.as_str(), payload).await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "update_account_error", "error updating account {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn get_account_by_document(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>) -> Result<Json<AccountResponseDTO>, AppError> {
+    let mut span = state.tracer.start("get.account");
+    let result = match state.account_use_case.get(&tenant, document.as_str()).await {
+        Ok(a) => Ok(Json(a)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "get_account_by_document_error", "error getting account {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn close_account_by_document(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("close.account");
+    let result = match state.account_use_case.close(&tenant, document.as_str()).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "close_account_error", "error closing account {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
@@ -0,0 +1,18 @@
+use axum::{
+    Router,
+    routing::{post, put, get},
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{create_account, update_account, get_account_by_document, close_account_by_document};
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/", post(create_account)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::AccountsWrite), permission_layer )))
+        .route("/:document", put(update_account).delete(close_account_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::AccountsWrite), permission_layer )))
+        .route("/:document", get(get_account_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::AccountsRead), permission_layer )))
+}
\ No newline at end of file
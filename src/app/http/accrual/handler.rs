@@ -0,0 +1,31 @@
+use axum::{Json, extract::State};
+use chrono::Utc;
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::usecases::accrual::{AccrualRequestDTO, AccrualReportDTO},
+    app::http::error::AppError
+};
+
+pub async fn run_accrual(State(state): State<Arc<Container>>, Json(payload): Json<AccrualRequestDTO>) -> Result<Json<AccrualReportDTO>, AppError> {
+    let mut span = state.tracer.start("run.accrual");
+    let reference_date = payload.reference_date.unwrap_or_else(|| Utc::now().date_naive());
+    let result = match state.accrual_use_case.run(reference_date, payload.dry_run).await {
+        Ok(r) => Ok(Json(r)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "run_accrual_error", "error running accrual {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
@@ -0,0 +1,14 @@
+use axum::{
+    Router,
+    routing::post,
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::run_accrual;
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/", post(run_accrual))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::AccrualsRun), permission_layer ))
+}
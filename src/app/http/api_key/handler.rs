@@ -0,0 +1,67 @@
+use axum::{Json, extract::{State, Path, Query}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::usecases::api_key::{CreateApiKeyRequestDTO, CreatedApiKeyResponseDTO, ApiKeyResponseDTO, ListApiKeysQueryDTO},
+    app::http::error::AppError
+};
+
+pub async fn create_api_key(State(state): State<Arc<Container>>, Json(payload): Json<CreateApiKeyRequestDTO>) -> Result<Json<CreatedApiKeyResponseDTO>, AppError> {
+    let mut span = state.tracer.start("create.api_key");
+    let result = match state.api_key_use_case.create(payload).await {
+        Ok(k) => Ok(Json(k)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "create_api_key_error", "error creating api key {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn list_api_keys(State(state): State<Arc<Container>>, Query(query): Query<ListApiKeysQueryDTO>) -> Result<Json<Vec<ApiKeyResponseDTO>>, AppError> {
+    let mut span = state.tracer.start("list.api_keys");
+    let result = match state.api_key_use_case.list(query.store_id).await {
+        Ok(k) => Ok(Json(k)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "list_api_keys_error", "error listing api keys {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn revoke_api_key(State(state): State<Arc<Container>>, Path(id): Path<String>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("revoke.api_key");
+    let result = match state.api_key_use_case.revoke(id.as_str()).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "revoke_api_key_error", "error revoking api key {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
@@ -0,0 +1,16 @@
+use axum::{
+    Router,
+    routing::{get, delete},
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{create_api_key, list_api_keys, revoke_api_key};
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new()
+        .route("/", get(list_api_keys).post(create_api_key))
+        .route("/:id", delete(revoke_api_key))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::ApiKeysManage), permission_layer ))
+}
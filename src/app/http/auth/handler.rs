@@ -0,0 +1,91 @@
+use axum::{Json, extract::State, http::HeaderMap};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::usecases::auth::{LoginRequestDTO, LoginResponseDTO},
+    domain::usecases::admin::{RefreshRequestDTO, LogoutRequestDTO, TokenPairResponseDTO, JsonWebKeySetDTO},
+    app::http::{error::AppError, middlewares::permission::get_token_from_header}
+};
+
+pub async fn login(State(state): State<Arc<Container>>, Json(payload): Json<LoginRequestDTO>) -> Result<Json<LoginResponseDTO>, AppError> {
+    let mut span = state.tracer.start("login.user");
+    let result = match state.auth_use_case.login(payload).await {
+        Ok(r) => Ok(Json(r)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "login_error", "error logging in {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn refresh(State(state): State<Arc<Container>>, Json(payload): Json<RefreshRequestDTO>) -> Result<Json<TokenPairResponseDTO>, AppError> {
+    let mut span = state.tracer.start("refresh.token");
+    let result = match state.admin_use_case.refresh(payload).await {
+        Ok(r) => Ok(Json(r)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "refresh_token_error", "error refreshing token {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn logout(State(state): State<Arc<Container>>, headers: HeaderMap, payload: Option<Json<LogoutRequestDTO>>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("logout.session");
+    let payload = payload.map(|Json(p)| p).unwrap_or_default();
+    let result = match get_token_from_header(&headers) {
+        Ok(token) => match state.admin_use_case.logout(token, payload).await {
+            Ok(()) => Ok(()),
+            Err(err) => Err(AppError::from_domain(err))
+        },
+        Err(e) => Err(e)
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "logout_error", "error logging out {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn get_jwks(State(state): State<Arc<Container>>) -> Result<Json<JsonWebKeySetDTO>, AppError> {
+    let mut span = state.tracer.start("get.jwks");
+    let result = match state.admin_use_case.get_jwks().await {
+        Ok(r) => Ok(Json(r)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "get_jwks_error", "error getting jwks {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
@@ -0,0 +1,20 @@
+use axum::{
+    Router,
+    routing::{get, post},
+    extract::State
+};
+use std::sync::Arc;
+use super::handler::{login, refresh, logout, get_jwks};
+use crate::app::container::Container;
+
+pub fn build_routes(State(_state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new()
+        .route("/login", post(login))
+        .route("/refresh", post(refresh))
+        .route("/logout", post(logout))
+}
+
+/// Public keys verifying staff tokens, served without authentication.
+pub fn build_well_known_routes(State(_state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/jwks.json", get(get_jwks))
+}
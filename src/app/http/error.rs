@@ -1,12 +1,13 @@
 use axum::{
     response::{Response, IntoResponse},
-    http::StatusCode,
+    http::{StatusCode, header::WWW_AUTHENTICATE},
     Json
 };
 use std::error::Error as stdError;
 
 use serde::Serialize;
 use crate::domain::error::{Error, Kind};
+use crate::domain::usecases::admin::{INVALID_TOKEN_ERROR, EXPIRED_TOKEN_ERROR};
 
 
 #[derive(Debug)]
@@ -23,6 +24,14 @@ impl AppError {
     pub fn get_message(&self) -> String {
         self.0.get_message()
     }
+
+    /// Bearer challenge as described by RFC 6750, tokens that were sent but rejected get `invalid_token`.
+    fn get_challenge(&self) -> &'static str {
+        match self.0.get_code() {
+            INVALID_TOKEN_ERROR | EXPIRED_TOKEN_ERROR => r#"Bearer error="invalid_token""#,
+            _ => "Bearer"
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -39,7 +48,9 @@ impl IntoResponse for AppError {
             Kind::Business =>  (StatusCode::BAD_REQUEST, Json(body)).into_response(),
             Kind::NotFound =>  (StatusCode::NOT_FOUND, Json(body)).into_response(),
             Kind::Internal =>  (StatusCode::INTERNAL_SERVER_ERROR, Json(body)).into_response(),
-            Kind::AlreadyExists => (StatusCode::CONFLICT, Json(body)).into_response()
+            Kind::AlreadyExists => (StatusCode::CONFLICT, Json(body)).into_response(),
+            Kind::Unauthorized => (StatusCode::UNAUTHORIZED, [(WWW_AUTHENTICATE, self.get_challenge())], Json(body)).into_response(),
+            Kind::Forbidden => (StatusCode::FORBIDDEN, [(WWW_AUTHENTICATE, r#"Bearer error="insufficient_scope""#)], Json(body)).into_response()
         }
     }
 }
@@ -0,0 +1,48 @@
+use axum::{Json, Extension, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::{entities::Tenant, usecases::ledger::{AdjustmentRequestDTO, AdjustmentResponseDTO, ReconciliationResponseDTO}},
+    app::http::error::AppError
+};
+
+pub async fn register_adjustment(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>, Json(payload): Json<AdjustmentRequestDTO>) -> Result<Json<AdjustmentResponseDTO>, AppError> {
+    let mut span = state.tracer.start("register.adjustment");
+    let result = match state.ledger_use_case.adjust(&tenant, document.as_str(), payload).await {
+        Ok(a) => Ok(Json(a)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "register_adjustment_error", "error registering adjustment {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn reconcile_balances(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>) -> Result<Json<ReconciliationResponseDTO>, AppError> {
+    let mut span = state.tracer.start("reconcile.ledger");
+    let result = match state.ledger_use_case.reconcile(&tenant).await {
+        Ok(r) => Ok(Json(r)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "reconcile_ledger_error", "error reconciling ledger {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
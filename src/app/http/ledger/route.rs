@@ -0,0 +1,19 @@
+use axum::{
+    Router,
+    routing::{get, post},
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{register_adjustment, reconcile_balances};
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/reconciliation", get(reconcile_balances))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::LedgerRead), permission_layer ))
+}
+
+pub fn build_adjustment_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/", post(register_adjustment))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::AdjustmentsCreate), permission_layer ))
+}
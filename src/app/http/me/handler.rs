@@ -0,0 +1,52 @@
+use axum::{Json, Extension, extract::State};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::usecases::{
+        auth::AuthenticatedUser,
+        account::AccountResponseDTO,
+        statement::StatementResponseDTO
+    },
+    app::http::error::AppError
+};
+
+pub async fn get_my_account(State(state): State<Arc<Container>>, Extension(user): Extension<AuthenticatedUser>) -> Result<Json<AccountResponseDTO>, AppError> {
+    let mut span = state.tracer.start("get.me.account");
+    let result = match state.account_use_case.get(&user.get_tenant(), user.document.as_str()).await {
+        Ok(a) => Ok(Json(a)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "get_my_account_error", "error getting account {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn list_my_statements(State(state): State<Arc<Container>>, Extension(user): Extension<AuthenticatedUser>) -> Result<Json<Vec<StatementResponseDTO>>, AppError> {
+    let mut span = state.tracer.start("list.me.statement");
+    let result = match state.statement_use_case.list(&user.get_tenant(), user.document.as_str()).await {
+        Ok(s) => Ok(Json(s)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "list_my_statements_error", "error listing statements {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
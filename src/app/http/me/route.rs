@@ -0,0 +1,14 @@
+use axum::{
+    Router,
+    routing::get,
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{get_my_account, list_my_statements};
+use crate::app::{container::Container, http::middlewares::customer::customer_layer};
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/account", get(get_my_account))
+        .route("/statements", get(list_my_statements))
+        .layer(middleware::from_fn_with_state(state.clone(), customer_layer ))
+}
@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{Response, IntoResponse}
+};
+use std::sync::Arc;
+use crate::app::container::Container;
+use crate::app::http::error::AppError;
+use super::permission::get_token_from_header;
+
+/// Accepts user tokens only and hands the authenticated customer and its tenant to the handlers.
+pub async fn customer_layer(
+    State(_state): State<Arc<Container>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let token = match get_token_from_header(request.headers()) {
+        Ok(t) => t,
+        Err(e) => return e.into_response()
+    };
+
+    let user = match _state.auth_use_case.validate_token(token).await {
+        Ok(u) => u,
+        Err(e) => return AppError::from_domain(e).into_response()
+    };
+
+    request.extensions_mut().insert(user.get_tenant());
+    request.extensions_mut().insert(user);
+    next.run(request).await
+}
@@ -1 +1,2 @@
-pub mod admin;
\ No newline at end of file
+pub mod permission;
+pub mod customer;
\ No newline at end of file
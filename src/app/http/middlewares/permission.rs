@@ -0,0 +1,75 @@
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, header::AUTHORIZATION},
+    middleware::Next,
+    response::{Response, IntoResponse}
+};
+use std::sync::Arc;
+use crate::app::container::Container;
+use crate::app::http::error::AppError;
+use crate::domain::entities::Permission;
+use crate::domain::error::Error;
+use crate::domain::usecases::admin::{MISSING_AUTH_TOKEN, INVALID_TOKEN_ERROR, PERMISSION_DENIED_ERROR};
+
+const API_KEY_HEADER: &str = "X-Api-Key";
+const BEARER_SCHEME: &str = "Bearer";
+
+/// Accepts staff tokens or API keys granting `permission` and hands the principal and its tenant to the handlers.
+pub async fn permission_layer(
+    State((_state, permission)): State<(Arc<Container>, Permission)>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let result = match get_api_key_from_header(request.headers()) {
+        Some(key) => _state.api_key_use_case.validate_key(key).await,
+        None => match get_token_from_header(request.headers()) {
+            Ok(token) => _state.admin_use_case.validate_token(token).await,
+            Err(e) => return e.into_response()
+        }
+    };
+
+    let principal = match result {
+        Ok(p) => p,
+        Err(e) => return AppError::from_domain(e).into_response()
+    };
+
+    if !principal.has_permission(permission) {
+        return AppError::from_domain(Error::new_forbidden(PERMISSION_DENIED_ERROR)).into_response();
+    }
+
+    let tenant = match principal.get_tenant() {
+        Ok(t) => t,
+        Err(e) => return AppError::from_domain(e).into_response()
+    };
+
+    request.extensions_mut().insert(tenant);
+    request.extensions_mut().insert(principal);
+    next.run(request).await
+}
+
+/// Machine credentials are sent in `X-Api-Key` instead of a bearer token.
+pub(crate) fn get_api_key_from_header(headers: &HeaderMap) -> Option<String> {
+    match headers.get(API_KEY_HEADER).map(|v| v.to_str()) {
+        Some(Ok(key)) if !key.trim().is_empty() => Some(String::from(key.trim())),
+        Some(_) => Some(String::new()),
+        None => None
+    }
+}
+
+/// Reads the token of an `Authorization: Bearer <token>` header, other schemes are rejected.
+pub(crate) fn get_token_from_header(headers: &HeaderMap) -> Result<String, AppError> {
+    let authorization = match headers.get(AUTHORIZATION) {
+        Some(a) => a,
+        None => return Err(AppError::from_domain(Error::new_unauthorized(MISSING_AUTH_TOKEN)))
+    };
+
+    let token = match authorization.to_str().map(|a| a.trim().split_once(' ')) {
+        Ok(Some((scheme, token))) if scheme.eq_ignore_ascii_case(BEARER_SCHEME) => token.trim(),
+        _ => return Err(AppError::from_domain(Error::new_unauthorized(INVALID_TOKEN_ERROR)))
+    };
+
+    if token.is_empty() || token.contains(' ') {
+        return Err(AppError::from_domain(Error::new_unauthorized(INVALID_TOKEN_ERROR)));
+    }
+    Ok(String::from(token))
+}
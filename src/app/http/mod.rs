@@ -1,5 +1,16 @@
 pub mod error;
 pub mod user;
+pub mod account;
+pub mod purchase;
+pub mod payment;
+pub mod ledger;
+pub mod accrual;
+pub mod statement;
+pub mod auth;
+pub mod me;
+pub mod password;
+pub mod api_key;
+pub mod store;
 pub mod middlewares;
 
 use axum::extract::State;
@@ -7,9 +18,22 @@ use super::container::Container;
 use axum::routing::Router;
 use std::sync::Arc;
 
-pub fn build_app(container: Container) -> Router {
-    let state = Arc::new(container);
+pub fn build_app(state: Arc<Container>) -> Router {
     Router::new()
         .nest("/users", user::route::build_routes(State(state.clone())))
+        .nest("/users/:document/purchases", purchase::route::build_routes(State(state.clone())))
+        .nest("/users/:document/payments", payment::route::build_routes(State(state.clone())))
+        .nest("/users/:document/adjustments", ledger::route::build_adjustment_routes(State(state.clone())))
+        .nest("/users/:document/statements", statement::route::build_user_routes(State(state.clone())))
+        .nest("/users/:document/password", password::route::build_routes(State(state.clone())))
+        .nest("/accounts", account::route::build_routes(State(state.clone())))
+        .nest("/ledger", ledger::route::build_routes(State(state.clone())))
+        .nest("/accruals", accrual::route::build_routes(State(state.clone())))
+        .nest("/statements", statement::route::build_routes(State(state.clone())))
+        .nest("/stores", store::route::build_routes(State(state.clone())))
+        .nest("/api-keys", api_key::route::build_routes(State(state.clone())))
+        .nest("/auth", auth::route::build_routes(State(state.clone())))
+        .nest("/.well-known", auth::route::build_well_known_routes(State(state.clone())))
+        .nest("/me", me::route::build_routes(State(state.clone())))
         .with_state(state)
 } 
\ No newline at end of file
@@ -0,0 +1,85 @@
+use axum::{Json, Extension, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::{
+        entities::Tenant,
+        error::Error,
+        usecases::{
+            admin::PERMISSION_DENIED_ERROR,
+            auth::AuthenticatedUser,
+            password::{PasswordChangeRequestDTO, PasswordResetRequestDTO, PasswordResetTokenResponseDTO}
+        }
+    },
+    app::http::error::AppError
+};
+
+pub async fn change_password(
+    State(state): State<Arc<Container>>,
+    Path(document): Path<String>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Json(payload): Json<PasswordChangeRequestDTO>
+) -> Result<(), AppError> {
+    let mut span = state.tracer.start("change.password");
+    // customers can only change their own password
+    let result = if user.document != document {
+        Err(AppError::from_domain(Error::new_forbidden(PERMISSION_DENIED_ERROR)))
+    } else {
+        match state.password_use_case.change(&user.get_tenant(), document.as_str(), payload).await {
+            Ok(()) => Ok(()),
+            Err(err) => Err(AppError::from_domain(err))
+        }
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "change_password_error", "error changing password {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn create_reset_token(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>) -> Result<Json<PasswordResetTokenResponseDTO>, AppError> {
+    let mut span = state.tracer.start("create.password_reset");
+    let result = match state.password_use_case.create_reset_token(&tenant, document.as_str()).await {
+        Ok(r) => Ok(Json(r)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "create_password_reset_error", "error creating password reset {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn reset_password(State(state): State<Arc<Container>>, Path(document): Path<String>, Json(payload): Json<PasswordResetRequestDTO>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("reset.password");
+    let result = match state.password_use_case.reset(document.as_str(), payload).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "reset_password_error", "error resetting password {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
@@ -0,0 +1,22 @@
+use axum::{
+    Router,
+    routing::{post, put},
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{change_password, create_reset_token, reset_password};
+use crate::app::{container::Container, http::middlewares::{permission::permission_layer, customer::customer_layer}};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    let customer_routes = Router::new().route("/", put(change_password))
+        .layer(middleware::from_fn_with_state(state.clone(), customer_layer ));
+
+    let staff_routes = Router::new().route("/reset-token", post(create_reset_token))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::PasswordResetsCreate), permission_layer ));
+
+    // the reset token itself authenticates the request
+    Router::new().route("/reset", post(reset_password))
+        .merge(customer_routes)
+        .merge(staff_routes)
+}
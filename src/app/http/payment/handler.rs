@@ -0,0 +1,29 @@
+use axum::{Json, Extension, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::{entities::Tenant, usecases::{admin::Principal, payment::{PaymentCreateRequestDTO, PaymentResponseDTO}}},
+    app::http::error::AppError
+};
+
+pub async fn register_payment(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Extension(principal): Extension<Principal>, Path(document): Path<String>, Json(payload): Json<PaymentCreateRequestDTO>) -> Result<Json<PaymentResponseDTO>, AppError> {
+    let mut span = state.tracer.start("register.payment");
+    let result = match state.payment_use_case.register(&tenant, &principal.subject, document.as_str(), payload).await {
+        Ok(p) => Ok(Json(p)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "register_payment_error", "error registering payment {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
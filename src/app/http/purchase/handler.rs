@@ -0,0 +1,29 @@
+use axum::{Json, Extension, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::{entities::Tenant, usecases::purchase::{PurchaseCreateRequestDTO, PurchaseResponseDTO}},
+    app::http::error::AppError
+};
+
+pub async fn register_purchase(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>, Json(payload): Json<PurchaseCreateRequestDTO>) -> Result<Json<PurchaseResponseDTO>, AppError> {
+    let mut span = state.tracer.start("register.purchase");
+    let result = match state.purchase_use_case.register(&tenant, document.as_str(), payload).await {
+        Ok(p) => Ok(Json(p)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "register_purchase_error", "error registering purchase {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
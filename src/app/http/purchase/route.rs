@@ -0,0 +1,14 @@
+use axum::{
+    Router,
+    routing::post,
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::register_purchase;
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/", post(register_purchase))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::PurchasesCreate), permission_layer ))
+}
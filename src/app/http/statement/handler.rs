@@ -0,0 +1,48 @@
+use axum::{Json, Extension, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::{entities::Tenant, usecases::statement::StatementResponseDTO},
+    app::http::error::AppError
+};
+
+pub async fn list_statements_by_document(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>) -> Result<Json<Vec<StatementResponseDTO>>, AppError> {
+    let mut span = state.tracer.start("list.statement");
+    let result = match state.statement_use_case.list(&tenant, document.as_str()).await {
+        Ok(s) => Ok(Json(s)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "list_statement_error", "error listing statements {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn get_statement_by_id(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(id): Path<String>) -> Result<Json<StatementResponseDTO>, AppError> {
+    let mut span = state.tracer.start("get.statement");
+    let result = match state.statement_use_case.get(&tenant, id.as_str()).await {
+        Ok(s) => Ok(Json(s)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "get_statement_error", "error getting statement {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
@@ -0,0 +1,19 @@
+use axum::{
+    Router,
+    routing::get,
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{list_statements_by_document, get_statement_by_id};
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/:id", get(get_statement_by_id))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::StatementsRead), permission_layer ))
+}
+
+pub fn build_user_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new().route("/", get(list_statements_by_document))
+        .layer(middleware::from_fn_with_state((state.clone(), Permission::StatementsRead), permission_layer ))
+}
@@ -0,0 +1,105 @@
+use axum::{Json, extract::{State, Path}};
+use opentelemetry::trace::{Tracer, Span, Status};
+use crate::app::container::Container;
+use std::{sync::Arc, borrow::Cow};
+use log::error;
+
+use crate::{
+    domain::usecases::store::{StoreCreateRequestDTO, StoreUpdateRequestDTO, StoreResponseDTO},
+    app::http::error::AppError
+};
+
+pub async fn create_store(State(state): State<Arc<Container>>, Json(payload): Json<StoreCreateRequestDTO>) -> Result<Json<StoreResponseDTO>, AppError> {
+    let mut span = state.tracer.start("create.store");
+    let result = match state.store_use_case.create(payload).await {
+        Ok(s) => Ok(Json(s)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "create_store_error", "error creating store {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn update_store(State(state): State<Arc<Container>>, Path(id): Path<String>, Json(payload): Json<StoreUpdateRequestDTO>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("update.store");
+    let result = match state.store_use_case.update(id.as_str(), payload).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "update_store_error", "error updating store {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn get_store(State(state): State<Arc<Container>>, Path(id): Path<String>) -> Result<Json<StoreResponseDTO>, AppError> {
+    let mut span = state.tracer.start("get.store");
+    let result = match state.store_use_case.get(id.as_str()).await {
+        Ok(s) => Ok(Json(s)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "get_store_error", "error getting store {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn list_stores(State(state): State<Arc<Container>>) -> Result<Json<Vec<StoreResponseDTO>>, AppError> {
+    let mut span = state.tracer.start("list.stores");
+    let result = match state.store_use_case.list().await {
+        Ok(s) => Ok(Json(s)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "list_stores_error", "error listing stores {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn delete_store(State(state): State<Arc<Container>>, Path(id): Path<String>) -> Result<(), AppError> {
+    let mut span = state.tracer.start("delete.store");
+    let result = match state.store_use_case.delete(id.as_str()).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "delete_store_error", "error deleting store {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use axum::{
+    Router,
+    routing::{get, post, put},
+    middleware, extract::State
+};
+use std::sync::Arc;
+use super::handler::{create_store, update_store, get_store, list_stores, delete_store};
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
+
+pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
+    Router::new()
+        .route("/", get(list_stores)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::StoresRead), permission_layer )))
+        .route("/", post(create_store)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::StoresWrite), permission_layer )))
+        .route("/:id", get(get_store)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::StoresRead), permission_layer )))
+        .route("/:id", put(update_store).delete(delete_store)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::StoresWrite), permission_layer )))
+}
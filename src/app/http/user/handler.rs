@@ -1,17 +1,23 @@
-use axum::{Json, extract::{State, Path}};
+use axum::{Json, Extension, extract::{State, Path, Query}};
 use opentelemetry::trace::{Tracer, Span, Status};
 use crate::app::container::Container;
 use std::{sync::Arc, borrow::Cow};
 use log::error;
 
 use crate::{
-    domain::usecases::user::{UserCreateRequestDTO, UserUpdateRequestDTO, PublicUserResponseDTO},
+    domain::{
+        entities::{Role, Tenant},
+        usecases::{
+            admin::Principal,
+            user::{UserCreateRequestDTO, UserUpdateRequestDTO, PublicUserResponseDTO, UserListQueryDTO, UserListResponseDTO, UserSearchQueryDTO, UserStatusChangeRequestDTO, UserPatchRequestDTO}
+        }
+    },
     app::http::error::AppError
 };
 
-pub async fn create_user(State(state): State<Arc<Container>>, Json(payload): Json<UserCreateRequestDTO>)-> Result<(), AppError> {
+pub async fn create_user(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Json(payload): Json<UserCreateRequestDTO>)-> Result<(), AppError> {
     let mut span = state.tracer.start("create.user");
-    let result = match state.user_use_case.create(payload).await {
+    let result = match state.user_use_case.create(&tenant, payload).await {
         Ok(_) => Ok(()),
         Err(err) => Err(AppError::from_domain(err))
     };
@@ -28,9 +34,9 @@ pub async fn create_user(State(state): State<Arc<Container>>, Json(payload): Jso
     result
 }
 
-pub async fn update_user(State(state): State<Arc<Container>>, Json(payload): Json<UserUpdateRequestDTO>)-> Result<(), AppError> {
+pub async fn update_user(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Json(payload): Json<UserUpdateRequestDTO>)-> Result<(), AppError> {
     let mut span = state.tracer.start("update.user");
-    let result = match state.user_use_case.update(payload).await {
+    let result = match state.user_use_case.update(&tenant, payload).await {
         Ok(_) => Ok(()),
         Err(err) => Err(AppError::from_domain(err))
     };
@@ -47,9 +53,33 @@ pub async fn update_user(State(state): State<Arc<Container>>, Json(payload): Jso
     result
 }
 
-pub async fn get_user_by_document(State(state): State<Arc<Container>>, Path(document): Path<String>)-> Result<Json<PublicUserResponseDTO>, AppError> {
+pub async fn patch_user_by_document(
+    State(state): State<Arc<Container>>,
+    Extension(tenant): Extension<Tenant>,
+    Path(document): Path<String>,
+    Json(payload): Json<UserPatchRequestDTO>
+) -> Result<Json<PublicUserResponseDTO>, AppError> {
+    let mut span = state.tracer.start("patch.user");
+    let result = match state.user_use_case.patch(&tenant, document.as_str(), payload).await {
+        Ok(u) => Ok(Json(u)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "patch_user_error", "error patching user {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn get_user_by_document(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>)-> Result<Json<PublicUserResponseDTO>, AppError> {
     let mut span = state.tracer.start("get.user");
-    let result = match state.user_use_case.get(document.as_str()).await {
+    let result = match state.user_use_case.get(&tenant, document.as_str()).await {
         Ok(u) => Ok(Json(u)),
         Err(err) => Err(AppError::from_domain(err))
     };
@@ -66,9 +96,9 @@ pub async fn get_user_by_document(State(state): State<Arc<Container>>, Path(docu
     result
 }
 
-pub async fn delete_user_by_document(State(state): State<Arc<Container>>, Path(document): Path<String>)-> Result<(), AppError> {
+pub async fn delete_user_by_document(State(state): State<Arc<Container>>, Extension(tenant): Extension<Tenant>, Path(document): Path<String>)-> Result<(), AppError> {
     let mut span = state.tracer.start("delete.user");
-    let result = match state.user_use_case.delete(document.as_str()).await {
+    let result = match state.user_use_case.delete(&tenant, document.as_str()).await {
         Ok(()) => Ok(()),
         Err(err) => Err(AppError::from_domain(err))
     };
@@ -84,3 +114,129 @@ pub async fn delete_user_by_document(State(state): State<Arc<Container>>, Path(d
     span.end();
     result
 }
+
+pub async fn restore_user_by_document(
+    State(state): State<Arc<Container>>,
+    Extension(tenant): Extension<Tenant>,
+    Extension(principal): Extension<Principal>,
+    Path(document): Path<String>
+) -> Result<(), AppError> {
+    let mut span = state.tracer.start("restore.user");
+    let result = match state.user_use_case.restore(&tenant, &principal.subject, document.as_str()).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "restore_error", "error restoring user {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn list_users(
+    State(state): State<Arc<Container>>,
+    Extension(tenant): Extension<Tenant>,
+    Extension(principal): Extension<Principal>,
+    Query(query): Query<UserListQueryDTO>
+) -> Result<Json<UserListResponseDTO>, AppError> {
+    let mut span = state.tracer.start("list.users");
+    // only admins see full documents
+    let mask_documents = principal.role != Role::Admin;
+    let result = match state.user_use_case.list(&tenant, query, mask_documents).await {
+        Ok(u) => Ok(Json(u)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "list_users_error", "error listing users {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn search_users(
+    State(state): State<Arc<Container>>,
+    Extension(tenant): Extension<Tenant>,
+    Extension(principal): Extension<Principal>,
+    Query(query): Query<UserSearchQueryDTO>
+) -> Result<Json<Vec<PublicUserResponseDTO>>, AppError> {
+    let mut span = state.tracer.start("search.users");
+    // only admins see full documents
+    let mask_documents = principal.role != Role::Admin;
+    let result = match state.user_use_case.search(&tenant, query, mask_documents).await {
+        Ok(u) => Ok(Json(u)),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "search_users_error", "error searching users {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn block_user_by_document(
+    State(state): State<Arc<Container>>,
+    Extension(tenant): Extension<Tenant>,
+    Extension(principal): Extension<Principal>,
+    Path(document): Path<String>,
+    Json(payload): Json<UserStatusChangeRequestDTO>
+) -> Result<(), AppError> {
+    let mut span = state.tracer.start("block.user");
+    let result = match state.user_use_case.block(&tenant, &principal.subject, document.as_str(), payload).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "block_error", "error blocking user {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
+
+pub async fn unblock_user_by_document(
+    State(state): State<Arc<Container>>,
+    Extension(tenant): Extension<Tenant>,
+    Extension(principal): Extension<Principal>,
+    Path(document): Path<String>,
+    Json(payload): Json<UserStatusChangeRequestDTO>
+) -> Result<(), AppError> {
+    let mut span = state.tracer.start("unblock.user");
+    let result = match state.user_use_case.unblock(&tenant, &principal.subject, document.as_str(), payload).await {
+        Ok(()) => Ok(()),
+        Err(err) => Err(AppError::from_domain(err))
+    };
+
+    if let Err(e) = &result {
+        span.record_error(e);
+        span.set_status(Status::Error { description: Cow::from(e.get_message()) });
+        error!(target: "unblock_error", "error unblocking user {e}");
+    } else {
+        span.set_status(Status::Ok);
+    }
+
+    span.end();
+    result
+}
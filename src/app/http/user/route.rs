@@ -1,16 +1,30 @@
 use axum::{
     Router,
-    routing::{post, put, get, delete},
+    routing::{post, get, patch, delete},
     middleware, extract::State
 };
 use std::sync::Arc;
-use super::handler::{create_user, update_user, get_user_by_document, delete_user_by_document};
-use crate::app::{container::Container, http::middlewares::admin::admin_layer};
+use super::handler::{create_user, update_user, patch_user_by_document, get_user_by_document, delete_user_by_document, restore_user_by_document, block_user_by_document, unblock_user_by_document, list_users, search_users};
+use crate::app::{container::Container, http::middlewares::permission::permission_layer};
+use crate::domain::entities::Permission;
 
 pub fn build_routes(State(state): State<Arc<Container>>) -> Router<Arc<Container>> {
-    Router::new().route("/", post(create_user))
-        .route("/", put(update_user))
-        .route("/:document", get(get_user_by_document))
-        .route("/:document", delete(delete_user_by_document))
-        .layer(middleware::from_fn_with_state(state.clone(), admin_layer ))
-}
+    Router::new().route("/", post(create_user).put(update_user)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersWrite), permission_layer )))
+        .route("/", get(list_users)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersRead), permission_layer )))
+        .route("/search", get(search_users)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersRead), permission_layer )))
+        .route("/:document", get(get_user_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersRead), permission_layer )))
+        .route("/:document", patch(patch_user_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersWrite), permission_layer )))
+        .route("/:document", delete(delete_user_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersDelete), permission_layer )))
+        .route("/:document/restore", post(restore_user_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersRestore), permission_layer )))
+        .route("/:document/block", post(block_user_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersBlock), permission_layer )))
+        .route("/:document/unblock", post(unblock_user_by_document)
+            .layer(middleware::from_fn_with_state((state.clone(), Permission::UsersBlock), permission_layer )))
+}
\ No newline at end of file
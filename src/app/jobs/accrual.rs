@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use chrono::Utc;
+use log::{error, info};
+use crate::app::container::Container;
+
+/// Runs the fee accrual on a fixed interval for as long as the server is up.
+/// Charges are idempotent per period, so running it more than once a day is harmless.
+pub async fn run(container: Arc<Container>) {
+    let mut interval = tokio::time::interval(container.accrual_interval);
+
+    loop {
+        interval.tick().await;
+
+        let reference_date = Utc::now().date_naive();
+        match container.accrual_use_case.run(reference_date, container.accrual_dry_run).await {
+            Ok(report) => info!(
+                target: "accrual_job",
+                "accrual for {} charged {} fees totaling {} (dry run: {})",
+                report.reference_date, report.charges.len(), report.total, report.dry_run
+            ),
+            Err(e) => error!(target: "accrual_job_error", "error running accrual {}", e.get_message())
+        }
+    }
+}
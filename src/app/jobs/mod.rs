@@ -0,0 +1,2 @@
+pub mod accrual;
+pub mod statement;
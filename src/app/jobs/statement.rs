@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use chrono::Utc;
+use log::{error, info};
+use crate::app::container::Container;
+
+/// Closes the billing cycles that ended since the last run. Each cycle is billed
+/// once, so the interval only bounds how late a statement can be generated.
+pub async fn run(container: Arc<Container>) {
+    let mut interval = tokio::time::interval(container.statement_interval);
+
+    loop {
+        interval.tick().await;
+
+        let reference_date = Utc::now().date_naive();
+        match container.statement_use_case.close_cycles(reference_date).await {
+            Ok(generated) => info!(target: "statement_job", "closed {} billing cycles up to {}", generated, reference_date),
+            Err(e) => error!(target: "statement_job_error", "error closing billing cycles {}", e.get_message())
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod http;
 pub mod container;
-pub mod env;
\ No newline at end of file
+pub mod env;
+pub mod jobs;
\ No newline at end of file
@@ -0,0 +1,197 @@
+use fiadors::app::{env, container::Container};
+use fiadors::data::usecases::admin::UseCase;
+use fiadors::data::usecases::store::protocols::repository::Repository as StoreRepository;
+use fiadors::infrastructure::{admin::PostgresRepository, store::PostgresRepository as StorePostgresRepository, uuid::Generator};
+use fiadors::domain::entities::Role;
+use fiadors::domain::usecases::admin::{AdminUseCase, TokenRequestDTO};
+use std::process::ExitCode;
+use std::time::Duration;
+use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
+
+const USAGE: &str = "usage:
+    fiado-admin issue [--duration <N>h|<N>d] [--subject <name>] [--role admin|store-manager|cashier] [--store <id>] [--refresh]
+    fiado-admin inspect <token>";
+
+enum Command {
+    Issue(TokenRequestDTO),
+    StartSession(TokenRequestDTO),
+    Inspect(String),
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let vars = match env::Vars::load() {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("invalid config: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let hasher = match Container::load_hasher(&vars) {
+        Ok(h) => h,
+        Err(e) => {
+            eprintln!("invalid password hashing config: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let key_set = match Container::load_key_set(&vars) {
+        Ok(k) => k,
+        Err(e) => {
+            eprintln!("invalid token signing config: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // only sessions and store-bound tokens touch the database, so the connection is opened on first use
+    let conn_string = format!("postgresql://{}:{}@{}:{}/{}", vars.db_user, vars.db_password, vars.db_host, vars.db_port, vars.db_name);
+    let pg_pool = match PgPoolOptions::new().connect_lazy(&conn_string) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("invalid database config: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // a token bound to a store that does not exist would act on nothing, or fail every request
+    let command = match command {
+        Command::Issue(dto) => check_store(&pg_pool, dto).await.map(Command::Issue),
+        Command::StartSession(dto) => check_store(&pg_pool, dto).await.map(Command::StartSession),
+        command => Ok(command)
+    };
+    let command = match command {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let use_case = UseCase::new(
+        Box::new(PostgresRepository::new(pg_pool)),
+        Box::new(Generator::new()),
+        Box::new(hasher),
+        key_set,
+        vars.admin_role_name,
+        vars.admin_access_token_duration,
+        vars.admin_token_duration
+    );
+
+    match command {
+        Command::Issue(dto) => match use_case.issue_token(dto).await {
+            Ok(token) => println!("{}", token),
+            Err(e) => {
+                eprintln!("error issuing token: code {} {}", e.get_code(), e.get_message());
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::StartSession(dto) => match use_case.start_session(dto).await {
+            Ok(pair) => println!("{}", serde_json::to_string_pretty(&pair).unwrap_or_default()),
+            Err(e) => {
+                eprintln!("error starting session: code {} {}", e.get_code(), e.get_message());
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Inspect(token) => match use_case.inspect_token(token).await {
+            Ok(claims) => println!("{}", serde_json::to_string_pretty(&claims).unwrap_or_default()),
+            Err(e) => {
+                eprintln!("error inspecting token: code {} {}", e.get_code(), e.get_message());
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Checks the store of store-bound tokens exists and uses its id as stored.
+async fn check_store(pg_pool: &Pool<Postgres>, mut dto: TokenRequestDTO) -> Result<TokenRequestDTO, String> {
+    let store_id = match dto.store_id.as_deref() {
+        Some(id) => id,
+        None => return Ok(dto)
+    };
+
+    match StorePostgresRepository::new(pg_pool.clone()).get_by_id(store_id).await {
+        Ok(store) => dto.store_id = Some(String::from(store.get_id())),
+        Err(e) => return Err(format!("invalid store {}: {}", store_id, e.get_message()))
+    }
+    Ok(dto)
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (command, rest) = match args.split_first() {
+        Some((c, r)) => (c.as_str(), r),
+        None => return Err(String::from("missing command"))
+    };
+
+    match command {
+        "issue" => {
+            let mut dto = TokenRequestDTO::default();
+            let mut refresh = false;
+            let mut options = rest.iter();
+            while let Some(option) = options.next() {
+                if option == "--refresh" {
+                    refresh = true;
+                    continue;
+                }
+
+                let value = match options.next() {
+                    Some(v) => v,
+                    None => return Err(format!("missing value for {}", option))
+                };
+
+                match option.as_str() {
+                    "--duration" => dto.duration = Some(parse_duration(value)?),
+                    "--subject" if !value.trim().is_empty() => dto.subject = Some(String::from(value.trim())),
+                    "--subject" => return Err(String::from("subject cannot be empty")),
+                    "--role" => dto.role = Some(parse_role(value)?),
+                    "--store" if !value.trim().is_empty() => dto.store_id = Some(String::from(value.trim())),
+                    "--store" => return Err(String::from("store cannot be empty")),
+                    _ => return Err(format!("unknown option {}", option))
+                }
+            }
+            match refresh {
+                true => Ok(Command::StartSession(dto)),
+                false => Ok(Command::Issue(dto))
+            }
+        },
+        "inspect" => match rest {
+            [token] => Ok(Command::Inspect(String::from(token.trim()))),
+            _ => Err(String::from("inspect expects exactly one token"))
+        },
+        _ => Err(format!("unknown command {}", command))
+    }
+}
+
+fn parse_role(value: &str) -> Result<Role, String> {
+    match Role::from_string(&value.replace('-', "_").to_uppercase()) {
+        Role::Customer | Role::Service | Role::Unknown => Err(format!("invalid role {}, use admin, store-manager or cashier", value)),
+        role => Ok(role)
+    }
+}
+
+/// Accepts hours or days, such as `12h` or `30d`.
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (amount, seconds_per_unit) = match value.char_indices().last() {
+        Some((i, 'h')) => (&value[..i], 3600),
+        Some((i, 'd')) => (&value[..i], 86400),
+        _ => return Err(format!("invalid duration {}, use hours (12h) or days (30d)", value))
+    };
+
+    match amount.parse::<u64>() {
+        Ok(n) if n > 0 => match n.checked_mul(seconds_per_unit) {
+            Some(seconds) => Ok(Duration::from_secs(seconds)),
+            None => Err(format!("duration {} is too long", value))
+        },
+        _ => Err(format!("invalid duration {}, use hours (12h) or days (30d)", value))
+    }
+}
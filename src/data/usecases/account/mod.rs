@@ -0,0 +1,113 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::domain::{
+    entities::{AccountStatus, CreditAccount, Tenant},
+    error::Error,
+    types::billing_cycle::BillingCycle,
+    usecases::{
+        account::{self, AccountUseCase, AccountCreateRequestDTO, AccountUpdateRequestDTO, AccountResponseDTO, billing_cycle_from_days},
+        user::check_user_status
+    }
+};
+use protocols::repository::Repository;
+use crate::data::usecases::user::{get_user_by_document, protocols::repository::Repository as UserRepository};
+use crate::data::usecases::store::protocols::repository::Repository as StoreRepository;
+use crate::data::protocols::uuid::Uuid;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    user_repository: Box<dyn UserRepository + Send + Sync>,
+    store_repository: Box<dyn StoreRepository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        user_repository: Box<dyn UserRepository + Send + Sync>,
+        store_repository: Box<dyn StoreRepository + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>
+    ) -> UseCase {
+        UseCase { repository, user_repository, store_repository, uuid_generator }
+    }
+}
+
+#[async_trait]
+impl AccountUseCase for UseCase {
+    async fn create(&self, tenant: &Tenant, dto: AccountCreateRequestDTO) -> Result<(), Error> {
+        if dto.credit_limit.is_negative() {
+            return Err(Error::new_business(account::INVALID_CREDIT_LIMIT_ERROR));
+        }
+
+        let billing_cycle = billing_cycle_from_days(BillingCycle::default(), dto.closing_day, dto.due_day)?;
+
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, &dto.document).await?;
+        check_user_status(user.get_status())?;
+
+        // store staff always open accounts for their own store, which must be the customer's
+        let store_id = match tenant.get_store_id().map(String::from).or(dto.store_id) {
+            Some(id) if user.get_store_id() != Some(id.as_str()) => return Err(Error::new_business(account::ACCOUNT_STORE_MISMATCH_ERROR)),
+            Some(id) => Some(String::from(self.store_repository.get_by_id(&id).await?.get_id())),
+            None => user.get_store_id().map(String::from)
+        };
+
+        let mut account = CreditAccount::new(String::from(user.get_id()), dto.credit_limit);
+        account.set_uuid(self.uuid_generator.generate());
+        account.set_store_id(store_id);
+        account.set_billing_cycle(billing_cycle);
+
+        self.repository.create(tenant, account).await
+    }
+
+    async fn update(&self, tenant: &Tenant, document: &str, dto: AccountUpdateRequestDTO) -> Result<(), Error> {
+        if dto.credit_limit.is_negative() {
+            return Err(Error::new_business(account::INVALID_CREDIT_LIMIT_ERROR));
+        }
+
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        let mut account = self.repository.get_by_user_id(tenant, user.get_id()).await?;
+
+        if !account.is_open() {
+            return Err(Error::new_business(account::ACCOUNT_CLOSED_ERROR));
+        }
+
+        // blocked customers may have their limit lowered but never raised
+        if dto.credit_limit > account.get_credit_limit() {
+            check_user_status(user.get_status())?;
+        }
+
+        let billing_cycle = billing_cycle_from_days(account.get_billing_cycle(), dto.closing_day, dto.due_day)?;
+
+        account.set_credit_limit(dto.credit_limit);
+        account.set_billing_cycle(billing_cycle);
+        account.set_updated_at(Utc::now());
+        self.repository.update(tenant, account).await
+    }
+
+    async fn get(&self, tenant: &Tenant, document: &str) -> Result<AccountResponseDTO, Error> {
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        let account = self.repository.get_by_user_id(tenant, user.get_id()).await?;
+        Ok(AccountResponseDTO::from_account(account, *user.get_document()))
+    }
+
+    async fn close(&self, tenant: &Tenant, document: &str) -> Result<(), Error> {
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        let mut account = self.repository.get_by_user_id(tenant, user.get_id()).await?;
+
+        if !account.is_open() {
+            return Err(Error::new_business(account::ACCOUNT_CLOSED_ERROR));
+        }
+
+        if !account.get_balance().is_zero() {
+            return Err(Error::new_business(account::OUTSTANDING_BALANCE_ERROR));
+        }
+
+        account.set_status(AccountStatus::Closed);
+        account.set_updated_at(Utc::now());
+        self.repository.update(tenant, account).await
+    }
+}
+
+mod tests;
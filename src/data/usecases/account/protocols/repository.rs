@@ -0,0 +1,14 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{
+    entities::{CreditAccount, Tenant},
+    error::Error
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    async fn create(&self, tenant: &Tenant, account: CreditAccount) -> Result<(), Error>;
+    async fn update(&self, tenant: &Tenant, account: CreditAccount) -> Result<(), Error>;
+    async fn get_by_user_id(&self, tenant: &Tenant, user_id: &str) -> Result<CreditAccount, Error>;
+}
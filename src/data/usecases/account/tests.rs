@@ -0,0 +1,279 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_return_error_when_credit_limit_is_negative() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::account::{AccountUseCase, AccountCreateRequestDTO, INVALID_CREDIT_LIMIT_ERROR};
+    use crate::domain::types::money::Money;
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+    let dto = AccountCreateRequestDTO {
+        document: String::from("40735626065"),
+        credit_limit: Money::from_cents(-1),
+        closing_day: None,
+        due_day: None,
+        store_id: None
+    };
+
+    let result = sut.create(&Tenant::Platform, dto).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == INVALID_CREDIT_LIMIT_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_return_error_when_user_is_blocked() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, UserStatus, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::account::{AccountUseCase, AccountCreateRequestDTO};
+    use crate::domain::usecases::user::USER_BLOCKED_ERROR;
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_status(UserStatus::Blocked);
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(user_repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+    let dto = AccountCreateRequestDTO {
+        document: cpf.to_string(),
+        credit_limit: Money::from_cents(10000),
+        closing_day: None,
+        due_day: None,
+        store_id: None
+    };
+
+    let result = sut.create(&Tenant::Platform, dto).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == USER_BLOCKED_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_create_an_open_account_on_success() {
+    use chrono::NaiveDate;
+    use mockall::predicate::eq;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::account::{AccountUseCase, AccountCreateRequestDTO};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_uuid(String::from("user_id"));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_uuid(String::from("uuid"));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().with(eq(Tenant::Platform), eq(account)).return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(MockStoreRepository::new()), Box::new(uuid_mock));
+    let dto = AccountCreateRequestDTO {
+        document: cpf.to_string(),
+        credit_limit: Money::from_cents(10000),
+        closing_day: None,
+        due_day: None,
+        store_id: None
+    };
+
+    let result = sut.create(&Tenant::Platform, dto).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn it_should_scope_the_account_to_an_existing_store() {
+    use chrono::NaiveDate;
+    use mockall::predicate::eq;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Store, Tenant};
+    use crate::domain::types::{cpf::CPF, cnpj::CNPJ, birth_date::BirthDate};
+    use crate::domain::usecases::account::{AccountUseCase, AccountCreateRequestDTO};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_uuid(String::from("user_id"));
+    user.set_store_id(Some(String::from("store_id")));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut store = Store::new(String::from("store"), CNPJ::from_string(String::from("11222333000181")).unwrap());
+    store.set_uuid(String::from("store_id"));
+    let mut store_repository_mock = MockStoreRepository::new();
+    store_repository_mock.expect_get_by_id().with(eq("store_id")).times(1).return_const(Ok(store));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_uuid(String::from("uuid"));
+    account.set_store_id(Some(String::from("store_id")));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().with(eq(Tenant::Platform), eq(account)).return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(store_repository_mock), Box::new(uuid_mock));
+    let dto = AccountCreateRequestDTO {
+        document: cpf.to_string(),
+        credit_limit: Money::from_cents(10000),
+        closing_day: None,
+        due_day: None,
+        store_id: Some(String::from("store_id"))
+    };
+
+    let result = sut.create(&Tenant::Platform, dto).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn it_should_not_open_an_account_in_a_store_other_than_the_customer_one() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::account::{AccountUseCase, AccountCreateRequestDTO, ACCOUNT_STORE_MISMATCH_ERROR};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_uuid(String::from("user_id"));
+    user.set_store_id(Some(String::from("store_id")));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut store_repository_mock = MockStoreRepository::new();
+    store_repository_mock.expect_get_by_id().never();
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(store_repository_mock), Box::new(MockUuid::new()));
+    let dto = AccountCreateRequestDTO {
+        document: cpf.to_string(),
+        credit_limit: Money::from_cents(10000),
+        closing_day: None,
+        due_day: None,
+        store_id: Some(String::from("other_store_id"))
+    };
+
+    let result = sut.create(&Tenant::Platform, dto).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == ACCOUNT_STORE_MISMATCH_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_not_close_an_account_with_outstanding_balance() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::account::{AccountUseCase, OUTSTANDING_BALANCE_ERROR};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(500));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_user_id().return_const(Ok(account));
+    repository_mock.expect_update().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+
+    let result = sut.close(&Tenant::Platform, &cpf.to_string()).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == OUTSTANDING_BALANCE_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_not_raise_the_credit_limit_of_a_blocked_user() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::account::UseCase;
+    use crate::data::usecases::account::protocols::repository::MockRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, UserStatus, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::account::{AccountUseCase, AccountUpdateRequestDTO};
+    use crate::domain::usecases::user::USER_BLOCKED_ERROR;
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_status(UserStatus::Blocked);
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_user_id().return_const(Ok(account));
+    repository_mock.expect_update().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+    let dto = AccountUpdateRequestDTO {
+        credit_limit: Money::from_cents(20000),
+        closing_day: None,
+        due_day: None
+    };
+
+    let result = sut.update(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == USER_BLOCKED_ERROR
+    });
+}
@@ -0,0 +1,111 @@
+pub mod protocols;
+
+use std::collections::BTreeMap;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use crate::domain::{
+    entities::{Fee, FeePolicy, Installment, LedgerTransaction, Tenant},
+    error::Error,
+    types::money::Money,
+    usecases::accrual::{AccrualUseCase, AccrualReportDTO, FeeChargeDTO, INVALID_REFERENCE_DATE_ERROR}
+};
+use protocols::repository::Repository;
+use crate::data::protocols::uuid::Uuid;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>,
+    default_policy: FeePolicy
+}
+
+impl UseCase {
+    /// `default_policy` applies to accounts whose store has no fee policy of its own.
+    pub fn new(repository: Box<dyn Repository + Send + Sync>, uuid_generator: Box<dyn Uuid + Send + Sync>, default_policy: FeePolicy) -> UseCase {
+        UseCase { repository, uuid_generator, default_policy }
+    }
+}
+
+/// Computes the fees due for the given overdue installments: a late fee per installment
+/// and one interest charge per account over its overdue balance for the period, at the
+/// rates of the installment's store or `default_policy` when it has none.
+pub fn compute_fees(default_policy: &FeePolicy, installments: &[(Installment, Option<FeePolicy>)], reference_date: NaiveDate) -> Result<Vec<Fee>, Error> {
+    let period = reference_date.format("%Y-%m").to_string();
+    let mut overdue_by_account: BTreeMap<&str, (Money, FeePolicy)> = BTreeMap::new();
+    let mut fees: Vec<Fee> = Vec::new();
+
+    for (installment, policy) in installments.iter().filter(|(i, _)| i.is_overdue(reference_date)) {
+        let policy = policy.unwrap_or(*default_policy);
+        let outstanding = installment.get_outstanding_amount();
+        let (overdue, _) = overdue_by_account.entry(installment.get_account_id()).or_insert((Money::zero(), policy));
+        *overdue = match overdue.checked_add(outstanding) {
+            Some(o) => o,
+            None => return Err(Error::new_internal("overdue balance overflow"))
+        };
+
+        let late_fee = outstanding.percentage_bps(policy.get_late_fee_bps());
+        if late_fee.is_positive() {
+            fees.push(Fee::late_fee(String::from(installment.get_account_id()), String::from(installment.get_id()), period.clone(), late_fee));
+        }
+    }
+
+    for (account_id, (overdue, policy)) in overdue_by_account {
+        let interest = overdue.percentage_bps(policy.get_monthly_interest_bps());
+        if interest.is_positive() {
+            fees.push(Fee::interest(String::from(account_id), period.clone(), interest));
+        }
+    }
+    Ok(fees)
+}
+
+#[async_trait]
+impl AccrualUseCase for UseCase {
+    async fn run(&self, reference_date: NaiveDate, dry_run: bool) -> Result<AccrualReportDTO, Error> {
+        // accruals are charged for every store at once
+        let tenant = Tenant::Platform;
+
+        // posted fees cannot be reversed, so charges are never brought forward nor backdated
+        if reference_date > Utc::now().date_naive() {
+            return Err(Error::new_business_with_message(INVALID_REFERENCE_DATE_ERROR, "reference date is in the future"));
+        }
+        if let Some(last) = self.repository.get_last_reference_date(&tenant).await? {
+            if reference_date < last {
+                return Err(Error::new_business_with_message(INVALID_REFERENCE_DATE_ERROR, "reference date is before the last run"));
+            }
+        }
+
+        let installments = self.repository.get_overdue_installments(&tenant, reference_date).await?;
+        let fees = compute_fees(&self.default_policy, &installments, reference_date)?;
+
+        let keys: Vec<String> = fees.iter().map(|f| String::from(f.get_idempotency_key())).collect();
+        let charged_keys = if keys.is_empty() { Vec::new() } else { self.repository.get_charged_keys(&tenant, keys).await? };
+
+        let mut charges: Vec<FeeChargeDTO> = Vec::new();
+        let mut total = Money::zero();
+        for mut fee in fees.into_iter().filter(|f| !charged_keys.iter().any(|k| k == f.get_idempotency_key())) {
+            let charge = FeeChargeDTO::from_fee(&fee);
+
+            if !dry_run {
+                fee.set_uuid(self.uuid_generator.generate());
+                let transaction = LedgerTransaction::for_fee(self.uuid_generator.generate(), &fee);
+                // another run may have charged it between the lookup and now
+                if !self.repository.create(&tenant, fee, transaction).await? {
+                    continue;
+                }
+            }
+
+            total = match total.checked_add(charge.amount) {
+                Some(t) => t,
+                None => return Err(Error::new_internal("accrual total overflow"))
+            };
+            charges.push(charge);
+        }
+
+        if !dry_run {
+            self.repository.record_run(&tenant, reference_date).await?;
+        }
+
+        Ok(AccrualReportDTO { reference_date, dry_run, charges, total })
+    }
+}
+
+mod tests;
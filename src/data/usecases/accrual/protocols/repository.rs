@@ -0,0 +1,23 @@
+use mockall::automock;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::{
+    entities::{Fee, FeePolicy, Installment, LedgerTransaction, Tenant},
+    error::Error
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    /// Unsettled installments of open accounts whose due date is before `reference_date`,
+    /// each with the fee policy of its account's store when the store has one.
+    async fn get_overdue_installments(&self, tenant: &Tenant, reference_date: NaiveDate) -> Result<Vec<(Installment, Option<FeePolicy>)>, Error>;
+    /// Returns which of the given idempotency keys were already charged.
+    async fn get_charged_keys(&self, tenant: &Tenant, keys: Vec<String>) -> Result<Vec<String>, Error>;
+    /// Stores the fee, posts its ledger transaction and adds it to the account balance
+    /// atomically. Returns false, without posting anything, if the fee was already charged.
+    async fn create(&self, tenant: &Tenant, fee: Fee, transaction: LedgerTransaction) -> Result<bool, Error>;
+    /// Reference date of the latest run that posted its charges, if any.
+    async fn get_last_reference_date(&self, tenant: &Tenant) -> Result<Option<NaiveDate>, Error>;
+    async fn record_run(&self, tenant: &Tenant, reference_date: NaiveDate) -> Result<(), Error>;
+}
@@ -0,0 +1,142 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_preview_late_fees_and_interest_without_posting_on_dry_run() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::accrual::UseCase;
+    use crate::data::usecases::accrual::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{FeeKind, FeePolicy, Installment};
+    use crate::domain::usecases::accrual::AccrualUseCase;
+    use crate::domain::types::money::Money;
+
+    let due_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let mut overdue = Installment::new(String::from("purchase_id"), String::from("account_id"), 1, Money::from_cents(10000), due_date);
+    overdue.set_uuid(String::from("overdue"));
+    overdue.set_paid_amount(Money::from_cents(5000));
+    let mut settled = Installment::new(String::from("purchase_id"), String::from("account_id"), 2, Money::from_cents(2000), due_date);
+    settled.set_uuid(String::from("settled"));
+    settled.set_paid_amount(Money::from_cents(2000));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_overdue_installments().return_const(Ok(vec![(overdue, None), (settled, None)]));
+    repository_mock.expect_get_charged_keys().return_const(Ok(vec![]));
+    repository_mock.expect_get_last_reference_date().return_const(Ok(None));
+    repository_mock.expect_create().never();
+    repository_mock.expect_record_run().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), FeePolicy::new(200, 100).unwrap());
+
+    let result = sut.run(NaiveDate::from_ymd_opt(2024, 4, 15).unwrap(), true).await.unwrap();
+    assert!(result.dry_run);
+    assert_eq!(result.charges.len(), 2);
+    assert_eq!(result.charges[0].kind, FeeKind::LateFee);
+    assert_eq!(result.charges[0].amount, Money::from_cents(100));
+    assert_eq!(result.charges[1].kind, FeeKind::Interest);
+    assert_eq!(result.charges[1].period, "2024-04");
+    assert_eq!(result.charges[1].amount, Money::from_cents(50));
+    assert_eq!(result.total, Money::from_cents(150));
+}
+
+#[tokio::test]
+async fn it_should_not_charge_fees_already_posted_for_the_period() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::accrual::UseCase;
+    use crate::data::usecases::accrual::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{FeeKind, FeePolicy, Installment, Tenant, TransactionKind};
+    use crate::domain::usecases::accrual::AccrualUseCase;
+    use crate::domain::types::money::Money;
+
+    let due_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let mut installment = Installment::new(String::from("purchase_id"), String::from("account_id"), 1, Money::from_cents(10000), due_date);
+    installment.set_uuid(String::from("installment"));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_overdue_installments().return_const(Ok(vec![(installment, None)]));
+    repository_mock.expect_get_charged_keys().return_const(Ok(vec![String::from("LATE_FEE:installment")]));
+    repository_mock.expect_create()
+        .times(1)
+        .withf(|tenant, fee, transaction| *tenant == Tenant::Platform && fee.get_kind() == FeeKind::Interest && transaction.get_kind() == TransactionKind::Fee && transaction.get_receivable_change() == Some(Money::from_cents(100)))
+        .return_const(Ok(true));
+    repository_mock.expect_get_last_reference_date().return_const(Ok(Some(NaiveDate::from_ymd_opt(2024, 4, 14).unwrap())));
+    repository_mock.expect_record_run()
+        .times(1)
+        .withf(|_, reference_date| *reference_date == NaiveDate::from_ymd_opt(2024, 4, 15).unwrap())
+        .return_const(Ok(()));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), FeePolicy::new(200, 100).unwrap());
+
+    let result = sut.run(NaiveDate::from_ymd_opt(2024, 4, 15).unwrap(), false).await.unwrap();
+    assert_eq!(result.charges.len(), 1);
+    assert_eq!(result.total, Money::from_cents(100));
+}
+
+#[tokio::test]
+async fn it_should_refuse_reference_dates_in_the_future_or_before_the_last_run() {
+    use chrono::{Duration, NaiveDate, Utc};
+    use crate::data::usecases::accrual::UseCase;
+    use crate::data::usecases::accrual::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::FeePolicy;
+    use crate::domain::usecases::accrual::{AccrualUseCase, INVALID_REFERENCE_DATE_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_last_reference_date().return_const(Ok(Some(NaiveDate::from_ymd_opt(2024, 4, 15).unwrap())));
+    repository_mock.expect_get_overdue_installments().never();
+    repository_mock.expect_create().never();
+    repository_mock.expect_record_run().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), FeePolicy::new(200, 100).unwrap());
+
+    let tomorrow = Utc::now().date_naive() + Duration::days(1);
+    for reference_date in [tomorrow, NaiveDate::from_ymd_opt(2024, 4, 14).unwrap()] {
+        let result = sut.run(reference_date, false).await;
+        assert!(match result {
+            Ok(_) => false,
+            Err(e) => e.get_code() == INVALID_REFERENCE_DATE_ERROR
+        });
+    }
+}
+
+#[tokio::test]
+async fn it_should_charge_each_account_at_its_store_rates() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::accrual::compute_fees;
+    use crate::domain::entities::{FeeKind, FeePolicy, Installment};
+    use crate::domain::types::money::Money;
+
+    let due_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let mut store_installment = Installment::new(String::from("purchase_id"), String::from("store_account"), 1, Money::from_cents(10000), due_date);
+    store_installment.set_uuid(String::from("store_installment"));
+    let mut platform_installment = Installment::new(String::from("purchase_id"), String::from("platform_account"), 1, Money::from_cents(10000), due_date);
+    platform_installment.set_uuid(String::from("platform_installment"));
+
+    let installments = vec![
+        (store_installment, Some(FeePolicy::new(100, 50).unwrap())),
+        (platform_installment, None)
+    ];
+
+    let fees = compute_fees(&FeePolicy::new(200, 100).unwrap(), &installments, NaiveDate::from_ymd_opt(2024, 4, 15).unwrap()).unwrap();
+    let amount = |account_id: &str, kind: FeeKind| fees.iter()
+        .find(|f| f.get_account_id() == account_id && f.get_kind() == kind)
+        .map(|f| f.get_amount());
+
+    assert_eq!(amount("store_account", FeeKind::LateFee), Some(Money::from_cents(100)));
+    assert_eq!(amount("store_account", FeeKind::Interest), Some(Money::from_cents(50)));
+    assert_eq!(amount("platform_account", FeeKind::LateFee), Some(Money::from_cents(200)));
+    assert_eq!(amount("platform_account", FeeKind::Interest), Some(Money::from_cents(100)));
+}
+
+#[tokio::test]
+async fn it_should_reject_fee_policies_above_the_legal_limits() {
+    use crate::domain::entities::{FeePolicy, LEGAL_MAX_LATE_FEE_BPS, LEGAL_MAX_MONTHLY_INTEREST_BPS};
+
+    assert!(FeePolicy::new(LEGAL_MAX_LATE_FEE_BPS, LEGAL_MAX_MONTHLY_INTEREST_BPS).is_ok());
+    assert!(FeePolicy::new(LEGAL_MAX_LATE_FEE_BPS + 1, 0).is_err());
+    assert!(FeePolicy::new(0, LEGAL_MAX_MONTHLY_INTEREST_BPS + 1).is_err());
+}
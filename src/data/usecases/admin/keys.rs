@@ -0,0 +1,181 @@
+use crate::domain::usecases::admin::{JsonWebKeyDTO, JsonWebKeySetDTO};
+use jsonwebtoken::{Header, Algorithm, encode, EncodingKey, DecodingKey, decode, decode_header, Validation, crypto, errors::{self, ErrorKind}};
+use serde::{Serialize, de::DeserializeOwned};
+use simple_asn1::{from_der, ASN1Block};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+
+const RSA_ENCRYPTION_OID: [u64; 7] = [1, 2, 840, 113549, 1, 1, 1];
+const ED25519_OID: [u64; 4] = [1, 3, 101, 112];
+const ED25519_KEY_LENGTH: usize = 32;
+
+struct VerificationKey {
+    id: Option<String>,
+    decoding_key: DecodingKey,
+    jwk: Option<JsonWebKeyDTO>,
+}
+
+/// Keys signing and verifying staff tokens. Asymmetric tokens name their key in the `kid` header,
+/// so retired keys keep verifying the tokens they signed until they are removed from the set.
+pub struct KeySet {
+    algorithm: Algorithm,
+    signing_key_id: Option<String>,
+    encoding_key: EncodingKey,
+    verification_keys: Vec<VerificationKey>,
+}
+
+impl KeySet {
+    /// Shared secret keys are never published, tokens signed with them carry no `kid`.
+    pub fn new_hmac(secret: &str) -> KeySet {
+        KeySet {
+            algorithm: Algorithm::HS512,
+            signing_key_id: None,
+            encoding_key: EncodingKey::from_secret(secret.as_ref()),
+            verification_keys: vec![VerificationKey { id: None, decoding_key: DecodingKey::from_secret(secret.as_ref()), jwk: None }]
+        }
+    }
+
+    /// Loads a PEM private key for signing and the PEM public keys, identified by their `kid`,
+    /// that verify tokens. The signing key must be one of them.
+    pub fn new_asymmetric(algorithm: Algorithm, signing_key_id: &str, private_key: &[u8], public_keys: &[(String, Vec<u8>)]) -> Result<KeySet, String> {
+        let encoding_key = match algorithm {
+            Algorithm::RS256 => EncodingKey::from_rsa_pem(private_key),
+            Algorithm::EdDSA => EncodingKey::from_ed_pem(private_key),
+            _ => return Err(format!("unsupported signing algorithm {:?}, use RS256 or EdDSA", algorithm))
+        };
+
+        let encoding_key = match encoding_key {
+            Ok(k) => k,
+            Err(e) => return Err(format!("invalid private key {}: {}", signing_key_id, e))
+        };
+
+        let mut verification_keys: Vec<VerificationKey> = Vec::with_capacity(public_keys.len());
+        for (id, pem) in public_keys {
+            if verification_keys.iter().any(|k| k.id.as_deref() == Some(id.as_str())) {
+                return Err(format!("duplicated key id {}", id));
+            }
+            verification_keys.push(parse_public_key(algorithm, id, pem)?);
+        }
+
+        let key_set = KeySet { algorithm, signing_key_id: Some(String::from(signing_key_id)), encoding_key, verification_keys };
+        key_set.check_signing_key()?;
+        Ok(key_set)
+    }
+
+    pub fn encode<T: Serialize>(&self, claims: &T) -> Result<String, errors::Error> {
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.signing_key_id.clone();
+        encode(&header, claims, &self.encoding_key)
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, token: &str, validate_exp: bool) -> Result<T, errors::Error> {
+        let header = decode_header(token)?;
+        if header.alg != self.algorithm {
+            return Err(errors::Error::from(ErrorKind::InvalidAlgorithm));
+        }
+
+        let key = match self.signing_key_id {
+            None => self.verification_keys.first(),
+            Some(_) => match header.kid {
+                Some(kid) => self.verification_keys.iter().find(|k| k.id.as_deref() == Some(kid.as_str())),
+                None => None
+            }
+        };
+
+        let key = match key {
+            Some(k) => k,
+            None => return Err(errors::Error::from(ErrorKind::InvalidToken))
+        };
+
+        let mut validation = Validation::new(self.algorithm);
+        validation.validate_exp = validate_exp;
+        decode::<T>(token, &key.decoding_key, &validation).map(|data| data.claims)
+    }
+
+    pub fn get_jwks(&self) -> JsonWebKeySetDTO {
+        JsonWebKeySetDTO { keys: self.verification_keys.iter().filter_map(|k| k.jwk.clone()).collect() }
+    }
+
+    /// A private key without its public pair in the set would sign tokens nobody can verify.
+    fn check_signing_key(&self) -> Result<(), String> {
+        let signing_key_id = self.signing_key_id.as_deref().unwrap_or_default();
+        let key = match self.verification_keys.iter().find(|k| k.id.as_deref() == Some(signing_key_id)) {
+            Some(k) => k,
+            None => return Err(format!("no public key for signing key {}", signing_key_id))
+        };
+
+        let message = signing_key_id.as_bytes();
+        let verified = crypto::sign(message, &self.encoding_key, self.algorithm)
+            .and_then(|signature| crypto::verify(&signature, message, &key.decoding_key, self.algorithm));
+
+        match verified {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(format!("public key {} does not match the private key", signing_key_id)),
+            Err(e) => Err(format!("invalid signing key {}: {}", signing_key_id, e))
+        }
+    }
+}
+
+/// Reads a PEM encoded SubjectPublicKeyInfo into the decoding key and its JWK.
+fn parse_public_key(algorithm: Algorithm, id: &str, pem: &[u8]) -> Result<VerificationKey, String> {
+    let invalid_key = || format!("invalid public key {}", id);
+    let pem = match pem::parse(pem) {
+        Ok(p) if p.tag() == "PUBLIC KEY" => p,
+        _ => return Err(invalid_key())
+    };
+
+    let blocks = match from_der(pem.contents()) {
+        Ok(b) => b,
+        Err(_) => return Err(invalid_key())
+    };
+
+    let (oid, key) = match blocks.as_slice() {
+        [ASN1Block::Sequence(_, info)] => match info.as_slice() {
+            [ASN1Block::Sequence(_, identifier), ASN1Block::BitString(_, _, key)] => match identifier.first() {
+                Some(ASN1Block::ObjectIdentifier(_, oid)) => (oid.as_vec::<u64>().unwrap_or_default(), key),
+                _ => return Err(invalid_key())
+            },
+            _ => return Err(invalid_key())
+        },
+        _ => return Err(invalid_key())
+    };
+
+    let mut jwk = JsonWebKeyDTO {
+        kty: String::new(),
+        kid: String::from(id),
+        alg: format!("{:?}", algorithm),
+        key_use: String::from("sig"),
+        n: None,
+        e: None,
+        crv: None,
+        x: None
+    };
+
+    let decoding_key = match algorithm {
+        Algorithm::RS256 if oid == RSA_ENCRYPTION_OID => {
+            let (n, e) = match from_der(key).as_deref() {
+                Ok([ASN1Block::Sequence(_, parts)]) => match parts.as_slice() {
+                    [ASN1Block::Integer(_, n), ASN1Block::Integer(_, e)] => (URL_SAFE_NO_PAD.encode(n.to_bytes_be().1), URL_SAFE_NO_PAD.encode(e.to_bytes_be().1)),
+                    _ => return Err(invalid_key())
+                },
+                _ => return Err(invalid_key())
+            };
+
+            jwk.kty = String::from("RSA");
+            jwk.n = Some(n);
+            jwk.e = Some(e);
+            DecodingKey::from_rsa_components(jwk.n.as_deref().unwrap_or_default(), jwk.e.as_deref().unwrap_or_default())
+        },
+        Algorithm::EdDSA if oid == ED25519_OID && key.len() == ED25519_KEY_LENGTH => {
+            jwk.kty = String::from("OKP");
+            jwk.crv = Some(String::from("Ed25519"));
+            jwk.x = Some(URL_SAFE_NO_PAD.encode(key));
+            DecodingKey::from_ed_components(jwk.x.as_deref().unwrap_or_default())
+        },
+        _ => return Err(format!("public key {} is not a {:?} key", id, algorithm))
+    };
+
+    match decoding_key {
+        Ok(decoding_key) => Ok(VerificationKey { id: Some(String::from(id)), decoding_key, jwk: Some(jwk) }),
+        Err(_) => Err(invalid_key())
+    }
+}
@@ -1,89 +1,338 @@
+pub mod protocols;
+pub mod keys;
+
 use crate::domain::{
-    usecases::admin::{AdminUseCase, EXPIRED_TOKEN_ERROR, INVALID_TOKEN_ERROR},
-    error::Error
+    entities::{Role, Permission, RefreshToken},
+    usecases::admin::{
+        AdminUseCase, Principal, TokenRequestDTO, TokenClaimsDTO, TokenPairResponseDTO, RefreshRequestDTO,
+        LogoutRequestDTO, JsonWebKeySetDTO, EXPIRED_TOKEN_ERROR, INVALID_TOKEN_ERROR, TENANT_REQUIRED_ERROR
+    },
+    error::{Error, Kind}
 };
+use protocols::repository::Repository;
+use keys::KeySet;
+use crate::data::usecases::user::protocols::hash::Hash;
+use crate::data::protocols::uuid::Uuid;
+use chrono::{DateTime, Utc};
 use std::time::{Duration, SystemTime};
 use serde::{Serialize, Deserialize};
-use jsonwebtoken::{Header, Algorithm, encode, EncodingKey, DecodingKey, decode, Validation, errors::{self, ErrorKind}};
+use jsonwebtoken::errors::{self, ErrorKind};
 use async_trait::async_trait;
 
-const SECONDS_IN_A_DAY: u64 = 86400;
+const SECONDS_IN_A_MINUTE: u64 = 60;
+const TOKEN_SEPARATOR: char = '.';
 
 pub struct UseCase {
-    api_secret: String,
+    repository: Box<dyn Repository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>,
+    hash: Box<dyn Hash + Send + Sync>,
+    keys: KeySet,
     role_name: String,
-    token_duration_in_days: u64 
+    access_token_duration_in_minutes: u64,
+    refresh_token_duration_in_days: u64
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Claims {
     sub: String,
+    // tokens issued before the role claim existed carry the role as subject
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    // tokens without permissions get the ones of their role
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    perms: Option<Vec<String>>,
+    // only tokens with an id can be revoked, so it is required to validate them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jti: Option<String>,
+    // staff bound to a store only reach its customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    store: Option<String>,
     exp: u64,
 }
 
+impl Claims {
+    fn get_role_name(&self) -> &str {
+        self.role.as_deref().unwrap_or(self.sub.as_str())
+    }
+}
+
 impl UseCase {
-    pub fn new(api_secret: String, role_name: String, token_duration_in_days: u64) -> UseCase {
-        UseCase { api_secret, role_name, token_duration_in_days}
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>,
+        hash: Box<dyn Hash + Send + Sync>,
+        keys: KeySet,
+        role_name: String,
+        access_token_duration_in_minutes: u64,
+        refresh_token_duration_in_days: u64
+    ) -> UseCase {
+        UseCase { repository, uuid_generator, hash, keys, role_name, access_token_duration_in_minutes, refresh_token_duration_in_days }
     }
 }
 
 #[async_trait]
 impl AdminUseCase for UseCase {
-    async fn validate_token(&self, token: String) -> Result<(), Error> {
-        let mut validation = Validation::new(Algorithm::HS512);
-        validation.sub = Some(self.role_name.clone());
-
-        let result =decode::<Claims>(
-            token.as_str(),
-            &DecodingKey::from_secret(self.api_secret.as_ref()),
-            &validation
-        );
-
-        match result {
-            Ok(_) => Ok(()),
-            Err(e) => Err(to_domain_error(e))
+    async fn validate_token(&self, token: String) -> Result<Principal, Error> {
+        let claims = self.decode_claims(token.as_str(), true)?;
+        let role = self.get_role(&claims);
+        if matches!(role, Role::Customer | Role::Service | Role::Unknown) {
+            return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
         }
+
+        let token_id = match &claims.jti {
+            Some(jti) => jti.clone(),
+            None => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+
+        if self.repository.is_access_token_revoked(&token_id).await? {
+            return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
+        }
+
+        Ok(Principal {
+            permissions: get_permissions(&claims, role),
+            token_id,
+            subject: claims.sub,
+            store_id: claims.store,
+            role
+        })
     }   
     
     async fn generate_token(&self) -> Result<String, Error> {
-        let header = Header::new(Algorithm::HS512);
-        let expires_at = match self.get_expiration_timestamp() {
-            Ok(timestamp) => timestamp,
-            Err(e) => return Err(e)
+        self.issue_token(TokenRequestDTO::default()).await
+    }
+
+    async fn issue_token(&self, dto: TokenRequestDTO) -> Result<String, Error> {
+        let role = dto.role.unwrap_or(Role::Admin);
+        let subject = match dto.subject {
+            Some(s) => s,
+            None => self.get_role_name(role)?
         };
+        check_store(role, &dto.store_id)?;
 
-        let claims = Claims {
-            sub: self.role_name.clone(),
-            exp: expires_at
+        let duration = match dto.duration {
+            Some(d) => d,
+            None => Duration::from_secs(SECONDS_IN_A_MINUTE * self.access_token_duration_in_minutes)
+        };
+
+        let (token, _) = self.encode_access_token(subject, role, dto.store_id, duration)?;
+        Ok(token)
+    }
+
+    async fn inspect_token(&self, token: String) -> Result<TokenClaimsDTO, Error> {
+        // the signature is still checked, only expired tokens are let through
+        let claims = self.decode_claims(token.as_str(), false)?;
+        let expires_at = match DateTime::<Utc>::from_timestamp(claims.exp as i64, 0) {
+            Some(d) => d,
+            None => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+
+        let permissions = get_permissions(&claims, self.get_role(&claims));
+        Ok(TokenClaimsDTO {
+            role: String::from(claims.get_role_name()),
+            permissions: permissions.iter().map(|p| String::from(p.to_string())).collect(),
+            subject: claims.sub,
+            store_id: claims.store,
+            expires_at,
+            expired: expires_at <= Utc::now()
+        })
+    }
+    async fn start_session(&self, dto: TokenRequestDTO) -> Result<TokenPairResponseDTO, Error> {
+        let role = dto.role.unwrap_or(Role::Admin);
+        let subject = match dto.subject {
+            Some(s) => s,
+            None => self.get_role_name(role)?
+        };
+        check_store(role, &dto.store_id)?;
+
+        let (mut refresh_token, secret) = self.new_refresh_token(self.uuid_generator.generate(), subject, role)?;
+        refresh_token.set_store_id(dto.store_id);
+        self.repository.create_refresh_token(refresh_token.clone()).await?;
+        self.get_token_pair(&refresh_token, secret, dto.duration)
+    }
+
+    async fn refresh(&self, dto: RefreshRequestDTO) -> Result<TokenPairResponseDTO, Error> {
+        let current = self.get_refresh_token(&dto.refresh_token).await?;
+
+        // a replaced token showing up again means it leaked, so nobody keeps the session
+        if current.is_revoked() {
+            self.repository.revoke_refresh_token_family(current.get_family_id()).await?;
+            return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
+        }
+
+        if current.is_expired(Utc::now()) {
+            return Err(Error::new_unauthorized(EXPIRED_TOKEN_ERROR));
+        }
+
+        let (mut replacement, secret) = self.new_refresh_token(String::from(current.get_family_id()), String::from(current.get_subject()), current.get_role())?;
+        replacement.set_store_id(current.get_store_id().map(String::from));
+        if !self.repository.rotate_refresh_token(current.get_id(), replacement.clone()).await? {
+            self.repository.revoke_refresh_token_family(current.get_family_id()).await?;
+            return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
+        }
+
+        self.get_token_pair(&replacement, secret, None)
+    }
+
+    async fn logout(&self, access_token: String, dto: LogoutRequestDTO) -> Result<(), Error> {
+        let claims = self.decode_claims(access_token.as_str(), true)?;
+        let (jti, expires_at) = match (&claims.jti, DateTime::<Utc>::from_timestamp(claims.exp as i64, 0)) {
+            (Some(jti), Some(expires_at)) => (jti, expires_at),
+            _ => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
         };
 
-        match encode(&header, &claims, &EncodingKey::from_secret(self.api_secret.as_ref())) {
-            Ok(token) => Ok(token),
-            Err(e) => {
-                Err(Error::new_internal(e.to_string().as_str()))
+        if let Some(refresh_token) = dto.refresh_token {
+            let refresh_token = self.get_refresh_token(&refresh_token).await?;
+            if refresh_token.get_subject() != claims.sub {
+                return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
             }
+            self.repository.revoke_refresh_token_family(refresh_token.get_family_id()).await?;
         }
+
+        self.repository.revoke_access_token(jti, expires_at).await
+    }
+
+    async fn get_jwks(&self) -> Result<JsonWebKeySetDTO, Error> {
+        Ok(self.keys.get_jwks())
     }
 }
 
 impl UseCase {
-    fn get_expiration_timestamp(&self) -> Result<u64, Error> {
-        let now = SystemTime::now();
-        if let Some(expires_at) = now.checked_add(Duration::from_secs(SECONDS_IN_A_DAY * self.token_duration_in_days)) {
-
-            match expires_at.duration_since(std::time::UNIX_EPOCH) {
-                Ok(expires_at_time_since) => return Ok(expires_at_time_since.as_secs()),
-                Err(e) => return Err(Error::new_internal(&e.to_string()))
-            };
+    fn get_role_name(&self, role: Role) -> Result<String, Error> {
+        match role {
+            Role::Admin => Ok(self.role_name.clone()),
+            Role::StoreManager | Role::Cashier => Ok(String::from(role.to_string())),
+            Role::Customer | Role::Service | Role::Unknown => Err(Error::new_business_with_message(INVALID_TOKEN_ERROR, "staff tokens cannot be issued for this role"))
         }
-        Err(Error::new_internal("error generating expiration date"))
     }
+
+    /// Returns the token and its expiration timestamp.
+    fn encode_access_token(&self, subject: String, role: Role, store_id: Option<String>, duration: Duration) -> Result<(String, u64), Error> {
+        let expires_at = get_expiration_timestamp(duration)?;
+        let claims = Claims {
+            sub: subject,
+            role: Some(self.get_role_name(role)?),
+            perms: Some(role.get_permissions().iter().map(|p| String::from(p.to_string())).collect()),
+            jti: Some(self.uuid_generator.generate()),
+            store: store_id,
+            exp: expires_at
+        };
+
+        match self.keys.encode(&claims) {
+            Ok(token) => Ok((token, expires_at)),
+            Err(e) => Err(Error::new_internal(e.to_string().as_str()))
+        }
+    }
+
+    /// Returns the refresh token to be stored and the secret handed to the caller.
+    fn new_refresh_token(&self, family_id: String, subject: String, role: Role) -> Result<(RefreshToken, String), Error> {
+        let secret = self.uuid_generator.generate();
+        let token_hash = match self.hash.run(secret.clone()) {
+            Ok(h) => h,
+            Err(message) => return Err(Error::new_internal(&message))
+        };
+
+        let expires_at = Utc::now() + chrono::Duration::days(self.refresh_token_duration_in_days as i64);
+        let mut refresh_token = RefreshToken::new(family_id, subject, role, token_hash, expires_at);
+        refresh_token.set_uuid(self.uuid_generator.generate());
+        Ok((refresh_token, secret))
+    }
+
+    async fn get_refresh_token(&self, token: &str) -> Result<RefreshToken, Error> {
+        let (id, secret) = match token.trim().split_once(TOKEN_SEPARATOR) {
+            Some(parts) => parts,
+            None => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+
+        let refresh_token = match self.repository.get_refresh_token(id).await {
+            Ok(t) => t,
+            Err(e) if e.get_kind() == Kind::Internal => return Err(e),
+            Err(_) => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+
+        match self.hash.verify(String::from(secret), String::from(refresh_token.get_token_hash())) {
+            Ok(true) => Ok(refresh_token),
+            Ok(false) => Err(Error::new_unauthorized(INVALID_TOKEN_ERROR)),
+            Err(message) => Err(Error::new_internal(&message))
+        }
+    }
+
+    fn get_token_pair(&self, refresh_token: &RefreshToken, secret: String, duration: Option<Duration>) -> Result<TokenPairResponseDTO, Error> {
+        let duration = duration.unwrap_or(Duration::from_secs(SECONDS_IN_A_MINUTE * self.access_token_duration_in_minutes));
+        let (access_token, expires_at) = self.encode_access_token(
+            String::from(refresh_token.get_subject()),
+            refresh_token.get_role(),
+            refresh_token.get_store_id().map(String::from),
+            duration
+        )?;
+        let expires_at = match DateTime::<Utc>::from_timestamp(expires_at as i64, 0) {
+            Some(d) => d,
+            None => return Err(Error::new_internal("error generating expiration date"))
+        };
+
+        Ok(TokenPairResponseDTO {
+            access_token,
+            refresh_token: format!("{}{}{}", refresh_token.get_id(), TOKEN_SEPARATOR, secret),
+            token_type: String::from("Bearer"),
+            expires_at,
+            refresh_expires_at: refresh_token.get_expires_at()
+        })
+    }
+
+    /// Only the configured role name grants admin, so changing it invalidates old admin tokens.
+    fn get_role(&self, claims: &Claims) -> Role {
+        let role_name = claims.get_role_name();
+        if role_name == self.role_name {
+            return Role::Admin;
+        }
+
+        match Role::from_string(role_name) {
+            Role::Admin => Role::Unknown,
+            role => role
+        }
+    }
+
+    fn decode_claims(&self, token: &str, validate_exp: bool) -> Result<Claims, Error> {
+        match self.keys.decode::<Claims>(token, validate_exp) {
+            Ok(claims) => Ok(claims),
+            Err(e) => Err(to_domain_error(e))
+        }
+    }
+}
+
+fn get_permissions(claims: &Claims, role: Role) -> Vec<Permission> {
+    match &claims.perms {
+        Some(perms) => perms.iter()
+            .map(|p| Permission::from_string(p))
+            .filter(|p| *p != Permission::Unknown)
+            .collect(),
+        None => role.get_permissions().to_vec()
+    }
+}
+
+/// Staff other than admins only ever act on behalf of a store.
+fn check_store(role: Role, store_id: &Option<String>) -> Result<(), Error> {
+    match (role, store_id) {
+        (Role::Admin, _) | (_, Some(_)) => Ok(()),
+        _ => Err(Error::new_business_with_message(TENANT_REQUIRED_ERROR, "staff tokens for this role must be bound to a store"))
+    }
+}
+
+fn get_expiration_timestamp(duration: Duration) -> Result<u64, Error> {
+    let now = SystemTime::now();
+    if let Some(expires_at) = now.checked_add(duration) {
+
+        match expires_at.duration_since(std::time::UNIX_EPOCH) {
+            Ok(expires_at_time_since) => return Ok(expires_at_time_since.as_secs()),
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+    }
+    Err(Error::new_internal("error generating expiration date"))
 }
 
 fn to_domain_error(e: errors::Error) -> Error {
     match e.kind() {
-        ErrorKind::ExpiredSignature => Error::new_business(EXPIRED_TOKEN_ERROR),
-        ErrorKind::InvalidSubject | ErrorKind::InvalidSignature => Error::new_business(INVALID_TOKEN_ERROR),
+        ErrorKind::ExpiredSignature => Error::new_unauthorized(EXPIRED_TOKEN_ERROR),
+        ErrorKind::InvalidSubject | ErrorKind::InvalidAudience | ErrorKind::InvalidSignature | ErrorKind::InvalidToken | ErrorKind::InvalidAlgorithm | ErrorKind::Base64(_) | ErrorKind::Json(_) | ErrorKind::Utf8(_) => Error::new_unauthorized(INVALID_TOKEN_ERROR),
         _ => Error::new_internal(e.to_string().as_str())
     }
 }
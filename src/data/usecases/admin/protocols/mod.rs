@@ -0,0 +1 @@
+pub mod repository;
\ No newline at end of file
@@ -0,0 +1,17 @@
+use mockall::automock;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::{entities::RefreshToken, error::Error};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    async fn create_refresh_token(&self, token: RefreshToken) -> Result<(), Error>;
+    async fn get_refresh_token(&self, id: &str) -> Result<RefreshToken, Error>;
+    /// Revokes the token and stores its replacement, returns false when the token
+    /// was revoked in the meantime.
+    async fn rotate_refresh_token(&self, id: &str, replacement: RefreshToken) -> Result<bool, Error>;
+    async fn revoke_refresh_token_family(&self, family_id: &str) -> Result<(), Error>;
+    async fn revoke_access_token(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), Error>;
+    async fn is_access_token_revoked(&self, jti: &str) -> Result<bool, Error>;
+}
@@ -0,0 +1,135 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use ring::{digest, constant_time};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use crate::domain::{
+    entities::{ApiKey, Permission, Role, Tenant},
+    error::{Error, Kind},
+    usecases::{
+        admin::{Principal, INVALID_TOKEN_ERROR},
+        api_key::{
+            ApiKeyUseCase, CreateApiKeyRequestDTO, CreatedApiKeyResponseDTO, ApiKeyResponseDTO,
+            INVALID_API_KEY_REQUEST_ERROR
+        }
+    }
+};
+use protocols::repository::Repository;
+use crate::data::usecases::store::protocols::repository::Repository as StoreRepository;
+use crate::data::protocols::uuid::Uuid;
+
+const KEY_SEPARATOR: char = '.';
+const KEY_PREFIX: &str = "fk_";
+const KEY_PREFIX_LENGTH: usize = 12;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    store_repository: Box<dyn StoreRepository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(repository: Box<dyn Repository + Send + Sync>, store_repository: Box<dyn StoreRepository + Send + Sync>, uuid_generator: Box<dyn Uuid + Send + Sync>) -> UseCase {
+        UseCase { repository, store_repository, uuid_generator }
+    }
+
+    fn new_random_part(&self) -> String {
+        self.uuid_generator.generate().replace('-', "")
+    }
+}
+
+#[async_trait]
+impl ApiKeyUseCase for UseCase {
+    async fn create(&self, dto: CreateApiKeyRequestDTO) -> Result<CreatedApiKeyResponseDTO, Error> {
+        let name = dto.name.trim();
+        let store_id = dto.store_id.trim();
+        if name.is_empty() || store_id.is_empty() {
+            return Err(Error::new_business_with_message(INVALID_API_KEY_REQUEST_ERROR, "name and store_id are required"));
+        }
+
+        let permissions = get_permissions(&dto.permissions)?;
+        // the key acts as its store, so it can only be bound to one that exists
+        let store = self.store_repository.get_by_id(store_id).await?;
+
+        let prefix = format!("{}{}", KEY_PREFIX, self.new_random_part().chars().take(KEY_PREFIX_LENGTH).collect::<String>());
+        let secret = self.new_random_part();
+
+        let mut api_key = ApiKey::new(prefix, hash_secret(&secret), String::from(name), String::from(store.get_id()), permissions);
+        api_key.set_uuid(self.uuid_generator.generate());
+        // the key is written as its store, so row level security checks it lands there
+        self.repository.create_api_key(&Tenant::Store(String::from(api_key.get_store_id())), api_key.clone()).await?;
+
+        Ok(CreatedApiKeyResponseDTO {
+            key: format!("{}{}{}", api_key.get_prefix(), KEY_SEPARATOR, secret),
+            api_key: ApiKeyResponseDTO::from_api_key(&api_key)
+        })
+    }
+
+    async fn list(&self, store_id: Option<String>) -> Result<Vec<ApiKeyResponseDTO>, Error> {
+        let tenant = match store_id {
+            Some(id) => Tenant::Store(id),
+            None => Tenant::Platform
+        };
+        let api_keys = self.repository.list_api_keys(&tenant).await?;
+        Ok(api_keys.iter().map(ApiKeyResponseDTO::from_api_key).collect())
+    }
+
+    async fn revoke(&self, id: &str) -> Result<(), Error> {
+        self.repository.revoke_api_key(&Tenant::Platform, id).await
+    }
+
+    async fn validate_key(&self, key: String) -> Result<Principal, Error> {
+        let (prefix, secret) = match key.trim().split_once(KEY_SEPARATOR) {
+            Some(parts) => parts,
+            None => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+
+        // the store of the key is only known once it is found
+        let api_key = match self.repository.get_api_key_by_prefix(&Tenant::Platform, prefix).await {
+            Ok(k) => k,
+            Err(e) if e.get_kind() == Kind::Internal => return Err(e),
+            Err(_) => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+
+        let matches = constant_time::verify_slices_are_equal(hash_secret(secret).as_bytes(), api_key.get_secret_hash().as_bytes()).is_ok();
+        if !matches || api_key.is_revoked() {
+            return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
+        }
+
+        Ok(Principal {
+            token_id: String::from(api_key.get_id()),
+            subject: String::from(api_key.get_prefix()),
+            role: Role::Service,
+            permissions: api_key.get_permissions().to_vec(),
+            store_id: Some(String::from(api_key.get_store_id()))
+        })
+    }
+}
+
+/// Keys are checked on every request and their secrets are random, so a plain digest
+/// is enough where passwords would need a slow hash.
+fn hash_secret(secret: &str) -> String {
+    STANDARD.encode(digest::digest(&digest::SHA256, secret.as_bytes()).as_ref())
+}
+
+/// Only store level permissions are granted, otherwise a leaked terminal key could mint
+/// new keys, run accruals or edit stores.
+fn get_permissions(names: &[String]) -> Result<Vec<Permission>, Error> {
+    let mut permissions: Vec<Permission> = Vec::with_capacity(names.len());
+    for name in names {
+        match Permission::from_string(name.trim()) {
+            p if !p.is_grantable_to_api_key() => {
+                return Err(Error::new_business_with_message(INVALID_API_KEY_REQUEST_ERROR, &format!("permission {} cannot be granted to an API key", name)));
+            },
+            p if !permissions.contains(&p) => permissions.push(p),
+            _ => ()
+        }
+    }
+
+    if permissions.is_empty() {
+        return Err(Error::new_business_with_message(INVALID_API_KEY_REQUEST_ERROR, "at least one permission is required"));
+    }
+    Ok(permissions)
+}
+
+mod tests;
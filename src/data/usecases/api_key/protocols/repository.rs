@@ -0,0 +1,13 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{entities::{ApiKey, Tenant}, error::Error};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    async fn create_api_key(&self, tenant: &Tenant, api_key: ApiKey) -> Result<(), Error>;
+    async fn get_api_key_by_prefix(&self, tenant: &Tenant, prefix: &str) -> Result<ApiKey, Error>;
+    async fn list_api_keys(&self, tenant: &Tenant) -> Result<Vec<ApiKey>, Error>;
+    /// Revoking a revoked key keeps its original revocation date.
+    async fn revoke_api_key(&self, tenant: &Tenant, id: &str) -> Result<(), Error>;
+}
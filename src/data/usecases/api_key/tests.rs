@@ -0,0 +1,164 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_create_a_key_storing_only_the_hash_of_its_secret() {
+    use crate::data::usecases::api_key::{UseCase, hash_secret};
+    use crate::data::usecases::api_key::protocols::repository::MockRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use mockall::predicate::eq;
+    use crate::domain::entities::{Permission, Store, Tenant};
+    use crate::domain::types::cnpj::CNPJ;
+    use crate::domain::usecases::api_key::{ApiKeyUseCase, CreateApiKeyRequestDTO};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create_api_key()
+        .withf(|tenant, k| *tenant == Tenant::Store(String::from("store-01")) && k.get_prefix() == "fk_0a1b2c3d4e5f" && k.get_secret_hash() == hash_secret("0a1b2c3d4e5f67890a1b2c3d4e5f6789") && k.get_store_id() == "store-01")
+        .times(1)
+        .return_const(Ok(()));
+    let mut store = Store::new(String::from("store"), CNPJ::from_string(String::from("11222333000181")).unwrap());
+    store.set_uuid(String::from("store-01"));
+    let mut store_repository_mock = MockStoreRepository::new();
+    store_repository_mock.expect_get_by_id().with(eq("store-01")).times(1).return_const(Ok(store));
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const(String::from("0a1b2c3d-4e5f-6789-0a1b-2c3d4e5f6789"));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(store_repository_mock), Box::new(uuid_mock));
+    let dto = CreateApiKeyRequestDTO {
+        name: String::from(" front counter "),
+        store_id: String::from(" store-01 "),
+        permissions: vec![String::from("purchases:create"), String::from("users:read"), String::from("purchases:create")]
+    };
+
+    let created = sut.create(dto).await.unwrap();
+    assert_eq!(created.key, "fk_0a1b2c3d4e5f.0a1b2c3d4e5f67890a1b2c3d4e5f6789");
+    assert_eq!(created.api_key.name, "front counter");
+    assert_eq!(created.api_key.permissions, vec![Permission::PurchasesCreate.to_string(), Permission::UsersRead.to_string()]);
+}
+
+#[tokio::test]
+async fn it_should_not_create_keys_with_unknown_or_key_management_permissions() {
+    use crate::data::usecases::api_key::UseCase;
+    use crate::data::usecases::api_key::protocols::repository::MockRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::api_key::{ApiKeyUseCase, CreateApiKeyRequestDTO, INVALID_API_KEY_REQUEST_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create_api_key().never();
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+
+    for permissions in [vec![String::from("purchases:delete")], vec![String::from("api_keys:manage")], vec![]] {
+        let dto = CreateApiKeyRequestDTO { name: String::from("pos"), store_id: String::from("store-01"), permissions };
+        let result = sut.create(dto).await;
+        assert!(match result {
+            Ok(_) => false,
+            Err(e) => e.get_code() == INVALID_API_KEY_REQUEST_ERROR
+        });
+    }
+}
+
+#[tokio::test]
+async fn it_should_not_create_keys_with_permissions_beyond_the_store() {
+    use crate::data::usecases::api_key::UseCase;
+    use crate::data::usecases::api_key::protocols::repository::MockRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::api_key::{ApiKeyUseCase, CreateApiKeyRequestDTO, INVALID_API_KEY_REQUEST_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create_api_key().never();
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+
+    for permission in ["accruals:run", "stores:write"] {
+        let permissions = vec![String::from("purchases:create"), String::from(permission)];
+        let dto = CreateApiKeyRequestDTO { name: String::from("pos"), store_id: String::from("store-01"), permissions };
+        let result = sut.create(dto).await;
+        assert!(match result {
+            Ok(_) => false,
+            Err(e) => e.get_code() == INVALID_API_KEY_REQUEST_ERROR
+        });
+    }
+}
+
+#[tokio::test]
+async fn it_should_not_create_keys_for_unknown_stores() {
+    use crate::data::usecases::api_key::UseCase;
+    use crate::data::usecases::api_key::protocols::repository::MockRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::error::Error;
+    use crate::domain::usecases::api_key::{ApiKeyUseCase, CreateApiKeyRequestDTO};
+    use crate::domain::usecases::store::STORE_NOT_FOUND;
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create_api_key().never();
+    let mut store_repository_mock = MockStoreRepository::new();
+    store_repository_mock.expect_get_by_id().return_const(Err(Error::new_not_found(STORE_NOT_FOUND, "store")));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(store_repository_mock), Box::new(MockUuid::new()));
+    let dto = CreateApiKeyRequestDTO {
+        name: String::from("front counter"),
+        store_id: String::from("not-a-store"),
+        permissions: vec![String::from("purchases:create")]
+    };
+
+    let result = sut.create(dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == STORE_NOT_FOUND
+    });
+}
+
+#[tokio::test]
+async fn it_should_return_a_store_scoped_principal_for_a_valid_key() {
+    use mockall::predicate::eq;
+    use crate::data::usecases::api_key::{UseCase, hash_secret};
+    use crate::data::usecases::api_key::protocols::repository::MockRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{ApiKey, Permission, Role, Tenant};
+    use crate::domain::usecases::api_key::ApiKeyUseCase;
+
+    let mut api_key = ApiKey::new(String::from("fk_abc"), hash_secret("secret"), String::from("pos"), String::from("store-01"), vec![Permission::PurchasesCreate]);
+    api_key.set_uuid(String::from("key-id"));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_api_key_by_prefix().with(eq(Tenant::Platform), eq("fk_abc")).times(1).return_const(Ok(api_key));
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+
+    let principal = sut.validate_key(String::from("fk_abc.secret")).await.unwrap();
+    assert_eq!(principal.role, Role::Service);
+    assert_eq!(principal.token_id, "key-id");
+    assert_eq!(principal.store_id.as_deref(), Some("store-01"));
+    assert!(principal.has_permission(Permission::PurchasesCreate));
+    assert!(!principal.has_permission(Permission::UsersRead));
+}
+
+#[tokio::test]
+async fn it_should_reject_revoked_keys_and_wrong_secrets() {
+    use chrono::Utc;
+    use crate::data::usecases::api_key::{UseCase, hash_secret};
+    use crate::data::usecases::api_key::protocols::repository::MockRepository;
+    use crate::data::usecases::store::protocols::repository::MockRepository as MockStoreRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{ApiKey, Permission};
+    use crate::domain::usecases::{admin::INVALID_TOKEN_ERROR, api_key::ApiKeyUseCase};
+
+    let api_key = ApiKey::new(String::from("fk_abc"), hash_secret("secret"), String::from("pos"), String::from("store-01"), vec![Permission::PurchasesCreate]);
+    let mut revoked = api_key.clone();
+    revoked.set_revoked_at(Some(Utc::now()));
+
+    for (stored, key) in [(api_key, "fk_abc.wrong"), (revoked, "fk_abc.secret")] {
+        let mut repository_mock = MockRepository::new();
+        repository_mock.expect_get_api_key_by_prefix().return_const(Ok(stored));
+        let sut = UseCase::new(Box::new(repository_mock), Box::new(MockStoreRepository::new()), Box::new(MockUuid::new()));
+
+        let result = sut.validate_key(String::from(key)).await;
+        assert!(match result {
+            Ok(_) => false,
+            Err(e) => e.get_code() == INVALID_TOKEN_ERROR
+        });
+    }
+}
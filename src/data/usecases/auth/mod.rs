@@ -0,0 +1,163 @@
+use crate::domain::{
+    entities::{Role, Tenant, UserStatus},
+    usecases::{
+        admin::{EXPIRED_TOKEN_ERROR, INVALID_TOKEN_ERROR},
+        auth::{AuthUseCase, LoginRequestDTO, LoginResponseDTO, AuthenticatedUser, INVALID_CREDENTIALS_ERROR}
+    },
+    error::{Error, Kind}
+};
+use crate::data::usecases::user::{get_user_by_store_and_document, protocols::{repository::Repository, hash::Hash}};
+use chrono::{DateTime, Utc};
+use std::time::{Duration, SystemTime};
+use serde::{Serialize, Deserialize};
+use jsonwebtoken::{Header, Algorithm, encode, EncodingKey, DecodingKey, decode, Validation, errors::{self, ErrorKind}};
+use async_trait::async_trait;
+
+const SECONDS_IN_AN_HOUR: u64 = 3600;
+
+/// Audience of customer tokens, admin tokens never carry it.
+pub const USER_TOKEN_AUDIENCE: &str = "customer";
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    hash: Box<dyn Hash + Send + Sync>,
+    api_secret: String,
+    token_duration_in_hours: u64
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    doc: String,
+    role: String,
+    // customers registered before stores existed are not bound to any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    store: Option<String>,
+    aud: String,
+    exp: u64,
+}
+
+impl UseCase {
+    pub fn new(repository: Box<dyn Repository + Send + Sync>, hash: Box<dyn Hash + Send + Sync>, api_secret: String, token_duration_in_hours: u64) -> UseCase {
+        UseCase { repository, hash, api_secret, token_duration_in_hours }
+    }
+}
+
+#[async_trait]
+impl AuthUseCase for UseCase {
+    async fn login(&self, dto: LoginRequestDTO) -> Result<LoginResponseDTO, Error> {
+        // unknown documents and wrong passwords look the same to the caller, the document
+        // is only unique within a store so the customer names the one they sign in to
+        let user = match get_user_by_store_and_document(self.repository.as_ref(), dto.store_id.clone(), &dto.document).await {
+            Ok(u) => u,
+            Err(e) if e.get_kind() == Kind::Internal => return Err(e),
+            Err(_) => return Err(Error::new_unauthorized(INVALID_CREDENTIALS_ERROR))
+        };
+
+        match self.hash.verify(dto.password.clone(), String::from(user.get_password())) {
+            Ok(true) => (),
+            Ok(false) => return Err(Error::new_unauthorized(INVALID_CREDENTIALS_ERROR)),
+            Err(message) => return Err(Error::new_internal(&message))
+        }
+
+        // blocked customers still sign in to see what they owe, only new credit is refused to them
+        if is_deleted(user.get_status())? {
+            return Err(Error::new_unauthorized(INVALID_CREDENTIALS_ERROR));
+        }
+
+        // hashes from a rotated pepper or cost are upgraded while the password is at hand,
+        // a failed upgrade is retried on the next login instead of failing this one
+        if self.hash.needs_rehash(String::from(user.get_password())) {
+            if let Ok(password) = self.hash.run(dto.password) {
+                let _ = self.repository.update_password(&Tenant::Platform, user.get_id(), &password).await;
+            }
+        }
+
+        let expires_at = self.get_expiration_timestamp()?;
+        let claims = Claims {
+            sub: String::from(user.get_id()),
+            doc: user.get_document().to_string(),
+            role: String::from(Role::Customer.to_string()),
+            store: user.get_store_id().map(String::from),
+            aud: String::from(USER_TOKEN_AUDIENCE),
+            exp: expires_at
+        };
+
+        let token = match encode(&Header::new(Algorithm::HS512), &claims, &EncodingKey::from_secret(self.api_secret.as_ref())) {
+            Ok(t) => t,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let expires_at = match DateTime::<Utc>::from_timestamp(expires_at as i64, 0) {
+            Some(d) => d,
+            None => return Err(Error::new_internal("error generating expiration date"))
+        };
+
+        Ok(LoginResponseDTO { token, token_type: String::from("Bearer"), expires_at })
+    }
+
+    async fn validate_token(&self, token: String) -> Result<AuthenticatedUser, Error> {
+        let mut validation = Validation::new(Algorithm::HS512);
+        validation.set_audience(&[USER_TOKEN_AUDIENCE]);
+
+        let result = decode::<Claims>(
+            token.as_str(),
+            &DecodingKey::from_secret(self.api_secret.as_ref()),
+            &validation
+        );
+
+        let claims = match result {
+            Ok(data) if Role::from_string(&data.claims.role) == Role::Customer => data.claims,
+            Ok(_) => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR)),
+            Err(e) => return Err(to_domain_error(e))
+        };
+
+        // the customer is loaded by id on every request, so deleted users lose access at once
+        // and a changed document never points the token elsewhere
+        let user = match self.repository.get_by_id(&Tenant::Platform, &claims.sub).await {
+            Ok(u) => u,
+            Err(e) if e.get_kind() == Kind::Internal => return Err(e),
+            Err(_) => return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR))
+        };
+        if is_deleted(user.get_status())? {
+            return Err(Error::new_unauthorized(INVALID_TOKEN_ERROR));
+        }
+
+        Ok(AuthenticatedUser {
+            id: String::from(user.get_id()),
+            document: user.get_document().to_string(),
+            store_id: user.get_store_id().map(String::from)
+        })
+    }
+}
+
+impl UseCase {
+    fn get_expiration_timestamp(&self) -> Result<u64, Error> {
+        let now = SystemTime::now();
+        if let Some(expires_at) = now.checked_add(Duration::from_secs(SECONDS_IN_AN_HOUR * self.token_duration_in_hours)) {
+            match expires_at.duration_since(std::time::UNIX_EPOCH) {
+                Ok(expires_at_time_since) => return Ok(expires_at_time_since.as_secs()),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            };
+        }
+        Err(Error::new_internal("error generating expiration date"))
+    }
+}
+
+fn is_deleted(status: UserStatus) -> Result<bool, Error> {
+    match status {
+        UserStatus::Active | UserStatus::Blocked => Ok(false),
+        UserStatus::Deleted => Ok(true),
+        UserStatus::Unknown => Err(Error::new_internal("user with unknown status"))
+    }
+}
+
+fn to_domain_error(e: errors::Error) -> Error {
+    match e.kind() {
+        ErrorKind::ExpiredSignature => Error::new_unauthorized(EXPIRED_TOKEN_ERROR),
+        ErrorKind::InvalidSubject | ErrorKind::InvalidAudience | ErrorKind::InvalidSignature | ErrorKind::MissingRequiredClaim(_) | ErrorKind::InvalidToken | ErrorKind::InvalidAlgorithm | ErrorKind::Base64(_) | ErrorKind::Json(_) | ErrorKind::Utf8(_) => Error::new_unauthorized(INVALID_TOKEN_ERROR),
+        _ => Error::new_internal(e.to_string().as_str())
+    }
+}
+
+mod tests;
@@ -0,0 +1,80 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use crate::domain::{
+    entities::{LedgerTransaction, Tenant},
+    error::Error,
+    usecases::{
+        account::ACCOUNT_CLOSED_ERROR,
+        ledger::{self, LedgerUseCase, AdjustmentRequestDTO, AdjustmentResponseDTO, BalanceSnapshot, BalanceDriftDTO, ReconciliationResponseDTO}
+    }
+};
+use protocols::repository::Repository;
+use crate::data::usecases::account::protocols::repository::Repository as AccountRepository;
+use crate::data::usecases::user::{get_user_by_document, protocols::repository::Repository as UserRepository};
+use crate::data::protocols::uuid::Uuid;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    account_repository: Box<dyn AccountRepository + Send + Sync>,
+    user_repository: Box<dyn UserRepository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        account_repository: Box<dyn AccountRepository + Send + Sync>,
+        user_repository: Box<dyn UserRepository + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>
+    ) -> UseCase {
+        UseCase { repository, account_repository, user_repository, uuid_generator }
+    }
+}
+
+#[async_trait]
+impl LedgerUseCase for UseCase {
+    async fn adjust(&self, tenant: &Tenant, document: &str, dto: AdjustmentRequestDTO) -> Result<AdjustmentResponseDTO, Error> {
+        if dto.amount.is_zero() || dto.description.trim().is_empty() {
+            return Err(Error::new_business(ledger::INVALID_ADJUSTMENT_ERROR));
+        }
+
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        let account = self.account_repository.get_by_user_id(tenant, user.get_id()).await?;
+        if !account.is_open() {
+            return Err(Error::new_business(ACCOUNT_CLOSED_ERROR));
+        }
+
+        let transaction = LedgerTransaction::for_adjustment(self.uuid_generator.generate(), String::from(account.get_id()), dto.amount, dto.description);
+        let id = String::from(transaction.get_id());
+        let account = self.repository.create(tenant, transaction).await?;
+
+        Ok(AdjustmentResponseDTO { id, amount: dto.amount, balance: account.get_balance() })
+    }
+
+    async fn reconcile(&self, tenant: &Tenant) -> Result<ReconciliationResponseDTO, Error> {
+        let snapshots = self.repository.get_balance_snapshots(tenant).await?;
+        Ok(reconcile(snapshots))
+    }
+}
+
+/// Recomputes every balance from its ledger entries and reports the accounts whose
+/// cached balance drifted away from it.
+pub fn reconcile(snapshots: Vec<BalanceSnapshot>) -> ReconciliationResponseDTO {
+    let checked_accounts = snapshots.len();
+    let mut drifts: Vec<BalanceDriftDTO> = Vec::new();
+    for snapshot in snapshots {
+        let ledger_balance = snapshot.receivable_debits.saturating_sub(snapshot.receivable_credits);
+        if ledger_balance != snapshot.cached_balance {
+            drifts.push(BalanceDriftDTO {
+                account_id: snapshot.account_id,
+                cached_balance: snapshot.cached_balance,
+                ledger_balance,
+                drift: snapshot.cached_balance.saturating_sub(ledger_balance)
+            });
+        }
+    }
+    ReconciliationResponseDTO { checked_accounts, drifts }
+}
+
+mod tests;
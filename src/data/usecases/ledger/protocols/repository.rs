@@ -0,0 +1,17 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{
+    entities::{CreditAccount, LedgerTransaction, Tenant},
+    error::Error,
+    usecases::ledger::BalanceSnapshot
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    /// Posts the transaction and applies it to the cached account balance,
+    /// returning the updated account.
+    async fn create(&self, tenant: &Tenant, transaction: LedgerTransaction) -> Result<CreditAccount, Error>;
+    /// Balances of the accounts of the tenant's store, or of every account for the platform.
+    async fn get_balance_snapshots(&self, tenant: &Tenant) -> Result<Vec<BalanceSnapshot>, Error>;
+}
@@ -0,0 +1,116 @@
+use tokio;
+
+#[cfg(test)]
+#[test]
+fn it_should_report_accounts_whose_cached_balance_drifted() {
+    use crate::data::usecases::ledger::reconcile;
+    use crate::domain::usecases::ledger::{BalanceSnapshot, BalanceDriftDTO};
+    use crate::domain::types::money::Money;
+
+    let snapshots = vec![
+        BalanceSnapshot { account_id: String::from("in-sync"), cached_balance: Money::from_cents(1500), receivable_debits: Money::from_cents(2000), receivable_credits: Money::from_cents(500) },
+        BalanceSnapshot { account_id: String::from("drifted"), cached_balance: Money::from_cents(1000), receivable_debits: Money::from_cents(3000), receivable_credits: Money::from_cents(2200) },
+    ];
+
+    let result = reconcile(snapshots);
+
+    assert_eq!(result.checked_accounts, 2);
+    assert_eq!(result.drifts, vec![
+        BalanceDriftDTO { account_id: String::from("drifted"), cached_balance: Money::from_cents(1000), ledger_balance: Money::from_cents(800), drift: Money::from_cents(200) }
+    ]);
+}
+
+#[tokio::test]
+async fn it_should_return_error_when_adjustment_amount_is_zero() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::ledger::UseCase;
+    use crate::data::usecases::ledger::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::ledger::{LedgerUseCase, AdjustmentRequestDTO, INVALID_ADJUSTMENT_ERROR};
+    use crate::domain::types::money::Money;
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockUuid::new()));
+    let dto = AdjustmentRequestDTO {
+        amount: Money::from_cents(0),
+        description: String::from("estorno")
+    };
+
+    let result = sut.adjust(&Tenant::Platform, "40735626065", dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_ADJUSTMENT_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_post_a_balanced_credit_adjustment() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::ledger::UseCase;
+    use crate::data::usecases::ledger::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::ledger::{LedgerUseCase, AdjustmentRequestDTO};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(3000));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().return_const(Ok(account.clone()));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    account.set_balance(Money::from_cents(2500));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create()
+        .withf(|_, transaction| transaction.is_balanced() && transaction.get_receivable_change() == Some(Money::from_cents(-500)))
+        .return_const(Ok(account));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(uuid_mock));
+    let dto = AdjustmentRequestDTO {
+        amount: Money::from_cents(-500),
+        description: String::from("desconto"),
+    };
+
+    let result = sut.adjust(&Tenant::Platform, &cpf.to_string(), dto).await.unwrap();
+    assert_eq!(result.balance, Money::from_cents(2500));
+}
+
+#[tokio::test]
+async fn it_should_reconcile_only_the_accounts_of_the_tenant_store() {
+    use mockall::predicate::eq;
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::ledger::UseCase;
+    use crate::data::usecases::ledger::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::ledger::{LedgerUseCase, BalanceSnapshot};
+    use crate::domain::types::money::Money;
+
+    let tenant = Tenant::Store(String::from("store_id"));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_balance_snapshots()
+        .with(eq(tenant.clone()))
+        .times(1)
+        .return_const(Ok(vec![
+            BalanceSnapshot { account_id: String::from("account_id"), cached_balance: Money::from_cents(1500), receivable_debits: Money::from_cents(1500), receivable_credits: Money::from_cents(0) }
+        ]));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockUuid::new()));
+
+    let result = sut.reconcile(&tenant).await.unwrap();
+    assert_eq!(result.checked_accounts, 1);
+    assert!(result.drifts.is_empty());
+}
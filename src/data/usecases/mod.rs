@@ -1,2 +1,12 @@
 pub mod user;
 pub mod admin;
+pub mod account;
+pub mod purchase;
+pub mod payment;
+pub mod ledger;
+pub mod accrual;
+pub mod statement;
+pub mod auth;
+pub mod password;
+pub mod api_key;
+pub mod store;
\ No newline at end of file
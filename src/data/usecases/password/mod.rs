@@ -0,0 +1,138 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use crate::domain::{
+    entities::{PasswordReset, Tenant, User},
+    error::{Error, Kind},
+    usecases::{
+        auth::INVALID_CREDENTIALS_ERROR,
+        user::check_user_status,
+        password::{
+            check_password, PasswordUseCase, PasswordChangeRequestDTO, PasswordResetRequestDTO,
+            PasswordResetTokenResponseDTO, INVALID_RESET_TOKEN_ERROR
+        }
+    }
+};
+use protocols::repository::Repository;
+use crate::data::usecases::user::{get_user_by_document, get_user_by_store_and_document, protocols::{repository::Repository as UserRepository, hash::Hash}};
+use crate::data::protocols::uuid::Uuid;
+
+const TOKEN_SEPARATOR: char = '.';
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    user_repository: Box<dyn UserRepository + Send + Sync>,
+    hash: Box<dyn Hash + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>,
+    reset_token_duration_in_minutes: u64
+}
+
+impl UseCase {
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        user_repository: Box<dyn UserRepository + Send + Sync>,
+        hash: Box<dyn Hash + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>,
+        reset_token_duration_in_minutes: u64
+    ) -> UseCase {
+        UseCase { repository, user_repository, hash, uuid_generator, reset_token_duration_in_minutes }
+    }
+
+    fn hash_password(&self, password: String) -> Result<String, Error> {
+        match self.hash.run(password) {
+            Ok(h) => Ok(h),
+            Err(message) => Err(Error::new_internal(&message))
+        }
+    }
+
+    fn verify(&self, plain_text: String, hash: &str) -> Result<bool, Error> {
+        match self.hash.verify(plain_text, String::from(hash)) {
+            Ok(v) => Ok(v),
+            Err(message) => Err(Error::new_internal(&message))
+        }
+    }
+
+    async fn get_user_for_reset(&self, store_id: Option<String>, document: &str) -> Result<User, Error> {
+        // the reset endpoint is public, so unknown users look like a bad token, the document
+        // is only unique within a store so the customer names theirs
+        let user = match get_user_by_store_and_document(self.user_repository.as_ref(), store_id, document).await {
+            Ok(u) => u,
+            Err(e) if e.get_kind() == Kind::Internal => return Err(e),
+            Err(_) => return Err(Error::new_business(INVALID_RESET_TOKEN_ERROR))
+        };
+        check_user_status(user.get_status())?;
+        Ok(user)
+    }
+}
+
+#[async_trait]
+impl PasswordUseCase for UseCase {
+    async fn change(&self, tenant: &Tenant, document: &str, dto: PasswordChangeRequestDTO) -> Result<(), Error> {
+        check_password(&dto.new_password)?;
+
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        check_user_status(user.get_status())?;
+
+        if !self.verify(dto.current_password, user.get_password())? {
+            return Err(Error::new_business(INVALID_CREDENTIALS_ERROR));
+        }
+
+        let password = self.hash_password(dto.new_password)?;
+        self.user_repository.update_password(tenant, user.get_id(), &password).await
+    }
+
+    async fn create_reset_token(&self, tenant: &Tenant, document: &str) -> Result<PasswordResetTokenResponseDTO, Error> {
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        check_user_status(user.get_status())?;
+
+        let id = self.uuid_generator.generate();
+        let secret = self.uuid_generator.generate();
+        let expires_at = Utc::now() + Duration::minutes(self.reset_token_duration_in_minutes as i64);
+
+        let mut reset = PasswordReset::new(String::from(user.get_id()), self.hash_password(secret.clone())?, expires_at);
+        reset.set_uuid(id.clone());
+        self.repository.create_reset(tenant, reset).await?;
+
+        Ok(PasswordResetTokenResponseDTO {
+            token: format!("{}{}{}", id, TOKEN_SEPARATOR, secret),
+            expires_at
+        })
+    }
+
+    async fn reset(&self, document: &str, dto: PasswordResetRequestDTO) -> Result<(), Error> {
+        check_password(&dto.new_password)?;
+
+        let user = self.get_user_for_reset(dto.store_id.clone(), document).await?;
+        let tenant = match user.get_store_id() {
+            Some(store_id) => Tenant::Store(String::from(store_id)),
+            None => Tenant::Platform
+        };
+        let (id, secret) = match dto.token.trim().split_once(TOKEN_SEPARATOR) {
+            Some(parts) => parts,
+            None => return Err(Error::new_business(INVALID_RESET_TOKEN_ERROR))
+        };
+
+        let reset = match self.repository.get_reset(&tenant, id).await {
+            Ok(r) => r,
+            Err(e) if e.get_kind() == Kind::Internal => return Err(e),
+            Err(_) => return Err(Error::new_business(INVALID_RESET_TOKEN_ERROR))
+        };
+
+        if reset.get_user_id() != user.get_id() || !reset.is_usable(Utc::now()) {
+            return Err(Error::new_business(INVALID_RESET_TOKEN_ERROR));
+        }
+
+        if !self.verify(String::from(secret), reset.get_token_hash())? {
+            return Err(Error::new_business(INVALID_RESET_TOKEN_ERROR));
+        }
+
+        let password = self.hash_password(dto.new_password)?;
+        match self.repository.consume_reset(&tenant, reset.get_id(), &password).await? {
+            true => Ok(()),
+            false => Err(Error::new_business(INVALID_RESET_TOKEN_ERROR))
+        }
+    }
+}
+
+mod tests;
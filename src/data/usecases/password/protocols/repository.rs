@@ -0,0 +1,14 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{entities::{PasswordReset, Tenant}, error::Error};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    /// Stores the reset, invalidating every unused reset of the same user.
+    async fn create_reset(&self, tenant: &Tenant, reset: PasswordReset) -> Result<(), Error>;
+    async fn get_reset(&self, tenant: &Tenant, id: &str) -> Result<PasswordReset, Error>;
+    /// Marks the reset as used and sets the user's password, returns false when
+    /// the reset was already used or expired in the meantime.
+    async fn consume_reset(&self, tenant: &Tenant, id: &str, password: &str) -> Result<bool, Error>;
+}
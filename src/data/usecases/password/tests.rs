@@ -0,0 +1,172 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_not_change_the_password_when_current_password_does_not_match() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::password::{UseCase, protocols::repository::MockRepository};
+    use crate::data::usecases::user::protocols::{repository::MockRepository as MockUserRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::auth::INVALID_CREDENTIALS_ERROR;
+    use crate::domain::usecases::password::{PasswordUseCase, PasswordChangeRequestDTO};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(NaiveDate::from_ymd_opt(1999, 9, 5).unwrap()));
+    user.set_uuid(String::from("user_id"));
+    user.set_password(String::from("current_hash"));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    user_repository_mock.expect_update_password().never();
+    let mut hash_mock = MockHash::new();
+    hash_mock.expect_verify().return_const(Ok(false));
+    hash_mock.expect_run().never();
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(user_repository_mock), Box::new(hash_mock), Box::new(MockUuid::new()), 30);
+    let dto = PasswordChangeRequestDTO { current_password: String::from("wrong-password"), new_password: String::from("new-password") };
+
+    let result = sut.change(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == INVALID_CREDENTIALS_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_store_the_new_password_hashed() {
+    use chrono::NaiveDate;
+    use mockall::predicate::eq;
+    use crate::data::usecases::password::{UseCase, protocols::repository::MockRepository};
+    use crate::data::usecases::user::protocols::{repository::MockRepository as MockUserRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::password::{PasswordUseCase, PasswordChangeRequestDTO};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(NaiveDate::from_ymd_opt(1999, 9, 5).unwrap()));
+    user.set_uuid(String::from("user_id"));
+    user.set_password(String::from("current_hash"));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    let mut hash_mock = MockHash::new();
+    hash_mock.expect_verify().with(eq(String::from("current-password")), eq(String::from("current_hash"))).return_const(Ok(true));
+    hash_mock.expect_run().with(eq(String::from("new-password"))).return_const(Ok(String::from("new_hash")));
+    user_repository_mock.expect_update_password()
+        .withf(|_, user_id, password| user_id == "user_id" && password == "new_hash")
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(user_repository_mock), Box::new(hash_mock), Box::new(MockUuid::new()), 30);
+    let dto = PasswordChangeRequestDTO { current_password: String::from("current-password"), new_password: String::from("new-password") };
+
+    assert!(matches!(sut.change(&Tenant::Platform, &cpf.to_string(), dto).await, Ok(())));
+}
+
+#[tokio::test]
+async fn it_should_only_store_the_hash_of_reset_tokens() {
+    use chrono::{NaiveDate, Utc, Duration};
+    use crate::data::usecases::password::{UseCase, protocols::repository::MockRepository};
+    use crate::data::usecases::user::protocols::{repository::MockRepository as MockUserRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::password::PasswordUseCase;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(NaiveDate::from_ymd_opt(1999, 9, 5).unwrap()));
+    user.set_uuid(String::from("user_id"));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+    let mut hash_mock = MockHash::new();
+    hash_mock.expect_run().return_const(Ok(String::from("token_hash")));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create_reset()
+        .withf(|tenant, reset| *tenant == Tenant::Platform && reset.get_id() == "uuid" && reset.get_user_id() == "user_id" && reset.get_token_hash() == "token_hash")
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(hash_mock), Box::new(uuid_mock), 30);
+
+    let response = sut.create_reset_token(&Tenant::Platform, &cpf.to_string()).await.unwrap();
+    assert_eq!(response.token, "uuid.uuid");
+    assert!(response.expires_at <= Utc::now() + Duration::minutes(30));
+}
+
+#[tokio::test]
+async fn it_should_refuse_used_reset_tokens() {
+    use chrono::{NaiveDate, Utc, Duration};
+    use crate::data::usecases::password::{UseCase, protocols::repository::MockRepository};
+    use crate::data::usecases::user::protocols::{repository::MockRepository as MockUserRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{PasswordReset, User};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::password::{PasswordUseCase, PasswordResetRequestDTO, INVALID_RESET_TOKEN_ERROR};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(NaiveDate::from_ymd_opt(1999, 9, 5).unwrap()));
+    user.set_uuid(String::from("user_id"));
+
+    let mut reset = PasswordReset::new(String::from("user_id"), String::from("token_hash"), Utc::now() + Duration::minutes(30));
+    reset.set_uuid(String::from("reset_id"));
+    reset.set_used_at(Some(Utc::now()));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_store_and_cpf().return_const(Ok(user));
+    let mut hash_mock = MockHash::new();
+    hash_mock.expect_verify().return_const(Ok(true));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_reset().return_const(Ok(reset));
+    repository_mock.expect_consume_reset().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(hash_mock), Box::new(MockUuid::new()), 30);
+    let dto = PasswordResetRequestDTO { token: String::from("reset_id.secret"), new_password: String::from("new-password"), store_id: None };
+
+    let result = sut.reset(&cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(()) => false,
+        Err(e) => e.get_code() == INVALID_RESET_TOKEN_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_reset_the_password_with_a_valid_token() {
+    use chrono::{NaiveDate, Utc, Duration};
+    use mockall::predicate::eq;
+    use crate::data::usecases::password::{UseCase, protocols::repository::MockRepository};
+    use crate::data::usecases::user::protocols::{repository::MockRepository as MockUserRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{PasswordReset, Tenant, User};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::password::{PasswordUseCase, PasswordResetRequestDTO};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(NaiveDate::from_ymd_opt(1999, 9, 5).unwrap()));
+    user.set_uuid(String::from("user_id"));
+    user.set_store_id(Some(String::from("store_id")));
+
+    let mut reset = PasswordReset::new(String::from("user_id"), String::from("token_hash"), Utc::now() + Duration::minutes(30));
+    reset.set_uuid(String::from("reset_id"));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_store_and_cpf().with(eq(Some(String::from("store_id"))), eq("40735626065")).return_const(Ok(user));
+    let mut hash_mock = MockHash::new();
+    hash_mock.expect_verify().with(eq(String::from("secret")), eq(String::from("token_hash"))).return_const(Ok(true));
+    hash_mock.expect_run().with(eq(String::from("new-password"))).return_const(Ok(String::from("new_hash")));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_reset().with(eq(Tenant::Store(String::from("store_id"))), eq("reset_id")).return_const(Ok(reset));
+    repository_mock.expect_consume_reset()
+        .withf(|tenant, id, password| *tenant == Tenant::Store(String::from("store_id")) && id == "reset_id" && password == "new_hash")
+        .times(1)
+        .return_const(Ok(true));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(user_repository_mock), Box::new(hash_mock), Box::new(MockUuid::new()), 30);
+    let dto = PasswordResetRequestDTO { token: String::from("reset_id.secret"), new_password: String::from("new-password"), store_id: Some(String::from("store_id")) };
+
+    assert!(matches!(sut.reset(&cpf.to_string(), dto).await, Ok(())));
+}
@@ -0,0 +1,95 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use crate::domain::{
+    entities::{LedgerTransaction, Payment, PaymentMethod, Tenant},
+    error::Error,
+    types::money::Money,
+    usecases::{
+        account::ACCOUNT_CLOSED_ERROR,
+        purchase::INVALID_AMOUNT_ERROR,
+        payment::{self, PaymentUseCase, PaymentCreateRequestDTO, PaymentResponseDTO}
+    }
+};
+use protocols::repository::Repository;
+use crate::data::usecases::account::protocols::repository::Repository as AccountRepository;
+use crate::data::usecases::user::{get_user_by_document, protocols::repository::Repository as UserRepository};
+use crate::data::protocols::uuid::Uuid;
+
+/// A payment racing a purchase or another payment on the same account is computed again.
+const MAX_PAYMENT_ATTEMPTS: u32 = 3;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    account_repository: Box<dyn AccountRepository + Send + Sync>,
+    user_repository: Box<dyn UserRepository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        account_repository: Box<dyn AccountRepository + Send + Sync>,
+        user_repository: Box<dyn UserRepository + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>
+    ) -> UseCase {
+        UseCase { repository, account_repository, user_repository, uuid_generator }
+    }
+
+    async fn try_register(&self, tenant: &Tenant, operator: &str, user_id: &str, dto: &PaymentCreateRequestDTO, method: PaymentMethod) -> Result<PaymentResponseDTO, Error> {
+        let account = self.account_repository.get_by_user_id(tenant, user_id).await?;
+        if !account.is_open() {
+            return Err(Error::new_business(ACCOUNT_CLOSED_ERROR));
+        }
+
+        let installments = self.repository.get_unsettled_installments(tenant, account.get_id()).await?;
+
+        let mut payment = Payment::new(String::from(account.get_id()), dto.amount, method, String::from(operator));
+        payment.set_uuid(self.uuid_generator.generate());
+
+        // what is left after the installments pays fees and adjustments, only the part
+        // above the whole balance is carried forward as credit
+        let (allocations, _) = payment.allocate(&installments);
+        let carried_credit = dto.amount.saturating_sub(account.get_balance().max(Money::zero())).max(Money::zero());
+        payment.set_carried_credit(carried_credit);
+
+        let id = String::from(payment.get_id());
+        let transaction = LedgerTransaction::for_payment(self.uuid_generator.generate(), &payment);
+        let account = self.repository.create(tenant, payment, allocations.clone(), transaction, account.get_balance(), installments).await?;
+
+        Ok(PaymentResponseDTO {
+            id,
+            amount: dto.amount,
+            allocations,
+            carried_credit,
+            balance: account.get_balance()
+        })
+    }
+}
+
+#[async_trait]
+impl PaymentUseCase for UseCase {
+    async fn register(&self, tenant: &Tenant, operator: &str, document: &str, dto: PaymentCreateRequestDTO) -> Result<PaymentResponseDTO, Error> {
+        if !dto.amount.is_positive() {
+            return Err(Error::new_business(INVALID_AMOUNT_ERROR));
+        }
+
+        let method = PaymentMethod::from_string(&dto.method.to_uppercase());
+        if method == PaymentMethod::Unknown {
+            return Err(Error::new_business(payment::INVALID_PAYMENT_METHOD_ERROR));
+        }
+
+        // blocked customers are still allowed to settle what they owe
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+
+        let mut attempt = 1;
+        loop {
+            match self.try_register(tenant, operator, user.get_id(), &dto, method).await {
+                Err(e) if e.get_code() == payment::PAYMENT_CONFLICT_ERROR && attempt < MAX_PAYMENT_ATTEMPTS => attempt += 1,
+                result => return result
+            }
+        }
+    }
+}
+
+mod tests;
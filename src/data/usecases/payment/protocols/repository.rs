@@ -0,0 +1,26 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{
+    entities::{CreditAccount, Installment, LedgerTransaction, Payment, PaymentAllocation, Tenant},
+    error::Error,
+    types::money::Money
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    async fn get_unsettled_installments(&self, tenant: &Tenant, account_id: &str) -> Result<Vec<Installment>, Error>;
+    /// Stores the payment with its allocations, posts its ledger transaction and
+    /// discounts it from the account atomically, returning the updated account. The
+    /// account is locked first and the payment refused with `PAYMENT_CONFLICT_ERROR`
+    /// when its balance or unsettled installments no longer match the ones it was computed from.
+    async fn create(
+        &self,
+        tenant: &Tenant,
+        payment: Payment,
+        allocations: Vec<PaymentAllocation>,
+        transaction: LedgerTransaction,
+        expected_balance: Money,
+        expected_installments: Vec<Installment>
+    ) -> Result<CreditAccount, Error>;
+}
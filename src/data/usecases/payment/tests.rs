@@ -0,0 +1,175 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_return_error_when_payment_method_is_unknown() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::payment::UseCase;
+    use crate::data::usecases::payment::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::payment::{PaymentUseCase, PaymentCreateRequestDTO, INVALID_PAYMENT_METHOD_ERROR};
+    use crate::domain::types::money::Money;
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockUuid::new()));
+    let dto = PaymentCreateRequestDTO {
+        amount: Money::from_cents(1000),
+        method: String::from("cheque")
+    };
+
+    let result = sut.register(&Tenant::Platform, "caixa-1", "40735626065", dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_PAYMENT_METHOD_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_allocate_a_partial_payment_to_the_installments_due_first() {
+    use chrono::NaiveDate;
+    use mockall::predicate::{always, eq};
+    use crate::data::usecases::payment::UseCase;
+    use crate::data::usecases::payment::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Installment, PaymentAllocation, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::payment::{PaymentUseCase, PaymentCreateRequestDTO};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(3000));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().return_const(Ok(account.clone()));
+
+    let mut due_later = Installment::new(String::from("purchase"), String::from("account_id"), 2, Money::from_cents(1000), NaiveDate::from_ymd_opt(2024, 5, 15).unwrap());
+    due_later.set_uuid(String::from("due_later"));
+    let mut due_first = Installment::new(String::from("purchase"), String::from("account_id"), 1, Money::from_cents(2000), NaiveDate::from_ymd_opt(2024, 4, 15).unwrap());
+    due_first.set_uuid(String::from("due_first"));
+    due_first.set_paid_amount(Money::from_cents(500));
+
+    let expected_allocations = vec![
+        PaymentAllocation { installment_id: String::from("due_first"), purchase_id: String::from("purchase"), amount: Money::from_cents(1500), settled: true },
+        PaymentAllocation { installment_id: String::from("due_later"), purchase_id: String::from("purchase"), amount: Money::from_cents(500), settled: false }
+    ];
+
+    account.set_balance(Money::from_cents(1000));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_unsettled_installments().return_const(Ok(vec![due_later, due_first]));
+    repository_mock.expect_create().with(eq(Tenant::Platform), always(), eq(expected_allocations.clone()), always(), eq(Money::from_cents(3000)), always()).return_const(Ok(account));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(uuid_mock));
+    let dto = PaymentCreateRequestDTO {
+        amount: Money::from_cents(2000),
+        method: String::from("pix")
+    };
+
+    let result = sut.register(&Tenant::Platform, "caixa-1", &cpf.to_string(), dto).await.unwrap();
+    assert_eq!(result.allocations, expected_allocations);
+    assert_eq!(result.carried_credit, Money::from_cents(0));
+    assert_eq!(result.balance, Money::from_cents(1000));
+}
+
+#[tokio::test]
+async fn it_should_carry_forward_overpayments_as_credit() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::payment::UseCase;
+    use crate::data::usecases::payment::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Installment, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::payment::{PaymentUseCase, PaymentCreateRequestDTO};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(1000));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().return_const(Ok(account.clone()));
+
+    let mut installment = Installment::new(String::from("purchase"), String::from("account_id"), 1, Money::from_cents(1000), NaiveDate::from_ymd_opt(2024, 4, 15).unwrap());
+    installment.set_uuid(String::from("installment"));
+
+    account.set_balance(Money::from_cents(-500));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_unsettled_installments().return_const(Ok(vec![installment]));
+    repository_mock.expect_create().withf(|_, payment, _, transaction, _, _| payment.get_operator() == "caixa-1" && payment.get_carried_credit() == Money::from_cents(500) && transaction.get_receivable_change() == Some(Money::from_cents(-1500))).return_const(Ok(account));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(uuid_mock));
+    let dto = PaymentCreateRequestDTO {
+        amount: Money::from_cents(1500),
+        method: String::from("CASH")
+    };
+
+    let result = sut.register(&Tenant::Platform, "caixa-1", &cpf.to_string(), dto).await.unwrap();
+    assert_eq!(result.allocations.len(), 1);
+    assert!(result.allocations[0].settled);
+    assert_eq!(result.carried_credit, Money::from_cents(500));
+    assert_eq!(result.balance, Money::from_cents(-500));
+}
+
+#[tokio::test]
+async fn it_should_compute_the_payment_again_when_the_account_changes_meanwhile() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::payment::UseCase;
+    use crate::data::usecases::payment::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::error::Error;
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::payment::{PaymentUseCase, PaymentCreateRequestDTO, PAYMENT_CONFLICT_ERROR};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().times(1).return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(1000));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().times(3).return_const(Ok(account));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_unsettled_installments().times(3).return_const(Ok(vec![]));
+    repository_mock.expect_create().times(3).return_const(Err(Error::new_business(PAYMENT_CONFLICT_ERROR)));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(uuid_mock));
+    let dto = PaymentCreateRequestDTO {
+        amount: Money::from_cents(500),
+        method: String::from("pix")
+    };
+
+    let result = sut.register(&Tenant::Platform, "caixa-1", &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == PAYMENT_CONFLICT_ERROR
+    });
+}
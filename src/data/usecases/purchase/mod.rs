@@ -0,0 +1,94 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::domain::{
+    entities::{Installment, LedgerTransaction, Purchase, Tenant},
+    error::Error,
+    usecases::{
+        account::ACCOUNT_CLOSED_ERROR,
+        purchase::{self, PurchaseUseCase, PurchaseCreateRequestDTO, PurchaseResponseDTO, InstallmentResponseDTO, installment_due_dates},
+        user::check_user_status
+    }
+};
+use protocols::repository::Repository;
+use crate::data::usecases::account::protocols::repository::Repository as AccountRepository;
+use crate::data::usecases::user::{get_user_by_document, protocols::repository::Repository as UserRepository};
+use crate::data::protocols::uuid::Uuid;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    account_repository: Box<dyn AccountRepository + Send + Sync>,
+    user_repository: Box<dyn UserRepository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        account_repository: Box<dyn AccountRepository + Send + Sync>,
+        user_repository: Box<dyn UserRepository + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>
+    ) -> UseCase {
+        UseCase { repository, account_repository, user_repository, uuid_generator }
+    }
+}
+
+#[async_trait]
+impl PurchaseUseCase for UseCase {
+    async fn register(&self, tenant: &Tenant, document: &str, dto: PurchaseCreateRequestDTO) -> Result<PurchaseResponseDTO, Error> {
+        if !dto.amount.is_positive() {
+            return Err(Error::new_business(purchase::INVALID_AMOUNT_ERROR));
+        }
+
+        if dto.description.trim().is_empty() {
+            return Err(Error::new_business(purchase::INVALID_DESCRIPTION_ERROR));
+        }
+
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        check_user_status(user.get_status())?;
+
+        let account = self.account_repository.get_by_user_id(tenant, user.get_id()).await?;
+        if !account.is_open() {
+            return Err(Error::new_business(ACCOUNT_CLOSED_ERROR));
+        }
+
+        if dto.amount > account.get_available_credit() {
+            return Err(Error::new_business(purchase::CREDIT_LIMIT_EXCEEDED_ERROR));
+        }
+
+        let purchased_at = Utc::now();
+        let due_dates = installment_due_dates(account.get_billing_cycle(), purchased_at.date_naive(), dto.installments, dto.due_dates)?;
+
+        let mut purchase = Purchase::new(String::from(account.get_id()), dto.amount, dto.description, dto.merchant_reference, purchased_at);
+        purchase.set_uuid(self.uuid_generator.generate());
+        purchase.set_store_id(account.get_store_id().map(String::from));
+
+        let mut installments = match Installment::split(purchase.get_id(), account.get_id(), dto.amount, &due_dates) {
+            Some(i) => i,
+            None => return Err(Error::new_business(purchase::INVALID_INSTALLMENTS_ERROR))
+        };
+        for installment in installments.iter_mut() {
+            installment.set_uuid(self.uuid_generator.generate());
+        }
+        purchase.set_installments(installments);
+
+        let id = String::from(purchase.get_id());
+        let amount = purchase.get_amount();
+        let transaction = LedgerTransaction::for_purchase(self.uuid_generator.generate(), &purchase);
+        let (purchase, account) = self.repository.create(tenant, purchase, transaction).await?;
+
+        let today = Utc::now().date_naive();
+        let installments = purchase.get_installments().iter().map(|i| InstallmentResponseDTO::from_installment(i, today)).collect();
+
+        Ok(PurchaseResponseDTO {
+            id,
+            amount,
+            installments,
+            balance: account.get_balance(),
+            available_credit: account.get_available_credit()
+        })
+    }
+}
+
+mod tests;
@@ -0,0 +1,15 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{
+    entities::{CreditAccount, LedgerTransaction, Purchase, Tenant},
+    error::Error
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    /// Stores the purchase with its installments, posts its ledger transaction and
+    /// charges it to the account atomically. Returns the purchase, with installments
+    /// settled by credit the account carried, and the account with its updated balance.
+    async fn create(&self, tenant: &Tenant, purchase: Purchase, transaction: LedgerTransaction) -> Result<(Purchase, CreditAccount), Error>;
+}
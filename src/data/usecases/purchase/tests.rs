@@ -0,0 +1,230 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_return_error_when_amount_is_not_positive() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::purchase::UseCase;
+    use crate::data::usecases::purchase::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::purchase::{PurchaseUseCase, PurchaseCreateRequestDTO, INVALID_AMOUNT_ERROR};
+    use crate::domain::types::money::Money;
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockUuid::new()));
+    let dto = PurchaseCreateRequestDTO {
+        amount: Money::from_cents(0),
+        description: String::from("arroz"),
+        merchant_reference: String::from("cupom-1"),
+        installments: None,
+        due_dates: None
+    };
+
+    let result = sut.register(&Tenant::Platform, "40735626065", dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_AMOUNT_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_return_error_when_user_is_deleted() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::purchase::UseCase;
+    use crate::data::usecases::purchase::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, UserStatus, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::purchase::{PurchaseUseCase, PurchaseCreateRequestDTO};
+    use crate::domain::usecases::user::USER_DELETED_ERROR;
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_status(UserStatus::Deleted);
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().never();
+
+    let sut = UseCase::new(Box::new(MockRepository::new()), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(MockUuid::new()));
+    let dto = PurchaseCreateRequestDTO {
+        amount: Money::from_cents(1000),
+        description: String::from("arroz"),
+        merchant_reference: String::from("cupom-1"),
+        installments: None,
+        due_dates: None
+    };
+
+    let result = sut.register(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == USER_DELETED_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_return_error_when_purchase_exceeds_available_credit() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::purchase::UseCase;
+    use crate::data::usecases::purchase::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::purchase::{PurchaseUseCase, PurchaseCreateRequestDTO, CREDIT_LIMIT_EXCEEDED_ERROR};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(9500));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().return_const(Ok(account));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(MockUuid::new()));
+    let dto = PurchaseCreateRequestDTO {
+        amount: Money::from_cents(501),
+        description: String::from("arroz"),
+        merchant_reference: String::from("cupom-1"),
+        installments: None,
+        due_dates: None
+    };
+
+    let result = sut.register(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == CREDIT_LIMIT_EXCEEDED_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_return_the_new_balance_on_success() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::purchase::UseCase;
+    use crate::data::usecases::purchase::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::purchase::{PurchaseUseCase, PurchaseCreateRequestDTO};
+    use crate::domain::types::money::Money;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_balance(Money::from_cents(2000));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().return_const(Ok(account.clone()));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    account.set_balance(Money::from_cents(3500));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create()
+        .withf(|_, purchase, transaction| transaction.is_balanced() && transaction.get_receivable_change() == Some(purchase.get_amount()))
+        .returning(move |_, purchase, _| Ok((purchase, account.clone())));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(uuid_mock));
+    let dto = PurchaseCreateRequestDTO {
+        amount: Money::from_cents(1500),
+        description: String::from("arroz"),
+        merchant_reference: String::from("cupom-1"),
+        installments: None,
+        due_dates: None
+    };
+
+    let result = sut.register(&Tenant::Platform, &cpf.to_string(), dto).await.unwrap();
+    assert_eq!(result.id, "uuid");
+    assert_eq!(result.balance, Money::from_cents(3500));
+    assert_eq!(result.available_credit, Money::from_cents(6500));
+}
+
+#[tokio::test]
+async fn it_should_split_the_purchase_in_monthly_installments() {
+    use chrono::{Months, NaiveDate, Utc};
+    use crate::data::usecases::purchase::UseCase;
+    use crate::data::usecases::purchase::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, CreditAccount, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::purchase::{PurchaseUseCase, PurchaseCreateRequestDTO};
+    use crate::domain::types::{money::Money, billing_cycle::BillingCycle};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut user_repository_mock = MockUserRepository::new();
+    user_repository_mock.expect_get_by_cpf().return_const(Ok(user));
+
+    let account = CreditAccount::new(String::from("user_id"), Money::from_cents(100000));
+    let mut account_repository_mock = MockAccountRepository::new();
+    account_repository_mock.expect_get_by_user_id().return_const(Ok(account.clone()));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().returning(move |_, purchase, _| Ok((purchase, account.clone())));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(account_repository_mock), Box::new(user_repository_mock), Box::new(uuid_mock));
+    let dto = PurchaseCreateRequestDTO {
+        amount: Money::from_cents(100000),
+        description: String::from("geladeira"),
+        merchant_reference: String::from("cupom-1"),
+        installments: Some(3),
+        due_dates: None
+    };
+
+    let result = sut.register(&Tenant::Platform, &cpf.to_string(), dto).await.unwrap();
+    let amounts: Vec<Money> = result.installments.iter().map(|i| i.amount).collect();
+    let due_dates: Vec<NaiveDate> = result.installments.iter().map(|i| i.due_date).collect();
+    assert_eq!(amounts, vec![Money::from_cents(33334), Money::from_cents(33333), Money::from_cents(33333)]);
+    let first = BillingCycle::default().due_date_for_purchase(Utc::now().date_naive());
+    assert_eq!(due_dates, vec![
+        first,
+        first.checked_add_months(Months::new(1)).unwrap(),
+        first.checked_add_months(Months::new(2)).unwrap()
+    ]);
+}
+
+#[tokio::test]
+async fn it_should_return_error_when_due_dates_are_out_of_order() {
+    use chrono::NaiveDate;
+    use crate::domain::types::billing_cycle::BillingCycle;
+    use crate::domain::usecases::purchase::{installment_due_dates, INVALID_INSTALLMENTS_ERROR};
+
+    let purchased_on = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+    let due_dates = vec![NaiveDate::from_ymd_opt(2024, 5, 10).unwrap(), NaiveDate::from_ymd_opt(2024, 4, 10).unwrap()];
+
+    let result = installment_due_dates(BillingCycle::default(), purchased_on, None, Some(due_dates));
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_INSTALLMENTS_ERROR
+    });
+    assert!(installment_due_dates(BillingCycle::default(), purchased_on, Some(25), None).is_err());
+}
@@ -0,0 +1,90 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate};
+use crate::domain::{
+    entities::{Statement, Tenant},
+    error::Error,
+    usecases::statement::{StatementUseCase, StatementResponseDTO}
+};
+use protocols::repository::Repository;
+use crate::data::usecases::account::protocols::repository::Repository as AccountRepository;
+use crate::data::usecases::user::{get_user_by_document, protocols::repository::Repository as UserRepository};
+use crate::data::protocols::uuid::Uuid;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    account_repository: Box<dyn AccountRepository + Send + Sync>,
+    user_repository: Box<dyn UserRepository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(
+        repository: Box<dyn Repository + Send + Sync>,
+        account_repository: Box<dyn AccountRepository + Send + Sync>,
+        user_repository: Box<dyn UserRepository + Send + Sync>,
+        uuid_generator: Box<dyn Uuid + Send + Sync>
+    ) -> UseCase {
+        UseCase { repository, account_repository, user_repository, uuid_generator }
+    }
+}
+
+#[async_trait]
+impl StatementUseCase for UseCase {
+    async fn close_cycles(&self, reference_date: NaiveDate) -> Result<usize, Error> {
+        // cycles are closed for every store at once
+        let tenant = Tenant::Platform;
+        let states = self.repository.get_billing_states(&tenant).await?;
+
+        let mut generated = 0;
+        for state in states {
+            let cycle = state.account.get_billing_cycle();
+            let closing_date = cycle.last_closing_on_or_before(reference_date);
+
+            // cycles missed while the job was down are billed together in the latest one
+            let period_start = match state.last_closing_date {
+                Some(last) if last >= closing_date => continue,
+                Some(last) => last + Days::new(1),
+                None => state.account.get_created_at().date_naive()
+            };
+
+            if closing_date < period_start {
+                continue;
+            }
+
+            let account_id = String::from(state.account.get_id());
+            let transactions = self.repository.get_transactions(&tenant, &account_id, period_start, closing_date).await?;
+            let due_date = cycle.due_date_for_closing(closing_date);
+
+            let mut statement = match Statement::close(account_id, period_start, closing_date, due_date, state.last_closing_balance, &transactions) {
+                Some(s) => s,
+                None => return Err(Error::new_internal("statement balance overflow"))
+            };
+            statement.set_uuid(self.uuid_generator.generate());
+
+            self.repository.create(&tenant, statement).await?;
+            generated += 1;
+        }
+        Ok(generated)
+    }
+
+    async fn list(&self, tenant: &Tenant, document: &str) -> Result<Vec<StatementResponseDTO>, Error> {
+        let user = get_user_by_document(self.user_repository.as_ref(), tenant, document).await?;
+        let account = self.account_repository.get_by_user_id(tenant, user.get_id()).await?;
+
+        let statements = self.repository.get_by_account_id(tenant, account.get_id()).await?;
+        let mut response: Vec<StatementResponseDTO> = Vec::with_capacity(statements.len());
+        for statement in statements {
+            response.push(StatementResponseDTO::from_statement(statement)?);
+        }
+        Ok(response)
+    }
+
+    async fn get(&self, tenant: &Tenant, id: &str) -> Result<StatementResponseDTO, Error> {
+        let statement = self.repository.get_by_id(tenant, id).await?;
+        StatementResponseDTO::from_statement(statement)
+    }
+}
+
+mod tests;
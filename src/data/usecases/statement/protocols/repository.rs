@@ -0,0 +1,21 @@
+use mockall::automock;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::{
+    entities::{LedgerTransaction, Statement, Tenant},
+    error::Error,
+    usecases::statement::BillingState
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    async fn get_billing_states(&self, tenant: &Tenant) -> Result<Vec<BillingState>, Error>;
+    /// Ledger transactions of the account posted from `from` up to `to`, both inclusive.
+    async fn get_transactions(&self, tenant: &Tenant, account_id: &str, from: NaiveDate, to: NaiveDate) -> Result<Vec<LedgerTransaction>, Error>;
+    /// Stores the statement and its lines. A cycle already billed is left untouched.
+    async fn create(&self, tenant: &Tenant, statement: Statement) -> Result<(), Error>;
+    async fn get_by_account_id(&self, tenant: &Tenant, account_id: &str) -> Result<Vec<Statement>, Error>;
+    /// A statement of an account opened in another store than the tenant's is not found.
+    async fn get_by_id(&self, tenant: &Tenant, id: &str) -> Result<Statement, Error>;
+}
@@ -0,0 +1,104 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_bill_the_transactions_of_a_closed_cycle() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use mockall::predicate::eq;
+    use crate::data::usecases::statement::UseCase;
+    use crate::data::usecases::statement::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{CreditAccount, LedgerTransaction, Payment, PaymentMethod, Purchase, Tenant};
+    use crate::domain::usecases::statement::{StatementUseCase, BillingState};
+    use crate::domain::types::money::Money;
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_uuid(String::from("account_id"));
+    account.set_created_at(Utc.with_ymd_and_hms(2024, 2, 20, 12, 0, 0).unwrap());
+
+    let purchase = Purchase::new(String::from("account_id"), Money::from_cents(5000), String::from("arroz"), String::new(), Utc.with_ymd_and_hms(2024, 2, 25, 12, 0, 0).unwrap());
+    let payment = Payment::new(String::from("account_id"), Money::from_cents(2000), PaymentMethod::Pix, String::from("caixa-1"));
+    let transactions = vec![
+        LedgerTransaction::for_purchase(String::from("t1"), &purchase),
+        LedgerTransaction::for_payment(String::from("t2"), &payment)
+    ];
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_billing_states().return_const(Ok(vec![BillingState { account, last_closing_date: None, last_closing_balance: Money::zero() }]));
+    repository_mock.expect_get_transactions()
+        .with(eq(Tenant::Platform), eq("account_id"), eq(NaiveDate::from_ymd_opt(2024, 2, 20).unwrap()), eq(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()))
+        .return_const(Ok(transactions));
+    repository_mock.expect_create()
+        .times(1)
+        .withf(|_, s| s.get_closing_balance() == Money::from_cents(3000) && s.get_lines().len() == 2 && s.get_due_date() == NaiveDate::from_ymd_opt(2024, 3, 15).unwrap())
+        .return_const(Ok(()));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(uuid_mock));
+
+    let result = sut.close_cycles(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()).await;
+    assert!(matches!(result, Ok(1)));
+}
+
+#[tokio::test]
+async fn it_should_not_bill_a_cycle_twice() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use crate::data::usecases::statement::UseCase;
+    use crate::data::usecases::statement::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::CreditAccount;
+    use crate::domain::usecases::statement::{StatementUseCase, BillingState};
+    use crate::domain::types::money::Money;
+
+    let mut account = CreditAccount::new(String::from("user_id"), Money::from_cents(10000));
+    account.set_uuid(String::from("account_id"));
+    account.set_created_at(Utc.with_ymd_and_hms(2024, 1, 20, 12, 0, 0).unwrap());
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_billing_states().return_const(Ok(vec![BillingState {
+        account,
+        last_closing_date: Some(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()),
+        last_closing_balance: Money::from_cents(3000)
+    }]));
+    repository_mock.expect_get_transactions().never();
+    repository_mock.expect_create().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockUuid::new()));
+
+    let result = sut.close_cycles(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()).await;
+    assert!(matches!(result, Ok(0)));
+}
+
+#[tokio::test]
+async fn it_should_look_statements_up_within_the_tenant_store() {
+    use mockall::predicate::eq;
+    use crate::data::usecases::statement::UseCase;
+    use crate::data::usecases::statement::protocols::repository::MockRepository;
+    use crate::data::usecases::account::protocols::repository::MockRepository as MockAccountRepository;
+    use crate::data::usecases::user::protocols::repository::MockRepository as MockUserRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::Tenant;
+    use crate::domain::error::Error;
+    use crate::domain::usecases::statement::{StatementUseCase, STATEMENT_NOT_FOUND};
+
+    let tenant = Tenant::Store(String::from("store_id"));
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_id()
+        .with(eq(tenant.clone()), eq("statement_id"))
+        .times(1)
+        .return_const(Err(Error::new_not_found(STATEMENT_NOT_FOUND, "statement")));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockAccountRepository::new()), Box::new(MockUserRepository::new()), Box::new(MockUuid::new()));
+
+    let result = sut.get(&tenant, "statement_id").await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == STATEMENT_NOT_FOUND
+    });
+}
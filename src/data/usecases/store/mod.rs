@@ -0,0 +1,59 @@
+pub mod protocols;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::domain::{
+    error::Error,
+    usecases::store::{StoreUseCase, StoreCreateRequestDTO, StoreUpdateRequestDTO, StoreResponseDTO, check_store_name, get_fee_policy}
+};
+use protocols::repository::Repository;
+use crate::data::protocols::uuid::Uuid;
+
+pub struct UseCase {
+    repository: Box<dyn Repository + Send + Sync>,
+    uuid_generator: Box<dyn Uuid + Send + Sync>
+}
+
+impl UseCase {
+    pub fn new(repository: Box<dyn Repository + Send + Sync>, uuid_generator: Box<dyn Uuid + Send + Sync>) -> UseCase {
+        UseCase { repository, uuid_generator }
+    }
+}
+
+#[async_trait]
+impl StoreUseCase for UseCase {
+    async fn create(&self, dto: StoreCreateRequestDTO) -> Result<StoreResponseDTO, Error> {
+        let mut store = dto.to_store()?;
+        store.set_uuid(self.uuid_generator.generate());
+
+        self.repository.create(store.clone()).await?;
+        Ok(StoreResponseDTO::from_store(&store))
+    }
+
+    async fn update(&self, id: &str, dto: StoreUpdateRequestDTO) -> Result<(), Error> {
+        let name = check_store_name(&dto.name)?;
+        let fee_policy = get_fee_policy(dto.late_fee_bps, dto.monthly_interest_bps)?;
+        let mut store = self.repository.get_by_id(id).await?;
+
+        store.set_name(name);
+        store.set_fee_policy(fee_policy);
+        store.set_updated_at(Utc::now());
+        self.repository.update(store).await
+    }
+
+    async fn get(&self, id: &str) -> Result<StoreResponseDTO, Error> {
+        let store = self.repository.get_by_id(id).await?;
+        Ok(StoreResponseDTO::from_store(&store))
+    }
+
+    async fn list(&self) -> Result<Vec<StoreResponseDTO>, Error> {
+        let stores = self.repository.list().await?;
+        Ok(stores.iter().map(StoreResponseDTO::from_store).collect())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), Error> {
+        self.repository.delete_by_id(id).await
+    }
+}
+
+mod tests;
@@ -0,0 +1,16 @@
+use mockall::automock;
+use async_trait::async_trait;
+use crate::domain::{
+    entities::Store,
+    error::Error
+};
+
+#[automock]
+#[async_trait]
+pub trait Repository {
+    async fn create(&self, store: Store) -> Result<(), Error>;
+    async fn update(&self, store: Store) -> Result<(), Error>;
+    async fn get_by_id(&self, id: &str) -> Result<Store, Error>;
+    async fn list(&self) -> Result<Vec<Store>, Error>;
+    async fn delete_by_id(&self, id: &str) -> Result<(), Error>;
+}
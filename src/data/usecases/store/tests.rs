@@ -0,0 +1,113 @@
+use tokio;
+
+#[cfg(test)]
+#[tokio::test]
+async fn it_should_return_error_when_cnpj_is_invalid() {
+    use crate::data::usecases::store::UseCase;
+    use crate::data::usecases::store::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::store::{StoreUseCase, StoreCreateRequestDTO, INVALID_CNPJ_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()));
+    let dto = StoreCreateRequestDTO {
+        name: String::from("store"),
+        document: String::from("11.222.333/0001-80"),
+        late_fee_bps: None,
+        monthly_interest_bps: None
+    };
+
+    let result = sut.create(dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_CNPJ_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_create_a_store_with_generated_id() {
+    use crate::data::usecases::store::UseCase;
+    use crate::data::usecases::store::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::usecases::store::{StoreUseCase, StoreCreateRequestDTO};
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create()
+        .withf(|store| store.get_id() == "uuid" && store.get_document().to_string() == "11222333000181")
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock));
+    let dto = StoreCreateRequestDTO {
+        name: String::from(" store "),
+        document: String::from("11.222.333/0001-81"),
+        late_fee_bps: None,
+        monthly_interest_bps: None
+    };
+
+    let response = sut.create(dto).await.unwrap();
+    assert_eq!(response.id, "uuid");
+    assert_eq!(response.name, "store");
+}
+
+#[tokio::test]
+async fn it_should_only_rename_the_store_on_update() {
+    use crate::data::usecases::store::UseCase;
+    use crate::data::usecases::store::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{FeePolicy, Store};
+    use crate::domain::types::cnpj::CNPJ;
+    use crate::domain::usecases::store::{StoreUseCase, StoreUpdateRequestDTO};
+
+    let mut store = Store::new(String::from("old"), CNPJ::from_string(String::from("11222333000181")).unwrap());
+    store.set_uuid(String::from("store_id"));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_id().return_const(Ok(store));
+    repository_mock.expect_update()
+        .withf(|store| store.get_id() == "store_id" &&
+            store.get_name() == "new" &&
+            store.get_document().to_string() == "11222333000181" &&
+            store.get_fee_policy() == Some(FeePolicy::new(150, 80).unwrap()))
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()));
+    let dto = StoreUpdateRequestDTO {
+        name: String::from("new"),
+        late_fee_bps: Some(150),
+        monthly_interest_bps: Some(80)
+    };
+    let result = sut.update("store_id", dto).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn it_should_reject_store_fees_above_the_legal_limits() {
+    use crate::data::usecases::store::UseCase;
+    use crate::data::usecases::store::protocols::repository::MockRepository;
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::LEGAL_MAX_LATE_FEE_BPS;
+    use crate::domain::usecases::store::{StoreUseCase, StoreCreateRequestDTO, INVALID_FEE_POLICY_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()));
+    let dto = StoreCreateRequestDTO {
+        name: String::from("store"),
+        document: String::from("11.222.333/0001-81"),
+        late_fee_bps: Some(LEGAL_MAX_LATE_FEE_BPS + 1),
+        monthly_interest_bps: Some(100)
+    };
+
+    let result = sut.create(dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_FEE_POLICY_ERROR
+    });
+}
@@ -1,13 +1,20 @@
 pub mod protocols;
 
 use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{NaiveDateTime, Utc};
 use crate::domain::{
+    entities::{User, UserStatus, UserStatusChange, StatusChangeReason, Tenant},
     error::Error, 
-    types::cpf::CPF,
-    usecases::user::{self, UserUseCase, UserCreateRequestDTO, UserUpdateRequestDTO, PublicUserResponseDTO}
+    types::{cpf::CPF, birth_date::BirthDate},
+    usecases::user::{
+        self, UserUseCase, UserCreateRequestDTO, UserUpdateRequestDTO, PublicUserResponseDTO, UserListQueryDTO, UserListResponseDTO,
+        UserSearchQueryDTO, UserStatusChangeRequestDTO, UserPatchRequestDTO, INVALID_CURSOR_ERROR, INVALID_USER_FILTER_ERROR, INVALID_SEARCH_QUERY_ERROR,
+        DEFAULT_USER_PAGE_SIZE, MAX_USER_PAGE_SIZE, MIN_SEARCH_QUERY_LENGTH, check_user_status
+    }
 };
 use protocols::{
-    repository::Repository,
+    repository::{Repository, UserFilter, UserSort, UserCursor},
     hash::Hash,
 };
 use crate::data::protocols::uuid::Uuid;
@@ -22,11 +29,135 @@ impl UseCase {
     pub fn new(repository: Box<dyn Repository + Send + Sync>, uuid_generator: Box<dyn Uuid + Sync + Send>, hash: Box<dyn Hash + Sync + Send>) -> UseCase {
         UseCase { repository, uuid_generator, hash}
     }
+
+    async fn change_status(&self, tenant: &Tenant, actor: &str, document: &str, dto: UserStatusChangeRequestDTO, to_status: UserStatus) -> Result<(), Error> {
+        let reason = StatusChangeReason::from_string(&dto.reason.to_uppercase());
+        if matches!(reason, StatusChangeReason::Unknown | StatusChangeReason::Restored) {
+            return Err(Error::new_business(user::INVALID_STATUS_CHANGE_REASON_ERROR));
+        }
+
+        let note = dto.note.map(|n| String::from(n.trim())).filter(|n| !n.is_empty());
+        if note.as_ref().is_some_and(|n| n.chars().count() > user::MAX_STATUS_CHANGE_NOTE_LENGTH) {
+            return Err(Error::new_business_with_message(user::INVALID_STATUS_CHANGE_REASON_ERROR, "note too long"));
+        }
+
+        let user = get_user_by_document(self.repository.as_ref(), tenant, document).await?;
+        if to_status == UserStatus::Blocked {
+            check_user_status(user.get_status())?;
+        } else if user.get_status() != UserStatus::Blocked {
+            return Err(Error::new_business(user::USER_NOT_BLOCKED_ERROR));
+        }
+
+        let mut change = UserStatusChange::new(String::from(user.get_id()), user.get_status(), to_status, reason, String::from(actor));
+        change.set_uuid(self.uuid_generator.generate());
+        change.set_note(note);
+        self.repository.change_status(tenant, change).await
+    }
+}
+
+/// Validates the CPF and loads its user, shared by every use case keyed by document.
+pub(crate) async fn get_user_by_document(repository: &(dyn Repository + Send + Sync), tenant: &Tenant, document: &str) -> Result<User, Error> {
+    let cpf = match CPF::from_string(String::from(document)) {
+        Ok(c) => c,
+        Err(_) => return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+    };
+
+    if !cpf.is_valid() {
+        return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+    }
+
+    repository.get_by_cpf(tenant, document).await
+}
+
+/// Same as `get_user_by_document` for callers naming the store themselves, such as login.
+pub(crate) async fn get_user_by_store_and_document(repository: &(dyn Repository + Send + Sync), store_id: Option<String>, document: &str) -> Result<User, Error> {
+    let cpf = match CPF::from_string(String::from(document)) {
+        Ok(c) => c,
+        Err(_) => return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+    };
+
+    if !cpf.is_valid() {
+        return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+    }
+
+    repository.get_by_store_and_cpf(store_id, document).await
+}
+
+fn get_user_filter(query: UserListQueryDTO) -> Result<UserFilter, Error> {
+    let status = match query.status.as_deref().map(|s| UserStatus::from_string(&s.to_uppercase())) {
+        Some(UserStatus::Unknown) => return Err(Error::new_business_with_message(INVALID_USER_FILTER_ERROR, "unknown status")),
+        status => status
+    };
+
+    let sort = match query.sort.as_deref() {
+        None | Some("-created_at") => UserSort::CreatedAtDesc,
+        Some("created_at") => UserSort::CreatedAtAsc,
+        Some("name") => UserSort::NameAsc,
+        Some("-name") => UserSort::NameDesc,
+        Some(_) => return Err(Error::new_business_with_message(INVALID_USER_FILTER_ERROR, "unknown sort"))
+    };
+
+    let limit = query.limit.unwrap_or(DEFAULT_USER_PAGE_SIZE);
+    if limit == 0 || limit > MAX_USER_PAGE_SIZE {
+        return Err(Error::new_business_with_message(INVALID_USER_FILTER_ERROR, "limit out of range"));
+    }
+
+    let birth_dates_reversed = matches!((query.birth_date_from, query.birth_date_to), (Some(from), Some(to)) if from > to);
+    let created_reversed = matches!((query.created_from, query.created_to), (Some(from), Some(to)) if from > to);
+    if birth_dates_reversed || created_reversed {
+        return Err(Error::new_business_with_message(INVALID_USER_FILTER_ERROR, "range starts after it ends"));
+    }
+
+    let after = match query.cursor {
+        Some(cursor) => Some(decode_cursor(&cursor)?),
+        None => None
+    };
+
+    Ok(UserFilter {
+        status,
+        name_prefix: query.name.map(|n| String::from(n.trim())).filter(|n| !n.is_empty()),
+        birth_date_from: query.birth_date_from,
+        birth_date_to: query.birth_date_to,
+        created_from: query.created_from,
+        created_to: query.created_to,
+        sort,
+        after,
+        limit
+    })
+}
+
+/// Cursors are opaque to clients: `<created_at in microseconds>:<id>:<name>` in base64.
+fn to_public_users(users: Vec<User>, mask_documents: bool) -> Vec<PublicUserResponseDTO> {
+    users.into_iter().map(|u| match mask_documents {
+        true => PublicUserResponseDTO::from_user_masked(u),
+        false => PublicUserResponseDTO::from_user(u)
+    }).collect()
+}
+
+fn encode_cursor(user: &User) -> String {
+    let position = format!("{}:{}:{}", user.get_created_at().timestamp_micros(), user.get_id(), user.get_name());
+    URL_SAFE_NO_PAD.encode(position)
+}
+
+fn decode_cursor(cursor: &str) -> Result<UserCursor, Error> {
+    let position = match URL_SAFE_NO_PAD.decode(cursor).map(String::from_utf8) {
+        Ok(Ok(p)) => p,
+        _ => return Err(Error::new_business(INVALID_CURSOR_ERROR))
+    };
+
+    let mut parts = position.splitn(3, ':');
+    match (parts.next().map(|p| p.parse::<i64>()), parts.next(), parts.next()) {
+        (Some(Ok(micros)), Some(id), Some(name)) => match NaiveDateTime::from_timestamp_micros(micros).map(|d| d.and_utc()) {
+            Some(created_at) => Ok(UserCursor { id: String::from(id), name: String::from(name), created_at }),
+            None => Err(Error::new_business(INVALID_CURSOR_ERROR))
+        },
+        _ => Err(Error::new_business(INVALID_CURSOR_ERROR))
+    }
 }
 
 #[async_trait]
 impl UserUseCase for UseCase {
-    async fn create(&self, dto: UserCreateRequestDTO) -> Result<(), Error>{
+    async fn create(&self, tenant: &Tenant, dto: UserCreateRequestDTO) -> Result<(), Error>{
         let password = dto.password.clone();
         let mut user = match dto.to_user() {
             Ok(u) => u,
@@ -41,16 +172,20 @@ impl UserUseCase for UseCase {
             return Err(Error::new_business(user::UNDERAGE_ERROR));
         }
 
+        if let Some(store_id) = tenant.get_store_id() {
+            user.set_store_id(Some(String::from(store_id)));
+        }
+
         user.set_uuid(self.uuid_generator.generate());
         match self.hash.run(password) {
             Ok(hashed_password) => user.set_password(hashed_password),
             Err(message) => return Err(Error::new_internal(&message))
         }
 
-        return self.repository.create(user).await;
+        return self.repository.create(tenant, user).await;
     }
 
-    async fn update(&self, dto: UserUpdateRequestDTO) -> Result<(), Error> {
+    async fn update(&self, tenant: &Tenant, dto: UserUpdateRequestDTO) -> Result<(), Error> {
         let user = match dto.to_user() {
             Ok(u) => u,
             Err(e) => return Err(e)
@@ -64,10 +199,54 @@ impl UserUseCase for UseCase {
             return Err(Error::new_business(user::UNDERAGE_ERROR))
         }
 
-        return self.repository.update(user).await;
+        return self.repository.update(tenant, user).await;
     }
 
-    async fn get(&self, document: &str) -> Result<PublicUserResponseDTO, Error> {
+    async fn patch(&self, tenant: &Tenant, document: &str, dto: UserPatchRequestDTO) -> Result<PublicUserResponseDTO, Error> {
+        let mut user = get_user_by_document(self.repository.as_ref(), tenant, document).await?;
+        let mut changed = false;
+
+        if let Some(name) = dto.name {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(Error::new_business(user::INVALID_NAME_ERROR));
+            }
+            if name != user.get_name() {
+                user.set_name(String::from(name));
+                changed = true;
+            }
+        }
+
+        if let Some(new_document) = dto.document {
+            let cpf = match CPF::from_string(new_document) {
+                Ok(c) if c.is_valid() => c,
+                _ => return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+            };
+            if cpf != *user.get_document() {
+                user.set_document(cpf);
+                changed = true;
+            }
+        }
+
+        if let Some(birth_date) = dto.birth_date {
+            let birth_date = BirthDate::from_naive(birth_date);
+            if birth_date.is_under_age() {
+                return Err(Error::new_business(user::UNDERAGE_ERROR));
+            }
+            if birth_date != user.get_birth_date() {
+                user.set_birth_date(birth_date);
+                changed = true;
+            }
+        }
+
+        if changed {
+            user.set_updated_at(Utc::now());
+            self.repository.update(tenant, user.clone()).await?;
+        }
+        Ok(PublicUserResponseDTO::from_user(user))
+    }
+
+    async fn get(&self, tenant: &Tenant, document: &str) -> Result<PublicUserResponseDTO, Error> {
         let cpf = match CPF::from_string(String::from(document)) {
             Ok(c) => c,
             Err(_) => return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
@@ -77,13 +256,13 @@ impl UserUseCase for UseCase {
             return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
         }
 
-        match self.repository.get_by_cpf(document).await {
+        match self.repository.get_by_cpf(tenant, document).await {
             Ok(u) => Ok(PublicUserResponseDTO::from_user(u)),
             Err(e) => Err(e)
         }
     }
 
-    async fn delete(&self, document: &str) -> Result<(), Error> {
+    async fn delete(&self, tenant: &Tenant, document: &str) -> Result<(), Error> {
         let cpf = match CPF::from_string(String::from(document)) {
             Ok(c) => c,
             Err(_) => return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
@@ -93,7 +272,65 @@ impl UserUseCase for UseCase {
             return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
         }
 
-        self.repository.delete_by_cpf(document).await
+        self.repository.delete_by_cpf(tenant, document).await
+    }
+
+    async fn restore(&self, tenant: &Tenant, actor: &str, document: &str) -> Result<(), Error> {
+        let cpf = match CPF::from_string(String::from(document)) {
+            Ok(c) => c,
+            Err(_) => return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+        };
+
+        if !cpf.is_valid() {
+            return Err(Error::new_business(user::INVALID_DOCUMENT_ERROR))
+        }
+
+        self.repository.restore_by_cpf(tenant, document, &self.uuid_generator.generate(), actor).await
+    }
+
+    async fn list(&self, tenant: &Tenant, query: UserListQueryDTO, mask_documents: bool) -> Result<UserListResponseDTO, Error> {
+        let mut filter = get_user_filter(query)?;
+        let limit = filter.limit as usize;
+
+        // one extra user tells whether there is a next page
+        filter.limit += 1;
+        let mut users = self.repository.list(tenant, filter).await?;
+
+        let next_cursor = match users.len() > limit {
+            true => {
+                users.truncate(limit);
+                users.last().map(encode_cursor)
+            },
+            false => None
+        };
+
+        Ok(UserListResponseDTO {
+            items: to_public_users(users, mask_documents),
+            next_cursor
+        })
+    }
+
+    async fn search(&self, tenant: &Tenant, query: UserSearchQueryDTO, mask_documents: bool) -> Result<Vec<PublicUserResponseDTO>, Error> {
+        let name = query.q.trim();
+        if name.chars().count() < MIN_SEARCH_QUERY_LENGTH {
+            return Err(Error::new_business(INVALID_SEARCH_QUERY_ERROR));
+        }
+
+        let limit = query.limit.unwrap_or(DEFAULT_USER_PAGE_SIZE);
+        if limit == 0 || limit > MAX_USER_PAGE_SIZE {
+            return Err(Error::new_business_with_message(INVALID_SEARCH_QUERY_ERROR, "limit out of range"));
+        }
+
+        let users = self.repository.search(tenant, name, limit).await?;
+        Ok(to_public_users(users, mask_documents))
+    }
+
+    async fn block(&self, tenant: &Tenant, actor: &str, document: &str, dto: UserStatusChangeRequestDTO) -> Result<(), Error> {
+        self.change_status(tenant, actor, document, dto, UserStatus::Blocked).await
+    }
+
+    async fn unblock(&self, tenant: &Tenant, actor: &str, document: &str, dto: UserStatusChangeRequestDTO) -> Result<(), Error> {
+        self.change_status(tenant, actor, document, dto, UserStatus::Active).await
     }
 }
 
@@ -1,7 +1,26 @@
+use std::sync::Arc;
 use mockall::automock;
 
 #[automock]
 pub trait Hash {
     fn run(&self, plain_text: String) -> Result<String, String>;
     fn verify(&self, plain_text: String, hash: String) -> Result<bool, String>;
-}
\ No newline at end of file
+    /// Whether the hash was made with an old pepper or cost and should be replaced
+    /// the next time the plain text is known.
+    fn needs_rehash(&self, hash: String) -> bool;
+}
+
+/// Lets a single configured implementation be shared by every use case.
+impl<T: Hash + ?Sized> Hash for Arc<T> {
+    fn run(&self, plain_text: String) -> Result<String, String> {
+        (**self).run(plain_text)
+    }
+
+    fn verify(&self, plain_text: String, hash: String) -> Result<bool, String> {
+        (**self).verify(plain_text, hash)
+    }
+
+    fn needs_rehash(&self, hash: String) -> bool {
+        (**self).needs_rehash(hash)
+    }
+}
@@ -1,15 +1,63 @@
 use mockall::automock;
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use crate::domain::{
-    entities::User,
+    entities::{User, UserStatus, UserStatusChange, Tenant},
     error::Error
 };
 
+/// Ties on the sort key are broken by id, so pages never skip nor repeat users.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum UserSort {
+    CreatedAtAsc,
+    #[default]
+    CreatedAtDesc,
+    NameAsc,
+    NameDesc,
+}
+
+/// Last user of the previous page, carrying every sort key so it resumes any order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserCursor {
+    pub id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UserFilter {
+    pub status: Option<UserStatus>,
+    pub name_prefix: Option<String>,
+    pub birth_date_from: Option<NaiveDate>,
+    pub birth_date_to: Option<NaiveDate>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub sort: UserSort,
+    pub after: Option<UserCursor>,
+    pub limit: u32,
+}
+
+/// Every method only reaches the customers of `tenant`.
 #[automock]
 #[async_trait]
 pub trait Repository {
-    async fn create(&self, user: User) -> Result<(), Error>;
-    async fn update(&self, user: User) -> Result<(), Error>;
-    async fn get_by_cpf(&self, document: &str) -> Result<User, Error>;
-    async fn delete_by_cpf(&self, document: &str) -> Result<(), Error>;
+    async fn create(&self, tenant: &Tenant, user: User) -> Result<(), Error>;
+    /// Writes the profile fields, the password and status have their own methods.
+    async fn update(&self, tenant: &Tenant, user: User) -> Result<(), Error>;
+    async fn update_password(&self, tenant: &Tenant, user_id: &str, password: &str) -> Result<(), Error>;
+    /// Fails when the tenant reaches customers of several stores holding `document`.
+    async fn get_by_cpf(&self, tenant: &Tenant, document: &str) -> Result<User, Error>;
+    /// Looks the customer up in the given store, or among the ones bound to no store, for
+    /// callers that are not acting as a tenant yet such as login.
+    async fn get_by_store_and_cpf(&self, store_id: Option<String>, document: &str) -> Result<User, Error>;
+    async fn get_by_id(&self, tenant: &Tenant, id: &str) -> Result<User, Error>;
+    async fn delete_by_cpf(&self, tenant: &Tenant, document: &str) -> Result<(), Error>;
+    /// Brings back the latest deleted user holding `document` with the status they had
+    /// before deletion, recording the change as `change_id` made by `changed_by`.
+    async fn restore_by_cpf(&self, tenant: &Tenant, document: &str, change_id: &str, changed_by: &str) -> Result<(), Error>;
+    async fn list(&self, tenant: &Tenant, filter: UserFilter) -> Result<Vec<User>, Error>;
+    /// Users whose name resembles `name`, best matches first.
+    async fn search(&self, tenant: &Tenant, name: &str, limit: u32) -> Result<Vec<User>, Error>;
+    /// Moves the user to the new status and records the change, the user must still be in `from_status`.
+    async fn change_status(&self, tenant: &Tenant, change: UserStatusChange) -> Result<(), Error>;
 }
\ No newline at end of file
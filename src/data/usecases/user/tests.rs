@@ -2,6 +2,7 @@
 #[cfg(test)]
 #[tokio::test]
 async fn it_should_return_an_error_when_repo_fails() {
+    use crate::domain::entities::Tenant;
     use chrono::NaiveDate;
     use crate::{data::usecases::user::{UseCase, UserCreateRequestDTO}, domain::usecases::user::UserUseCase};
     use super::protocols::{repository::MockRepository, hash::MockHash};
@@ -24,9 +25,10 @@ async fn it_should_return_an_error_when_repo_fails() {
         name: String::from("Claudion du fret"),
         document: String::from("11133322292"),
         birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
-        password: String::from("password")
+        password: String::from("password"),
+        store_id: None
     };
-    let result = sut.create(dto).await;
+    let result = sut.create(&Tenant::Platform, dto).await;
 
     assert!(match result {
         Ok(()) => false,
@@ -36,6 +38,7 @@ async fn it_should_return_an_error_when_repo_fails() {
 
 #[tokio::test]
 async fn it_should_call_uuid_generator() {
+    use crate::domain::entities::Tenant;
     use chrono::NaiveDate;
     use crate::{data::usecases::user::{UseCase, UserCreateRequestDTO}, domain::usecases::user::UserUseCase};
     use super::protocols::{repository::MockRepository, hash::MockHash};
@@ -45,7 +48,8 @@ async fn it_should_call_uuid_generator() {
         name: String::from("Claudion du fret"),
         document: String::from("11133322292"),
         birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
-        password: String::from("password")
+        password: String::from("password"),
+        store_id: None
     };
 
     let mut hash_mock = MockHash::new();
@@ -58,11 +62,12 @@ async fn it_should_call_uuid_generator() {
     uuid_mock.expect_generate().return_const("uuid");
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let _ = sut.create(dto).await;
+    let _ = sut.create(&Tenant::Platform, dto).await;
 }
 
 #[tokio::test]
 async fn it_should_return_error_if_password_hash_fails() {
+    use crate::domain::entities::Tenant;
     use chrono::NaiveDate;
     use crate::data::usecases::user::{UseCase, UserCreateRequestDTO};
     use crate::domain::{usecases::user::UserUseCase, error::Kind};
@@ -74,7 +79,8 @@ async fn it_should_return_error_if_password_hash_fails() {
         name: String::from("Claudion du fret"),
         document: String::from("52976776024"),
         birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
-        password: String::from("password")
+        password: String::from("password"),
+        store_id: None
     };
 
     let mut hash_mock = MockHash::new();
@@ -87,7 +93,7 @@ async fn it_should_return_error_if_password_hash_fails() {
     uuid_mock.expect_generate().return_const("uuid");
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.create(dto).await;
+    let result = sut.create(&Tenant::Platform, dto).await;
 
     assert!(match result {
         Ok(())=> false,
@@ -97,6 +103,7 @@ async fn it_should_return_error_if_password_hash_fails() {
 
 #[tokio::test]
 async fn it_should_return_error_when_invalid_document_string_is_given() {
+    use crate::domain::entities::Tenant;
     use chrono::NaiveDate;
     use crate::{data::usecases::user::{UseCase, UserCreateRequestDTO}, domain::{usecases::user::UserUseCase, error::Error}};
     use super::protocols::{repository::MockRepository, hash::MockHash};
@@ -108,6 +115,7 @@ async fn it_should_return_error_when_invalid_document_string_is_given() {
         document: String::from("invalid123"),
         birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
         password: String::from("password"),
+        store_id: None
     };
 
     let mut hash_mock = MockHash::new();
@@ -120,7 +128,7 @@ async fn it_should_return_error_when_invalid_document_string_is_given() {
     repository_mock.expect_create().return_const(Ok(()));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.create(dto).await;
+    let result = sut.create(&Tenant::Platform, dto).await;
 
     let mut error: Error = Error::new();
     assert!(match result {
@@ -137,6 +145,7 @@ async fn it_should_return_error_when_invalid_document_string_is_given() {
 
 #[tokio::test]
 async fn it_should_return_error_when_invalid_cpf_is_given() {
+    use crate::domain::entities::Tenant;
     use chrono::NaiveDate;
     use crate::{data::usecases::user::{UseCase, UserCreateRequestDTO}, domain::{usecases::user::UserUseCase, error::Error}};
     use super::protocols::{repository::MockRepository, hash::MockHash};
@@ -148,6 +157,7 @@ async fn it_should_return_error_when_invalid_cpf_is_given() {
         document: String::from("40735626066"),
         birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
         password: String::from("password"),
+        store_id: None
     };
 
     let mut hash_mock = MockHash::new();
@@ -160,7 +170,7 @@ async fn it_should_return_error_when_invalid_cpf_is_given() {
     repository_mock.expect_create().return_const(Ok(()));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.create(dto).await;
+    let result = sut.create(&Tenant::Platform, dto).await;
 
     let mut error: Error = Error::new();
     assert!(match result {
@@ -177,7 +187,8 @@ async fn it_should_return_error_when_invalid_cpf_is_given() {
 
 #[tokio::test]
 async fn it_should_return_error_when_user_is_underage_given() {
-    use chrono::NaiveDate;
+    use crate::domain::entities::Tenant;
+    use chrono::{Months, Utc};
     use crate::{data::usecases::user::{UseCase, UserCreateRequestDTO}, domain::{usecases::user::UserUseCase, error::Error}};
     use super::protocols::{repository::MockRepository, hash::MockHash};
     use crate::domain::{error::Kind, usecases::user::UNDERAGE_ERROR};
@@ -186,8 +197,9 @@ async fn it_should_return_error_when_user_is_underage_given() {
     let dto = UserCreateRequestDTO{
         name: String::from("Claudion du fret"),
         document: String::from("55168718086"),
-        birth_date: NaiveDate::parse_from_str("2007-09-05", "%Y-%m-%d").unwrap(),
+        birth_date: Utc::now().date_naive().checked_sub_months(Months::new(12 * 17)).unwrap(),
         password: String::from("password"),
+        store_id: None
     };
 
     let mut hash_mock = MockHash::new();
@@ -200,7 +212,7 @@ async fn it_should_return_error_when_user_is_underage_given() {
     repository_mock.expect_create().return_const(Ok(()));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.create(dto).await;
+    let result = sut.create(&Tenant::Platform, dto).await;
 
     let mut error: Error = Error::new();
     assert!(match result {
@@ -218,6 +230,7 @@ async fn it_should_return_error_when_user_is_underage_given() {
 
 #[tokio::test]
 async fn it_should_not_return_error_on_success() {
+    use crate::domain::entities::Tenant;
     use chrono::NaiveDate;
     use crate::{
         data::usecases::user::{UseCase, UserCreateRequestDTO}, 
@@ -231,7 +244,8 @@ async fn it_should_not_return_error_on_success() {
         name: String::from("Claudion du fret"),
         document: String::from("40735626065"),
         birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
-        password: String::from("password")
+        password: String::from("password"),
+        store_id: None
     };
 
     let mut hash_mock = MockHash::new();
@@ -245,10 +259,10 @@ async fn it_should_not_return_error_on_success() {
     user.set_uuid(String::from("uuid"));
     user.set_password(String::from("hash_password"));
 
-    repository_mock.expect_create().with(eq(user)).return_const(Ok(()));
+    repository_mock.expect_create().with(eq(Tenant::Platform), eq(user)).return_const(Ok(()));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.create(dto).await;
+    let result = sut.create(&Tenant::Platform, dto).await;
 
     assert!(match result {
         Ok(()) => true,
@@ -258,6 +272,7 @@ async fn it_should_not_return_error_on_success() {
 
 #[tokio::test]
 async fn it_should_return_error_if_dto_map_fails() {
+    use crate::domain::entities::Tenant;
     use crate::domain::{
         usecases::user::{UserUseCase, UserUpdateRequestDTO, INVALID_DOCUMENT_ERROR},
         error::{Error, Kind}
@@ -279,7 +294,7 @@ async fn it_should_return_error_if_dto_map_fails() {
     let repository_mock = MockRepository::new();
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.update(dto).await;
+    let result = sut.update(&Tenant::Platform, dto).await;
 
     let mut err: Error = Error::new();
     assert!(match result {
@@ -297,6 +312,7 @@ async fn it_should_return_error_if_dto_map_fails() {
 
 #[tokio::test]
 async fn it_should_return_error_when_invalid_document_is_provided() {
+    use crate::domain::entities::Tenant;
     use crate::domain::usecases::user::{UserUseCase, UserUpdateRequestDTO};
     use crate::domain::{
         usecases::user::INVALID_DOCUMENT_ERROR,
@@ -319,7 +335,7 @@ async fn it_should_return_error_when_invalid_document_is_provided() {
     let repository_mock = MockRepository::new();
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.update(dto).await;
+    let result = sut.update(&Tenant::Platform, dto).await;
 
     let mut err: Error = Error::new();
     assert!(match result {
@@ -336,6 +352,7 @@ async fn it_should_return_error_when_invalid_document_is_provided() {
 
 #[tokio::test]
 async fn it_should_return_error_when_user_is_underage() {
+    use crate::domain::entities::Tenant;
     use crate::domain::usecases::user::{UserUseCase, UserUpdateRequestDTO};
     use crate::domain::{
         usecases::user::UNDERAGE_ERROR,
@@ -358,7 +375,7 @@ async fn it_should_return_error_when_user_is_underage() {
     let repository_mock = MockRepository::new();
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.update(dto).await;
+    let result = sut.update(&Tenant::Platform, dto).await;
 
     let mut err: Error = Error::new();
     assert!(match result {
@@ -375,6 +392,7 @@ async fn it_should_return_error_when_user_is_underage() {
 
 #[tokio::test]
 async fn it_should_return_error_when_repository_fails() {
+    use crate::domain::entities::Tenant;
     use crate::domain::usecases::user::{UserUseCase, UserUpdateRequestDTO};
     use crate::domain::error::{Error, Kind};
     use crate::data::usecases::user::UseCase;
@@ -396,10 +414,10 @@ async fn it_should_return_error_when_repository_fails() {
     let mut repository_mock = MockRepository::new();
     let user = dto.clone().to_user().unwrap();
 
-    repository_mock.expect_update().with(eq(user)).return_const(Err(expected_err));
+    repository_mock.expect_update().with(eq(Tenant::Platform), eq(user)).return_const(Err(expected_err));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.update(dto).await;
+    let result = sut.update(&Tenant::Platform, dto).await;
 
     let mut err: Error = Error::new();
     assert!(match result {
@@ -416,6 +434,7 @@ async fn it_should_return_error_when_repository_fails() {
 
 #[tokio::test]
 async fn it_should_not_return_error_on_update_success() {
+    use crate::domain::entities::Tenant;
     use crate::domain::usecases::user::{UserUseCase, UserUpdateRequestDTO};
     use crate::data::usecases::user::UseCase;
     use super::protocols::{repository::MockRepository, hash::MockHash};
@@ -435,9 +454,9 @@ async fn it_should_not_return_error_on_update_success() {
     let mut repository_mock = MockRepository::new();
     let user = dto.clone().to_user().unwrap();
 
-    repository_mock.expect_update().with(eq(user)).return_const(Ok(()));
+    repository_mock.expect_update().with(eq(Tenant::Platform), eq(user)).return_const(Ok(()));
     let sut: UseCase = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.update(dto).await;
+    let result = sut.update(&Tenant::Platform, dto).await;
 
     assert!(match result {
         Ok(()) => true,
@@ -448,6 +467,7 @@ async fn it_should_not_return_error_on_update_success() {
 
 #[tokio::test]
 async fn it_should_return_an_error_if_invalid_document_is_given() {
+    use crate::domain::entities::Tenant;
     use crate::data::usecases::user::UseCase;
     use crate::data::protocols::uuid::MockUuid;
     use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
@@ -459,13 +479,13 @@ async fn it_should_return_an_error_if_invalid_document_is_given() {
     let repository_mock = MockRepository::new();
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
 
-    let mut result = sut.get("4073563").await;
+    let mut result = sut.get(&Tenant::Platform, "4073563").await;
     assert!(match result {
         Ok(_) => false,
         Err(e) => e.get_code() == user::INVALID_DOCUMENT_ERROR
     });
 
-    result = sut.get("40735626061").await;
+    result = sut.get(&Tenant::Platform, "40735626061").await;
     assert!(match result {
         Ok(_) => false,
         Err(e) => e.get_code() == user::INVALID_DOCUMENT_ERROR
@@ -474,6 +494,7 @@ async fn it_should_return_an_error_if_invalid_document_is_given() {
 
 #[tokio::test]
 async fn it_should_return_an_error_when_repository_get_fails() {
+    use crate::domain::entities::Tenant;
     use crate::domain::usecases::user::UserUseCase;
     use crate::data::usecases::user::UseCase;
     use crate::data::protocols::uuid::MockUuid;
@@ -487,10 +508,10 @@ async fn it_should_return_an_error_when_repository_get_fails() {
     let hash_mock = MockHash::new();
     let uuid_mock = MockUuid::new();
     let mut repository_mock = MockRepository::new();
-    repository_mock.expect_get_by_cpf().with(eq(cpf)).return_const(Err(expected_err));
+    repository_mock.expect_get_by_cpf().with(eq(Tenant::Platform), eq(cpf)).return_const(Err(expected_err));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.get(cpf).await;
+    let result = sut.get(&Tenant::Platform, cpf).await;
     
     assert!(match result {
         Ok(_) => false,
@@ -504,7 +525,7 @@ async fn it_should_return_an_user_on_success() {
     use crate::data::usecases::user::UseCase;
     use crate::data::protocols::uuid::MockUuid;
     use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
-    use crate::domain::entities::User;
+    use crate::domain::entities::{User, Tenant};
     use crate::domain::error::Kind::Internal;
     use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
     use chrono::NaiveDate;
@@ -519,7 +540,7 @@ async fn it_should_return_an_user_on_success() {
     repository_mock.expect_get_by_cpf().return_const(Ok(user));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
-    let result = sut.get(&cpf.to_string()).await;
+    let result = sut.get(&Tenant::Platform, &cpf.to_string()).await;
     
     assert!(match result {
         Ok(u) => u.name == *"name",
@@ -529,6 +550,7 @@ async fn it_should_return_an_user_on_success() {
 
 #[tokio::test]
 async fn it_should_delete_return_an_error_if_invalid_document_is_given() {
+    use crate::domain::entities::Tenant;
     use crate::data::usecases::user::UseCase; 
     use crate::domain::usecases::user::INVALID_DOCUMENT_ERROR;
     use crate::domain::usecases::user::UserUseCase;
@@ -541,13 +563,13 @@ async fn it_should_delete_return_an_error_if_invalid_document_is_given() {
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
 
-    let mut result = sut.delete("4064").await;
+    let mut result = sut.delete(&Tenant::Platform, "4064").await;
     assert!(match result {
         Ok(_) => false,
         Err(e) => e.get_code() == INVALID_DOCUMENT_ERROR
     });
 
-    result = sut.delete("40735626064").await;
+    result = sut.delete(&Tenant::Platform, "40735626064").await;
     assert!(match result {
         Ok(_) => false,
         Err(e) => e.get_code() == INVALID_DOCUMENT_ERROR
@@ -557,6 +579,7 @@ async fn it_should_delete_return_an_error_if_invalid_document_is_given() {
 
 #[tokio::test]
 async fn it_should_delete_return_an_error_when_repository_fails() {
+    use crate::domain::entities::Tenant;
     use crate::data::usecases::user::UseCase; 
     use crate::domain::usecases::user::UserUseCase;
     use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
@@ -568,11 +591,11 @@ async fn it_should_delete_return_an_error_when_repository_fails() {
     let hash_mock = MockHash::new();
     let uuid_mock = MockUuid::new();
     let mut repository_mock = MockRepository::new();
-    repository_mock.expect_delete_by_cpf().with(eq(cpf)).return_const(Err(Error::new_internal("err")));
+    repository_mock.expect_delete_by_cpf().with(eq(Tenant::Platform), eq(cpf)).return_const(Err(Error::new_internal("err")));
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
 
-    let result = sut.delete(cpf).await;
+    let result = sut.delete(&Tenant::Platform, cpf).await;
     assert!(match result {
         Ok(_) => false,
         Err(e) => e.get_message().contains("err")
@@ -581,6 +604,7 @@ async fn it_should_delete_return_an_error_when_repository_fails() {
 
 #[tokio::test]
 async fn it_should_delete_return_no_errors_on_success() {
+    use crate::domain::entities::Tenant;
     use crate::data::usecases::user::UseCase; 
     use crate::domain::usecases::user::UserUseCase;
     use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
@@ -594,9 +618,434 @@ async fn it_should_delete_return_no_errors_on_success() {
 
     let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
 
-    let result = sut.delete(cpf).await;
+    let result = sut.delete(&Tenant::Platform, cpf).await;
     assert!(match result {
         Ok(()) => true,
         Err(_) => false
     });
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn it_should_restore_return_an_error_if_invalid_document_is_given() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::INVALID_DOCUMENT_ERROR;
+    use crate::domain::usecases::user::UserUseCase;
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_restore_by_cpf().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+
+    let result = sut.restore(&Tenant::Platform, "admin", "40735626064").await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_DOCUMENT_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_restore_the_user_within_the_tenant() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::UserUseCase;
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+    use mockall::predicate::eq;
+
+    let cpf = "95935806037";
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_restore_by_cpf()
+        .with(eq(Tenant::Platform), eq(cpf), eq("change_id"), eq("admin"))
+        .times(1)
+        .return_const(Ok(()));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("change_id");
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(MockHash::new()));
+
+    let result = sut.restore(&Tenant::Platform, "admin", cpf).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn it_should_bind_customers_created_by_store_staff_to_their_store() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::MockRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::Tenant;
+    use crate::domain::usecases::user::{UserUseCase, UserCreateRequestDTO};
+
+    let tenant = Tenant::Store(String::from("store_id"));
+    let mut hash_mock = MockHash::new();
+    hash_mock.expect_run().return_const(Ok(String::from("hash_password")));
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const("uuid");
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_create()
+        .withf(|tenant, user| *tenant == Tenant::Store(String::from("store_id")) && user.get_store_id() == Some("store_id"))
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(hash_mock));
+    let dto = UserCreateRequestDTO {
+        name: String::from("name"),
+        document: String::from("40735626065"),
+        birth_date: NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap(),
+        password: String::from("password"),
+        store_id: Some(String::from("another_store_id"))
+    };
+
+    assert!(sut.create(&tenant, dto).await.is_ok());
+}
+
+#[tokio::test]
+async fn it_should_return_a_cursor_resuming_after_the_last_user_of_the_page() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::{MockRepository, UserSort}, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::user::{UserUseCase, UserListQueryDTO};
+
+    let birth_date = BirthDate::from_naive(NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap());
+    let users: Vec<User> = ["first", "second", "third"].iter().map(|name| {
+        let mut user = User::new(String::from(*name), CPF::from_string(String::from("40735626065")).unwrap(), birth_date);
+        user.set_uuid(format!("{}_id", name));
+        user
+    }).collect();
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_list()
+        .withf(|_, filter| filter.limit == 3 && filter.sort == UserSort::NameAsc && filter.after.is_none())
+        .times(1)
+        .return_const(Ok(users));
+    repository_mock.expect_list()
+        .withf(|_, filter| filter.after.as_ref().is_some_and(|a| a.id == "second_id" && a.name == "second"))
+        .times(1)
+        .return_const(Ok(vec![]));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let query = UserListQueryDTO { sort: Some(String::from("name")), limit: Some(2), ..UserListQueryDTO::default() };
+
+    let page = sut.list(&Tenant::Platform, query, false).await.unwrap();
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items[1].name, "second");
+
+    let query = UserListQueryDTO { sort: Some(String::from("name")), cursor: page.next_cursor, ..UserListQueryDTO::default() };
+    let page = sut.list(&Tenant::Platform, query, false).await.unwrap();
+    assert!(page.items.is_empty());
+    assert!(page.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn it_should_mask_documents_of_listed_users_when_asked() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::MockRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::user::{UserUseCase, UserListQueryDTO};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_list().times(2).return_const(Ok(vec![user]));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+
+    let masked = sut.list(&Tenant::Platform, UserListQueryDTO::default(), true).await.unwrap();
+    assert_eq!(masked.items[0].document, "***.356.260-**");
+
+    let unmasked = sut.list(&Tenant::Platform, UserListQueryDTO::default(), false).await.unwrap();
+    assert_eq!(unmasked.items[0].document, "40735626065");
+}
+
+#[tokio::test]
+async fn it_should_reject_invalid_cursors() {
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::MockRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::Tenant;
+    use crate::domain::usecases::user::{UserUseCase, UserListQueryDTO, INVALID_CURSOR_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_list().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let query = UserListQueryDTO { cursor: Some(String::from("not-a-cursor")), ..UserListQueryDTO::default() };
+
+    let result = sut.list(&Tenant::Platform, query, false).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_CURSOR_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_reject_ranges_ending_before_they_start() {
+    use chrono::NaiveDate;
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::MockRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::Tenant;
+    use crate::domain::usecases::user::{UserUseCase, UserListQueryDTO, INVALID_USER_FILTER_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_list().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let query = UserListQueryDTO {
+        birth_date_from: NaiveDate::from_ymd_opt(2000, 1, 1),
+        birth_date_to: NaiveDate::from_ymd_opt(1990, 1, 1),
+        ..UserListQueryDTO::default()
+    };
+
+    let result = sut.list(&Tenant::Platform, query, false).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_USER_FILTER_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_reject_search_queries_too_short_to_match() {
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::MockRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::Tenant;
+    use crate::domain::usecases::user::{UserUseCase, UserSearchQueryDTO, INVALID_SEARCH_QUERY_ERROR};
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_search().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let query = UserSearchQueryDTO { q: String::from(" jo "), limit: None };
+
+    let result = sut.search(&Tenant::Platform, query, true).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_SEARCH_QUERY_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_mask_documents_of_search_results_when_asked() {
+    use chrono::NaiveDate;
+    use mockall::predicate::eq;
+    use crate::data::usecases::user::UseCase;
+    use crate::data::usecases::user::protocols::{repository::MockRepository, hash::MockHash};
+    use crate::data::protocols::uuid::MockUuid;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::domain::usecases::user::{UserUseCase, UserSearchQueryDTO, DEFAULT_USER_PAGE_SIZE};
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("Maria da Conceição"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_search()
+        .with(eq(Tenant::Platform), eq("dona maria"), eq(DEFAULT_USER_PAGE_SIZE))
+        .times(2)
+        .return_const(Ok(vec![user]));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let query = UserSearchQueryDTO { q: String::from("dona maria "), limit: None };
+
+    let masked = sut.search(&Tenant::Platform, query.clone(), true).await.unwrap();
+    assert_eq!(masked[0].document, "***.356.260-**");
+
+    let unmasked = sut.search(&Tenant::Platform, query, false).await.unwrap();
+    assert_eq!(unmasked[0].document, "40735626065");
+}
+
+#[tokio::test]
+async fn it_should_block_return_an_error_for_an_unknown_reason() {
+    use crate::domain::entities::Tenant;
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::{UserUseCase, UserStatusChangeRequestDTO, INVALID_STATUS_CHANGE_REASON_ERROR};
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_change_status().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+
+    // restores are recorded by the system, callers cannot claim them
+    for reason in ["bad_mood", "restored"] {
+        let dto = UserStatusChangeRequestDTO { reason: String::from(reason), note: None };
+        let result = sut.block(&Tenant::Platform, "manager", "95935806037", dto).await;
+        assert!(match result {
+            Ok(_) => false,
+            Err(e) => e.get_code() == INVALID_STATUS_CHANGE_REASON_ERROR
+        });
+    }
+}
+
+#[tokio::test]
+async fn it_should_block_recording_who_blocked_the_user_and_why() {
+    use chrono::NaiveDate;
+    use crate::domain::entities::{User, UserStatus, StatusChangeReason, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::{UserUseCase, UserStatusChangeRequestDTO};
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let cpf = CPF::from_string(String::from("95935806037")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+    user.set_uuid(String::from("user_id"));
+
+    let mut uuid_mock = MockUuid::new();
+    uuid_mock.expect_generate().return_const(String::from("change_id"));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    repository_mock.expect_change_status()
+        .withf(|_, c| c.get_id() == "change_id" &&
+            c.get_user_id() == "user_id" &&
+            c.get_from_status() == UserStatus::Active &&
+            c.get_to_status() == UserStatus::Blocked &&
+            c.get_reason() == StatusChangeReason::OverdueDebt &&
+            c.get_note() == Some("three months late") &&
+            c.get_changed_by() == "manager")
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(uuid_mock), Box::new(MockHash::new()));
+    let dto = UserStatusChangeRequestDTO { reason: String::from("overdue_debt"), note: Some(String::from(" three months late ")) };
+
+    let result = sut.block(&Tenant::Platform, "manager", &cpf.to_string(), dto).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn it_should_unblock_return_an_error_when_the_user_is_not_blocked() {
+    use chrono::NaiveDate;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::{UserUseCase, UserStatusChangeRequestDTO, USER_NOT_BLOCKED_ERROR};
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let cpf = CPF::from_string(String::from("95935806037")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("name"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    repository_mock.expect_change_status().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let dto = UserStatusChangeRequestDTO { reason: String::from("DEBT_SETTLED"), note: None };
+
+    let result = sut.unblock(&Tenant::Platform, "manager", &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == USER_NOT_BLOCKED_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_patch_only_the_fields_sent_keeping_password_and_status() {
+    use chrono::NaiveDate;
+    use crate::domain::entities::{User, UserStatus, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::{UserUseCase, UserPatchRequestDTO};
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let cpf = CPF::from_string(String::from("95935806037")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let mut user = User::new(String::from("Maria"), cpf, BirthDate::from_naive(birth_date));
+    user.set_uuid(String::from("user_id"));
+    user.set_password(String::from("hashed"));
+    user.set_status(UserStatus::Blocked);
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    repository_mock.expect_update()
+        .withf(move |_, u| u.get_id() == "user_id" &&
+            u.get_name() == "Maria da Silva" &&
+            *u.get_document() == cpf &&
+            u.get_birth_date() == BirthDate::from_naive(birth_date) &&
+            u.get_password() == "hashed" &&
+            u.get_status() == UserStatus::Blocked)
+        .times(1)
+        .return_const(Ok(()));
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let dto = UserPatchRequestDTO { name: Some(String::from(" Maria da Silva ")), ..Default::default() };
+
+    let result = sut.patch(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(u) => u.name == "Maria da Silva" && u.status == UserStatus::Blocked,
+        Err(_) => false
+    });
+}
+
+#[tokio::test]
+async fn it_should_patch_return_an_error_if_invalid_document_is_given() {
+    use chrono::NaiveDate;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::{UserUseCase, UserPatchRequestDTO, INVALID_DOCUMENT_ERROR};
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let cpf = CPF::from_string(String::from("95935806037")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("Maria"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    repository_mock.expect_update().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let dto = UserPatchRequestDTO { document: Some(String::from("40735626064")), ..Default::default() };
+
+    let result = sut.patch(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(match result {
+        Ok(_) => false,
+        Err(e) => e.get_code() == INVALID_DOCUMENT_ERROR
+    });
+}
+
+#[tokio::test]
+async fn it_should_not_write_a_patch_that_changes_nothing() {
+    use chrono::NaiveDate;
+    use crate::domain::entities::{User, Tenant};
+    use crate::domain::types::{cpf::CPF, birth_date::BirthDate};
+    use crate::data::usecases::user::UseCase;
+    use crate::domain::usecases::user::{UserUseCase, UserPatchRequestDTO};
+    use crate::data::usecases::user::protocols::{hash::MockHash, repository::MockRepository};
+    use crate::data::protocols::uuid::MockUuid;
+
+    let cpf = CPF::from_string(String::from("95935806037")).unwrap();
+    let birth_date = NaiveDate::parse_from_str("1999-09-05", "%Y-%m-%d").unwrap();
+    let user = User::new(String::from("Maria"), cpf, BirthDate::from_naive(birth_date));
+
+    let mut repository_mock = MockRepository::new();
+    repository_mock.expect_get_by_cpf().return_const(Ok(user));
+    repository_mock.expect_update().never();
+
+    let sut = UseCase::new(Box::new(repository_mock), Box::new(MockUuid::new()), Box::new(MockHash::new()));
+    let dto = UserPatchRequestDTO { name: Some(String::from("Maria")), document: Some(cpf.to_string()), birth_date: Some(birth_date) };
+
+    let result = sut.patch(&Tenant::Platform, &cpf.to_string(), dto).await;
+    assert!(result.is_ok());
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::types::{money::Money, billing_cycle::BillingCycle};
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum AccountStatus {
+    Open,
+    Closed,
+    Unknown,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CreditAccount {
+    id: String,
+    user_id: String,
+    store_id: Option<String>,
+    balance: Money,
+    credit_limit: Money,
+    status: AccountStatus,
+    billing_cycle: BillingCycle,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>
+}
+
+impl CreditAccount {
+    pub fn new(user_id: String, credit_limit: Money) -> CreditAccount {
+        CreditAccount {
+            id: String::new(),
+            user_id,
+            store_id: None,
+            balance: Money::zero(),
+            credit_limit,
+            status: AccountStatus::Open,
+            billing_cycle: BillingCycle::default(),
+            created_at: Utc::now(),
+            updated_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        self.user_id.as_str()
+    }
+
+    /// Store extending the credit, accounts opened before stores existed have none.
+    pub fn get_store_id(&self) -> Option<&str> {
+        self.store_id.as_deref()
+    }
+
+    pub fn set_store_id(&mut self, store_id: Option<String>) {
+        self.store_id = store_id;
+    }
+
+    pub fn get_balance(&self) -> Money {
+        self.balance
+    }
+
+    pub fn set_balance(&mut self, balance: Money) {
+        self.balance = balance;
+    }
+
+    /// Overpayments leave a negative balance, which is credit the customer
+    /// carries forward into the next purchases.
+    pub fn get_carried_credit(&self) -> Money {
+        if self.balance.is_negative() {
+            return self.balance.abs();
+        }
+        Money::zero()
+    }
+
+    pub fn get_credit_limit(&self) -> Money {
+        self.credit_limit
+    }
+
+    pub fn set_credit_limit(&mut self, credit_limit: Money) {
+        self.credit_limit = credit_limit;
+    }
+
+    pub fn get_available_credit(&self) -> Money {
+        self.credit_limit.saturating_sub(self.balance)
+    }
+
+    pub fn get_status(&self) -> AccountStatus {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: AccountStatus) {
+        self.status = status;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == AccountStatus::Open
+    }
+
+    pub fn get_billing_cycle(&self) -> BillingCycle {
+        self.billing_cycle
+    }
+
+    pub fn set_billing_cycle(&mut self, billing_cycle: BillingCycle) {
+        self.billing_cycle = billing_cycle;
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    pub fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    pub fn set_updated_at(&mut self, updated_at: DateTime<Utc>) {
+        self.updated_at = updated_at;
+    }
+}
+
+impl PartialEq for CreditAccount {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.user_id == other.user_id &&
+        self.balance == other.balance &&
+        self.credit_limit == other.credit_limit &&
+        self.status == other.status &&
+        self.billing_cycle == other.billing_cycle &&
+        self.store_id == other.store_id
+    }
+}
+
+impl AccountStatus {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Open => "OPEN",
+            Self::Closed => "CLOSED",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> AccountStatus {
+        match s {
+            "OPEN" => AccountStatus::Open,
+            "CLOSED" => AccountStatus::Closed,
+            _ => AccountStatus::Unknown,
+        }
+    }
+}
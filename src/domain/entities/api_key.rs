@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use super::Permission;
+
+/// Machine credential of a store integration, such as a point-of-sale terminal.
+/// The prefix identifies the key and only the hash of its secret is kept.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    id: String,
+    prefix: String,
+    secret_hash: String,
+    name: String,
+    store_id: String,
+    permissions: Vec<Permission>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>
+}
+
+impl ApiKey {
+    pub fn new(prefix: String, secret_hash: String, name: String, store_id: String, permissions: Vec<Permission>) -> ApiKey {
+        ApiKey {
+            id: String::new(),
+            prefix,
+            secret_hash,
+            name,
+            store_id,
+            permissions,
+            revoked_at: None,
+            created_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_prefix(&self) -> &str {
+        self.prefix.as_str()
+    }
+
+    pub fn get_secret_hash(&self) -> &str {
+        self.secret_hash.as_str()
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn get_store_id(&self) -> &str {
+        self.store_id.as_str()
+    }
+
+    pub fn get_permissions(&self) -> &[Permission] {
+        &self.permissions
+    }
+
+    pub fn get_revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    pub fn set_revoked_at(&mut self, revoked_at: Option<DateTime<Utc>>) {
+        self.revoked_at = revoked_at;
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+impl PartialEq for ApiKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.prefix == other.prefix &&
+        self.secret_hash == other.secret_hash &&
+        self.store_id == other.store_id &&
+        self.permissions == other.permissions &&
+        self.revoked_at == other.revoked_at
+    }
+}
@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::types::money::Money;
+
+/// Maximum late fee allowed by the consumer protection code (CDC, art. 52, §1º): 2%.
+pub const LEGAL_MAX_LATE_FEE_BPS: u32 = 200;
+/// Maximum default interest allowed by the civil code (CC, art. 406): 1% a month.
+pub const LEGAL_MAX_MONTHLY_INTEREST_BPS: u32 = 100;
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum FeeKind {
+    Interest,
+    LateFee,
+    Unknown,
+}
+
+/// Rates charged on overdue purchases, in basis points.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub struct FeePolicy {
+    late_fee_bps: u32,
+    monthly_interest_bps: u32,
+}
+
+impl FeePolicy {
+    pub fn new(late_fee_bps: u32, monthly_interest_bps: u32) -> Result<FeePolicy, String> {
+        if late_fee_bps > LEGAL_MAX_LATE_FEE_BPS {
+            return Err(format!("late fee of {} bps is above the legal limit of {} bps", late_fee_bps, LEGAL_MAX_LATE_FEE_BPS));
+        }
+
+        if monthly_interest_bps > LEGAL_MAX_MONTHLY_INTEREST_BPS {
+            return Err(format!("monthly interest of {} bps is above the legal limit of {} bps", monthly_interest_bps, LEGAL_MAX_MONTHLY_INTEREST_BPS));
+        }
+
+        Ok(FeePolicy { late_fee_bps, monthly_interest_bps })
+    }
+
+    pub fn get_late_fee_bps(&self) -> u32 {
+        self.late_fee_bps
+    }
+
+    pub fn get_monthly_interest_bps(&self) -> u32 {
+        self.monthly_interest_bps
+    }
+}
+
+/// A charge posted on an account because of an overdue balance. The idempotency key
+/// identifies the charge so the same fee is never posted twice for a period.
+#[derive(Serialize, Debug, Clone)]
+pub struct Fee {
+    id: String,
+    account_id: String,
+    installment_id: Option<String>,
+    kind: FeeKind,
+    period: String,
+    amount: Money,
+    idempotency_key: String,
+    created_at: DateTime<Utc>
+}
+
+impl Fee {
+    /// One-time fee charged on an installment the first time it is found overdue.
+    pub fn late_fee(account_id: String, installment_id: String, period: String, amount: Money) -> Fee {
+        let idempotency_key = format!("{}:{}", FeeKind::LateFee.to_string(), installment_id);
+        Fee::new(account_id, Some(installment_id), FeeKind::LateFee, period, amount, idempotency_key)
+    }
+
+    /// Interest charged once a month over the overdue balance of an account.
+    pub fn interest(account_id: String, period: String, amount: Money) -> Fee {
+        let idempotency_key = format!("{}:{}:{}", FeeKind::Interest.to_string(), account_id, period);
+        Fee::new(account_id, None, FeeKind::Interest, period, amount, idempotency_key)
+    }
+
+    fn new(account_id: String, installment_id: Option<String>, kind: FeeKind, period: String, amount: Money, idempotency_key: String) -> Fee {
+        Fee {
+            id: String::new(),
+            account_id,
+            installment_id,
+            kind,
+            period,
+            amount,
+            idempotency_key,
+            created_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_account_id(&self) -> &str {
+        self.account_id.as_str()
+    }
+
+    pub fn get_installment_id(&self) -> Option<&str> {
+        self.installment_id.as_deref()
+    }
+
+    pub fn get_kind(&self) -> FeeKind {
+        self.kind
+    }
+
+    pub fn get_period(&self) -> &str {
+        self.period.as_str()
+    }
+
+    pub fn get_amount(&self) -> Money {
+        self.amount
+    }
+
+    pub fn get_idempotency_key(&self) -> &str {
+        self.idempotency_key.as_str()
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+impl PartialEq for Fee {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.installment_id == other.installment_id &&
+        self.kind == other.kind &&
+        self.period == other.period &&
+        self.amount == other.amount &&
+        self.idempotency_key == other.idempotency_key
+    }
+}
+
+impl FeeKind {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Interest => "INTEREST",
+            Self::LateFee => "LATE_FEE",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> FeeKind {
+        match s {
+            "INTEREST" => FeeKind::Interest,
+            "LATE_FEE" => FeeKind::LateFee,
+            _ => FeeKind::Unknown,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use crate::domain::types::money::Money;
+
+/// Most installments a purchase can be split into.
+pub const MAX_INSTALLMENTS: u32 = 24;
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum InstallmentStatus {
+    Pending,
+    Paid,
+    Overdue,
+}
+
+/// A part of a purchase due on its own date. Installments are what customers owe:
+/// payments settle them and late fees are charged on them.
+#[derive(Serialize, Debug, Clone)]
+pub struct Installment {
+    id: String,
+    purchase_id: String,
+    account_id: String,
+    number: u32,
+    amount: Money,
+    paid_amount: Money,
+    due_date: NaiveDate,
+    created_at: DateTime<Utc>
+}
+
+impl Installment {
+    pub fn new(purchase_id: String, account_id: String, number: u32, amount: Money, due_date: NaiveDate) -> Installment {
+        Installment {
+            id: String::new(),
+            purchase_id,
+            account_id,
+            number,
+            amount,
+            paid_amount: Money::zero(),
+            due_date,
+            created_at: Utc::now()
+        }
+    }
+
+    /// Splits `amount` in one installment per due date. Cents that do not divide
+    /// evenly go to the first installments. Returns `None` if there are no due dates
+    /// or the amount is too small to give every installment at least one cent.
+    pub fn split(purchase_id: &str, account_id: &str, amount: Money, due_dates: &[NaiveDate]) -> Option<Vec<Installment>> {
+        let count = due_dates.len() as i64;
+        if count == 0 || amount.to_cents() < count {
+            return None;
+        }
+
+        let base = amount.to_cents() / count;
+        let remainder = amount.to_cents() % count;
+        let installments = due_dates.iter().enumerate().map(|(i, due_date)| {
+            let cents = if (i as i64) < remainder { base + 1 } else { base };
+            Installment::new(String::from(purchase_id), String::from(account_id), i as u32 + 1, Money::from_cents(cents), *due_date)
+        }).collect();
+        Some(installments)
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_purchase_id(&self) -> &str {
+        self.purchase_id.as_str()
+    }
+
+    pub fn set_purchase_id(&mut self, purchase_id: String) {
+        self.purchase_id = purchase_id;
+    }
+
+    pub fn get_account_id(&self) -> &str {
+        self.account_id.as_str()
+    }
+
+    pub fn get_number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn get_amount(&self) -> Money {
+        self.amount
+    }
+
+    pub fn get_paid_amount(&self) -> Money {
+        self.paid_amount
+    }
+
+    pub fn set_paid_amount(&mut self, paid_amount: Money) {
+        self.paid_amount = paid_amount;
+    }
+
+    pub fn get_outstanding_amount(&self) -> Money {
+        self.amount.saturating_sub(self.paid_amount)
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.paid_amount >= self.amount
+    }
+
+    pub fn get_due_date(&self) -> NaiveDate {
+        self.due_date
+    }
+
+    pub fn is_overdue(&self, reference_date: NaiveDate) -> bool {
+        !self.is_settled() && self.due_date < reference_date
+    }
+
+    pub fn get_status(&self, reference_date: NaiveDate) -> InstallmentStatus {
+        if self.is_settled() {
+            return InstallmentStatus::Paid;
+        }
+
+        if self.is_overdue(reference_date) {
+            return InstallmentStatus::Overdue;
+        }
+        InstallmentStatus::Pending
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+}
+
+impl PartialEq for Installment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.purchase_id == other.purchase_id &&
+        self.account_id == other.account_id &&
+        self.number == other.number &&
+        self.amount == other.amount &&
+        self.paid_amount == other.paid_amount &&
+        self.due_date == other.due_date
+    }
+}
+
+impl InstallmentStatus {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Pending => "PENDING",
+            Self::Paid => "PAID",
+            Self::Overdue => "OVERDUE"
+        }
+    }
+}
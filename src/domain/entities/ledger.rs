@@ -0,0 +1,250 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use super::{Purchase, Payment, Fee};
+use crate::domain::types::money::Money;
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum EntryDirection {
+    Debit,
+    Credit,
+}
+
+/// Chart of accounts used by the ledger. `Receivable` holds what customers owe,
+/// the other accounts are the counterparts of each kind of posting.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum LedgerAccount {
+    Receivable,
+    Sales,
+    Cash,
+    FeeIncome,
+    Adjustments,
+    Unknown,
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum TransactionKind {
+    Purchase,
+    Payment,
+    Fee,
+    Adjustment,
+    Unknown,
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct LedgerEntry {
+    pub ledger_account: LedgerAccount,
+    pub direction: EntryDirection,
+    pub amount: Money,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LedgerTransaction {
+    id: String,
+    account_id: String,
+    kind: TransactionKind,
+    reference_id: Option<String>,
+    description: String,
+    entries: Vec<LedgerEntry>,
+    created_at: DateTime<Utc>
+}
+
+impl LedgerTransaction {
+    pub fn new(id: String, account_id: String, kind: TransactionKind, reference_id: Option<String>, description: String, entries: Vec<LedgerEntry>) -> LedgerTransaction {
+        LedgerTransaction {
+            id,
+            account_id,
+            kind,
+            reference_id,
+            description,
+            entries,
+            created_at: Utc::now()
+        }
+    }
+
+    pub fn for_purchase(id: String, purchase: &Purchase) -> LedgerTransaction {
+        let mut transaction = LedgerTransaction::new(
+            id,
+            String::from(purchase.get_account_id()),
+            TransactionKind::Purchase,
+            Some(String::from(purchase.get_id())),
+            String::from(purchase.get_description()),
+            pair(LedgerAccount::Receivable, LedgerAccount::Sales, purchase.get_amount())
+        );
+        transaction.set_created_at(purchase.get_purchased_at());
+        transaction
+    }
+
+    pub fn for_payment(id: String, payment: &Payment) -> LedgerTransaction {
+        let mut transaction = LedgerTransaction::new(
+            id,
+            String::from(payment.get_account_id()),
+            TransactionKind::Payment,
+            Some(String::from(payment.get_id())),
+            String::from(payment.get_method().to_string()),
+            pair(LedgerAccount::Cash, LedgerAccount::Receivable, payment.get_amount())
+        );
+        transaction.set_created_at(payment.get_paid_at());
+        transaction
+    }
+
+    pub fn for_fee(id: String, fee: &Fee) -> LedgerTransaction {
+        LedgerTransaction::new(
+            id,
+            String::from(fee.get_account_id()),
+            TransactionKind::Fee,
+            Some(String::from(fee.get_id())),
+            String::from(fee.get_idempotency_key()),
+            pair(LedgerAccount::Receivable, LedgerAccount::FeeIncome, fee.get_amount())
+        )
+    }
+
+    /// Positive amounts increase what the customer owes, negative ones forgive debt.
+    pub fn for_adjustment(id: String, account_id: String, amount: Money, description: String) -> LedgerTransaction {
+        let entries = if amount.is_negative() {
+            pair(LedgerAccount::Adjustments, LedgerAccount::Receivable, amount.abs())
+        } else {
+            pair(LedgerAccount::Receivable, LedgerAccount::Adjustments, amount)
+        };
+        LedgerTransaction::new(id, account_id, TransactionKind::Adjustment, None, description, entries)
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_account_id(&self) -> &str {
+        self.account_id.as_str()
+    }
+
+    pub fn get_kind(&self) -> TransactionKind {
+        self.kind
+    }
+
+    pub fn get_reference_id(&self) -> Option<&str> {
+        self.reference_id.as_deref()
+    }
+
+    pub fn get_description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    pub fn get_entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        let mut total = Some(Money::zero());
+        for entry in &self.entries {
+            total = match entry.direction {
+                EntryDirection::Debit => total.and_then(|t| t.checked_add(entry.amount)),
+                EntryDirection::Credit => total.and_then(|t| t.checked_sub(entry.amount)),
+            };
+        }
+        !self.entries.is_empty() && total == Some(Money::zero())
+    }
+
+    /// How much the transaction changes what the customer owes.
+    pub fn get_receivable_change(&self) -> Option<Money> {
+        receivable_balance(&self.entries)
+    }
+}
+
+impl PartialEq for LedgerTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.kind == other.kind &&
+        self.reference_id == other.reference_id &&
+        self.entries == other.entries
+    }
+}
+
+/// Balance owed by the customer derived from receivable entries: debits minus credits.
+/// Returns `None` if the sum does not fit in `Money`.
+pub fn receivable_balance(entries: &[LedgerEntry]) -> Option<Money> {
+    let mut balance = Some(Money::zero());
+    for entry in entries.iter().filter(|e| e.ledger_account == LedgerAccount::Receivable) {
+        balance = match entry.direction {
+            EntryDirection::Debit => balance.and_then(|b| b.checked_add(entry.amount)),
+            EntryDirection::Credit => balance.and_then(|b| b.checked_sub(entry.amount)),
+        };
+    }
+    balance
+}
+
+fn pair(debit: LedgerAccount, credit: LedgerAccount, amount: Money) -> Vec<LedgerEntry> {
+    vec![
+        LedgerEntry { ledger_account: debit, direction: EntryDirection::Debit, amount },
+        LedgerEntry { ledger_account: credit, direction: EntryDirection::Credit, amount },
+    ]
+}
+
+impl EntryDirection {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Debit => "DEBIT",
+            Self::Credit => "CREDIT"
+        }
+    }
+
+    pub fn from_string(s: &str) -> EntryDirection {
+        match s {
+            "DEBIT" => EntryDirection::Debit,
+            _ => EntryDirection::Credit,
+        }
+    }
+}
+
+impl LedgerAccount {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Receivable => "RECEIVABLE",
+            Self::Sales => "SALES",
+            Self::Cash => "CASH",
+            Self::FeeIncome => "FEE_INCOME",
+            Self::Adjustments => "ADJUSTMENTS",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> LedgerAccount {
+        match s {
+            "RECEIVABLE" => LedgerAccount::Receivable,
+            "SALES" => LedgerAccount::Sales,
+            "CASH" => LedgerAccount::Cash,
+            "FEE_INCOME" => LedgerAccount::FeeIncome,
+            "ADJUSTMENTS" => LedgerAccount::Adjustments,
+            _ => LedgerAccount::Unknown,
+        }
+    }
+}
+
+impl TransactionKind {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Purchase => "PURCHASE",
+            Self::Payment => "PAYMENT",
+            Self::Fee => "FEE",
+            Self::Adjustment => "ADJUSTMENT",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> TransactionKind {
+        match s {
+            "PURCHASE" => TransactionKind::Purchase,
+            "PAYMENT" => TransactionKind::Payment,
+            "FEE" => TransactionKind::Fee,
+            "ADJUSTMENT" => TransactionKind::Adjustment,
+            _ => TransactionKind::Unknown,
+        }
+    }
+}
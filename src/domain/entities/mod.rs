@@ -2,6 +2,35 @@ use chrono::{DateTime, Utc};
 use serde::Serialize;
 use super::types::{cpf::CPF, birth_date::BirthDate};
 
+mod account;
+mod purchase;
+mod payment;
+mod ledger;
+mod fee;
+mod statement;
+mod installment;
+mod password_reset;
+mod role;
+mod refresh_token;
+mod api_key;
+mod store;
+mod tenant;
+mod user_status_change;
+pub use account::{CreditAccount, AccountStatus};
+pub use purchase::Purchase;
+pub use payment::{Payment, PaymentMethod, PaymentAllocation};
+pub use ledger::{LedgerTransaction, LedgerEntry, LedgerAccount, EntryDirection, TransactionKind, receivable_balance};
+pub use fee::{Fee, FeeKind, FeePolicy, LEGAL_MAX_LATE_FEE_BPS, LEGAL_MAX_MONTHLY_INTEREST_BPS};
+pub use statement::{Statement, StatementLine};
+pub use installment::{Installment, InstallmentStatus, MAX_INSTALLMENTS};
+pub use password_reset::PasswordReset;
+pub use role::{Role, Permission};
+pub use refresh_token::RefreshToken;
+pub use api_key::ApiKey;
+pub use store::Store;
+pub use tenant::Tenant;
+pub use user_status_change::{UserStatusChange, StatusChangeReason};
+
 #[derive(Serialize, Debug, PartialEq, Clone, Copy)]
 pub enum UserStatus {
     Active,
@@ -18,8 +47,10 @@ pub struct User {
     status: UserStatus,
     password: String,
     birth_date: BirthDate,
+    store_id: Option<String>,
     created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>
 }
 
 impl User {
@@ -31,8 +62,10 @@ impl User {
             birth_date,
             status: UserStatus::Active,
             password: String::new(),
+            store_id: None,
             created_at: Utc::now(),
-            updated_at: Utc::now()
+            updated_at: Utc::now(),
+            deleted_at: None
         }
     }
 
@@ -84,6 +117,14 @@ impl User {
         self.password.as_str()
     }
 
+    pub fn get_store_id(&self) -> Option<&str> {
+        self.store_id.as_deref()
+    }
+
+    pub fn set_store_id(&mut self, store_id: Option<String>) {
+        self.store_id = store_id;
+    }
+
     pub fn get_created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -99,6 +140,14 @@ impl User {
     pub fn set_updated_at(&mut self, updated_at: DateTime<Utc>) {
         self.updated_at = updated_at;
     }
+
+    pub fn get_deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    pub fn set_deleted_at(&mut self, deleted_at: Option<DateTime<Utc>>) {
+        self.deleted_at = deleted_at;
+    }
 }
 
 impl PartialEq for User {
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+
+/// One-time token issued by an admin so a customer can set a new password.
+/// Only the hash of the secret is kept, the plain token is shown once.
+#[derive(Debug, Clone)]
+pub struct PasswordReset {
+    id: String,
+    user_id: String,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>
+}
+
+impl PasswordReset {
+    pub fn new(user_id: String, token_hash: String, expires_at: DateTime<Utc>) -> PasswordReset {
+        PasswordReset {
+            id: String::new(),
+            user_id,
+            token_hash,
+            expires_at,
+            used_at: None,
+            created_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        self.user_id.as_str()
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        self.token_hash.as_str()
+    }
+
+    pub fn get_expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn get_used_at(&self) -> Option<DateTime<Utc>> {
+        self.used_at
+    }
+
+    pub fn set_used_at(&mut self, used_at: Option<DateTime<Utc>>) {
+        self.used_at = used_at;
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && now < self.expires_at
+    }
+}
+
+impl PartialEq for PasswordReset {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.user_id == other.user_id &&
+        self.token_hash == other.token_hash &&
+        self.expires_at == other.expires_at &&
+        self.used_at == other.used_at
+    }
+}
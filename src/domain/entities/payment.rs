@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use super::Installment;
+use crate::domain::types::money::Money;
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum PaymentMethod {
+    Cash,
+    Pix,
+    Card,
+    Unknown,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Payment {
+    id: String,
+    account_id: String,
+    amount: Money,
+    method: PaymentMethod,
+    operator: String,
+    carried_credit: Money,
+    paid_at: DateTime<Utc>
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct PaymentAllocation {
+    pub installment_id: String,
+    pub purchase_id: String,
+    pub amount: Money,
+    pub settled: bool,
+}
+
+impl Payment {
+    pub fn new(account_id: String, amount: Money, method: PaymentMethod, operator: String) -> Payment {
+        Payment {
+            id: String::new(),
+            account_id,
+            amount,
+            method,
+            operator,
+            carried_credit: Money::zero(),
+            paid_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_account_id(&self) -> &str {
+        self.account_id.as_str()
+    }
+
+    pub fn get_amount(&self) -> Money {
+        self.amount
+    }
+
+    pub fn get_method(&self) -> PaymentMethod {
+        self.method
+    }
+
+    pub fn get_operator(&self) -> &str {
+        self.operator.as_str()
+    }
+
+    pub fn get_carried_credit(&self) -> Money {
+        self.carried_credit
+    }
+
+    pub fn set_carried_credit(&mut self, carried_credit: Money) {
+        self.carried_credit = carried_credit;
+    }
+
+    pub fn get_paid_at(&self) -> DateTime<Utc> {
+        self.paid_at
+    }
+
+    pub fn set_paid_at(&mut self, paid_at: DateTime<Utc>) {
+        self.paid_at = paid_at;
+    }
+
+    /// Spreads the payment over the installments in due date order, returning what
+    /// was allocated to each one and the remainder that could not be allocated.
+    pub fn allocate(&self, installments: &[Installment]) -> (Vec<PaymentAllocation>, Money) {
+        let mut ordered: Vec<&Installment> = installments.iter().filter(|i| !i.is_settled()).collect();
+        ordered.sort_by_key(|i| (i.get_due_date(), i.get_created_at(), i.get_number()));
+
+        let mut remaining = self.amount;
+        let mut allocations: Vec<PaymentAllocation> = Vec::new();
+        for installment in ordered {
+            if remaining.is_zero() {
+                break;
+            }
+
+            let outstanding = installment.get_outstanding_amount();
+            let amount = remaining.min(outstanding);
+            remaining = remaining.saturating_sub(amount);
+            allocations.push(PaymentAllocation {
+                installment_id: String::from(installment.get_id()),
+                purchase_id: String::from(installment.get_purchase_id()),
+                amount,
+                settled: amount == outstanding
+            });
+        }
+        (allocations, remaining)
+    }
+}
+
+impl PartialEq for Payment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.amount == other.amount &&
+        self.method == other.method &&
+        self.operator == other.operator &&
+        self.carried_credit == other.carried_credit
+    }
+}
+
+impl PaymentMethod {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Cash => "CASH",
+            Self::Pix => "PIX",
+            Self::Card => "CARD",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> PaymentMethod {
+        match s {
+            "CASH" => PaymentMethod::Cash,
+            "PIX" => PaymentMethod::Pix,
+            "CARD" => PaymentMethod::Card,
+            _ => PaymentMethod::Unknown,
+        }
+    }
+}
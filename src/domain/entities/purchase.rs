@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use super::Installment;
+use crate::domain::types::money::Money;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Purchase {
+    id: String,
+    account_id: String,
+    store_id: Option<String>,
+    amount: Money,
+    paid_amount: Money,
+    description: String,
+    merchant_reference: String,
+    purchased_at: DateTime<Utc>,
+    installments: Vec<Installment>,
+    created_at: DateTime<Utc>
+}
+
+impl Purchase {
+    pub fn new(account_id: String, amount: Money, description: String, merchant_reference: String, purchased_at: DateTime<Utc>) -> Purchase {
+        Purchase {
+            id: String::new(),
+            account_id,
+            store_id: None,
+            amount,
+            paid_amount: Money::zero(),
+            description,
+            merchant_reference,
+            purchased_at,
+            installments: Vec::new(),
+            created_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_account_id(&self) -> &str {
+        self.account_id.as_str()
+    }
+
+    pub fn get_store_id(&self) -> Option<&str> {
+        self.store_id.as_deref()
+    }
+
+    pub fn set_store_id(&mut self, store_id: Option<String>) {
+        self.store_id = store_id;
+    }
+
+    pub fn get_amount(&self) -> Money {
+        self.amount
+    }
+
+    pub fn get_paid_amount(&self) -> Money {
+        self.paid_amount
+    }
+
+    pub fn set_paid_amount(&mut self, paid_amount: Money) {
+        self.paid_amount = paid_amount;
+    }
+
+    pub fn get_outstanding_amount(&self) -> Money {
+        self.amount.saturating_sub(self.paid_amount)
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.paid_amount >= self.amount
+    }
+
+    pub fn get_description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    pub fn get_merchant_reference(&self) -> &str {
+        self.merchant_reference.as_str()
+    }
+
+    pub fn get_purchased_at(&self) -> DateTime<Utc> {
+        self.purchased_at
+    }
+
+    pub fn get_installments(&self) -> &[Installment] {
+        &self.installments
+    }
+
+    pub fn set_installments(&mut self, installments: Vec<Installment>) {
+        self.installments = installments;
+    }
+
+    /// Settles the installments with credit carried by the account, the ones due
+    /// first before the others. Returns the credit left.
+    pub fn apply_credit(&mut self, credit: Money) -> Money {
+        self.installments.sort_by_key(|i| (i.get_due_date(), i.get_number()));
+
+        let mut remaining = credit;
+        for installment in self.installments.iter_mut() {
+            let amount = remaining.min(installment.get_outstanding_amount());
+            installment.set_paid_amount(installment.get_paid_amount().checked_add(amount).unwrap_or(installment.get_amount()));
+            remaining = remaining.saturating_sub(amount);
+        }
+
+        let applied = credit.saturating_sub(remaining);
+        self.paid_amount = self.paid_amount.checked_add(applied).unwrap_or(self.amount).min(self.amount);
+        remaining
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+}
+
+impl PartialEq for Purchase {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.amount == other.amount &&
+        self.paid_amount == other.paid_amount &&
+        self.description == other.description &&
+        self.merchant_reference == other.merchant_reference &&
+        self.purchased_at == other.purchased_at &&
+        self.installments == other.installments
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use super::Role;
+
+/// Long-lived token exchanged for new access tokens. Every use replaces it with a new
+/// one of the same family, presenting a replaced token revokes the whole family.
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    id: String,
+    family_id: String,
+    subject: String,
+    role: Role,
+    store_id: Option<String>,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>
+}
+
+impl RefreshToken {
+    pub fn new(family_id: String, subject: String, role: Role, token_hash: String, expires_at: DateTime<Utc>) -> RefreshToken {
+        RefreshToken {
+            id: String::new(),
+            family_id,
+            subject,
+            role,
+            store_id: None,
+            token_hash,
+            expires_at,
+            revoked_at: None,
+            created_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_family_id(&self) -> &str {
+        self.family_id.as_str()
+    }
+
+    pub fn get_subject(&self) -> &str {
+        self.subject.as_str()
+    }
+
+    pub fn get_role(&self) -> Role {
+        self.role
+    }
+
+    pub fn get_store_id(&self) -> Option<&str> {
+        self.store_id.as_deref()
+    }
+
+    pub fn set_store_id(&mut self, store_id: Option<String>) {
+        self.store_id = store_id;
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        self.token_hash.as_str()
+    }
+
+    pub fn get_expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn get_revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    pub fn set_revoked_at(&mut self, revoked_at: Option<DateTime<Utc>>) {
+        self.revoked_at = revoked_at;
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+impl PartialEq for RefreshToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.family_id == other.family_id &&
+        self.subject == other.subject &&
+        self.role == other.role &&
+        self.store_id == other.store_id &&
+        self.token_hash == other.token_hash &&
+        self.expires_at == other.expires_at &&
+        self.revoked_at == other.revoked_at
+    }
+}
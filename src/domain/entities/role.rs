@@ -0,0 +1,158 @@
+use serde::Serialize;
+
+/// Roles of the people operating the system. Customers authenticate with their own
+/// tokens and have no staff permissions, services hold the permissions of their API key.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum Role {
+    Admin,
+    StoreManager,
+    Cashier,
+    Customer,
+    Service,
+    Unknown,
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum Permission {
+    UsersRead,
+    UsersWrite,
+    UsersDelete,
+    AccountsRead,
+    AccountsWrite,
+    PurchasesCreate,
+    PaymentsCreate,
+    AdjustmentsCreate,
+    LedgerRead,
+    StatementsRead,
+    AccrualsRun,
+    PasswordResetsCreate,
+    ApiKeysManage,
+    StoresRead,
+    StoresWrite,
+    UsersRestore,
+    UsersBlock,
+    Unknown,
+}
+
+const ALL_PERMISSIONS: [Permission; 17] = [
+    Permission::UsersRead,
+    Permission::UsersWrite,
+    Permission::UsersDelete,
+    Permission::AccountsRead,
+    Permission::AccountsWrite,
+    Permission::PurchasesCreate,
+    Permission::PaymentsCreate,
+    Permission::AdjustmentsCreate,
+    Permission::LedgerRead,
+    Permission::StatementsRead,
+    Permission::AccrualsRun,
+    Permission::PasswordResetsCreate,
+    Permission::ApiKeysManage,
+    Permission::StoresRead,
+    Permission::StoresWrite,
+    Permission::UsersRestore,
+    Permission::UsersBlock,
+];
+
+const STORE_MANAGER_PERMISSIONS: [Permission; 12] = [
+    Permission::UsersRead,
+    Permission::UsersWrite,
+    Permission::UsersBlock,
+    Permission::AccountsRead,
+    Permission::AccountsWrite,
+    Permission::PurchasesCreate,
+    Permission::PaymentsCreate,
+    Permission::AdjustmentsCreate,
+    Permission::LedgerRead,
+    Permission::StatementsRead,
+    Permission::PasswordResetsCreate,
+    Permission::StoresRead,
+];
+
+const CASHIER_PERMISSIONS: [Permission; 5] = [
+    Permission::UsersRead,
+    Permission::AccountsRead,
+    Permission::PurchasesCreate,
+    Permission::PaymentsCreate,
+    Permission::StatementsRead,
+];
+
+/// What a point-of-sale terminal needs at the counter, a subset of the store manager
+/// permissions so a leaked key never reaches beyond its own store.
+const API_KEY_PERMISSIONS: [Permission; 6] = [
+    Permission::UsersRead,
+    Permission::AccountsRead,
+    Permission::PurchasesCreate,
+    Permission::PaymentsCreate,
+    Permission::StatementsRead,
+    Permission::StoresRead,
+];
+
+impl Role {
+    pub fn get_permissions(&self) -> &'static [Permission] {
+        match self {
+            Self::Admin => &ALL_PERMISSIONS,
+            Self::StoreManager => &STORE_MANAGER_PERMISSIONS,
+            Self::Cashier => &CASHIER_PERMISSIONS,
+            Self::Customer | Self::Service | Self::Unknown => &[]
+        }
+    }
+
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::Admin => "ADMIN",
+            Self::StoreManager => "STORE_MANAGER",
+            Self::Cashier => "CASHIER",
+            Self::Customer => "CUSTOMER",
+            Self::Service => "SERVICE",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> Role {
+        match s {
+            "ADMIN" => Role::Admin,
+            "STORE_MANAGER" => Role::StoreManager,
+            "CASHIER" => Role::Cashier,
+            "CUSTOMER" => Role::Customer,
+            "SERVICE" => Role::Service,
+            _ => Role::Unknown,
+        }
+    }
+}
+
+impl Permission {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::UsersRead => "users:read",
+            Self::UsersWrite => "users:write",
+            Self::UsersDelete => "users:delete",
+            Self::AccountsRead => "accounts:read",
+            Self::AccountsWrite => "accounts:write",
+            Self::PurchasesCreate => "purchases:create",
+            Self::PaymentsCreate => "payments:create",
+            Self::AdjustmentsCreate => "adjustments:create",
+            Self::LedgerRead => "ledger:read",
+            Self::StatementsRead => "statements:read",
+            Self::AccrualsRun => "accruals:run",
+            Self::PasswordResetsCreate => "password_resets:create",
+            Self::ApiKeysManage => "api_keys:manage",
+            Self::StoresRead => "stores:read",
+            Self::StoresWrite => "stores:write",
+            Self::UsersRestore => "users:restore",
+            Self::UsersBlock => "users:block",
+            Self::Unknown => "unknown"
+        }
+    }
+
+    pub fn is_grantable_to_api_key(&self) -> bool {
+        API_KEY_PERMISSIONS.contains(self)
+    }
+
+    pub fn from_string(s: &str) -> Permission {
+        match ALL_PERMISSIONS.iter().find(|p| p.to_string() == s) {
+            Some(p) => *p,
+            None => Permission::Unknown
+        }
+    }
+}
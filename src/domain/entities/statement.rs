@@ -0,0 +1,142 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use super::{LedgerTransaction, TransactionKind};
+use crate::domain::types::money::Money;
+
+/// A ledger transaction as it was billed, with the amount it added to (or, when
+/// negative, discounted from) what the customer owes.
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct StatementLine {
+    pub transaction_id: String,
+    pub kind: TransactionKind,
+    pub description: String,
+    pub amount: Money,
+    pub posted_at: DateTime<Utc>,
+}
+
+/// Snapshot of a billing cycle taken when it closes. Statements are never
+/// changed afterwards, later corrections show up in the next cycle.
+#[derive(Serialize, Debug, Clone)]
+pub struct Statement {
+    id: String,
+    account_id: String,
+    period_start: NaiveDate,
+    closing_date: NaiveDate,
+    due_date: NaiveDate,
+    opening_balance: Money,
+    closing_balance: Money,
+    lines: Vec<StatementLine>,
+    created_at: DateTime<Utc>
+}
+
+impl Statement {
+    /// Returns `None` if the balance does not fit in `Money`.
+    pub fn new(account_id: String, period_start: NaiveDate, closing_date: NaiveDate, due_date: NaiveDate, opening_balance: Money, lines: Vec<StatementLine>) -> Option<Statement> {
+        let mut closing_balance = opening_balance;
+        for line in &lines {
+            closing_balance = closing_balance.checked_add(line.amount)?;
+        }
+
+        Some(Statement {
+            id: String::new(),
+            account_id,
+            period_start,
+            closing_date,
+            due_date,
+            opening_balance,
+            closing_balance,
+            lines,
+            created_at: Utc::now()
+        })
+    }
+
+    /// Bills the ledger transactions posted during the cycle.
+    pub fn close(account_id: String, period_start: NaiveDate, closing_date: NaiveDate, due_date: NaiveDate, opening_balance: Money, transactions: &[LedgerTransaction]) -> Option<Statement> {
+        let mut lines: Vec<StatementLine> = Vec::with_capacity(transactions.len());
+        for transaction in transactions {
+            let amount = transaction.get_receivable_change()?;
+            if amount.is_zero() {
+                continue;
+            }
+
+            lines.push(StatementLine {
+                transaction_id: String::from(transaction.get_id()),
+                kind: transaction.get_kind(),
+                description: String::from(transaction.get_description()),
+                amount,
+                posted_at: transaction.get_created_at()
+            });
+        }
+        Statement::new(account_id, period_start, closing_date, due_date, opening_balance, lines)
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_account_id(&self) -> &str {
+        self.account_id.as_str()
+    }
+
+    pub fn get_period_start(&self) -> NaiveDate {
+        self.period_start
+    }
+
+    pub fn get_closing_date(&self) -> NaiveDate {
+        self.closing_date
+    }
+
+    pub fn get_due_date(&self) -> NaiveDate {
+        self.due_date
+    }
+
+    pub fn get_opening_balance(&self) -> Money {
+        self.opening_balance
+    }
+
+    pub fn get_closing_balance(&self) -> Money {
+        self.closing_balance
+    }
+
+    /// Credit carried from overpayments leaves nothing to pay.
+    pub fn get_amount_due(&self) -> Money {
+        self.closing_balance.max(Money::zero())
+    }
+
+    /// Sum of the lines of a kind, `None` on overflow.
+    pub fn get_total(&self, kind: TransactionKind) -> Option<Money> {
+        let mut total = Money::zero();
+        for line in self.lines.iter().filter(|l| l.kind == kind) {
+            total = total.checked_add(line.amount)?;
+        }
+        Some(total)
+    }
+
+    pub fn get_lines(&self) -> &[StatementLine] {
+        &self.lines
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+}
+
+impl PartialEq for Statement {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.period_start == other.period_start &&
+        self.closing_date == other.closing_date &&
+        self.due_date == other.due_date &&
+        self.opening_balance == other.opening_balance &&
+        self.lines == other.lines
+    }
+}
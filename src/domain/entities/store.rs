@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::types::cnpj::CNPJ;
+use super::FeePolicy;
+
+/// Merchant extending credit to its customers, identified by its CNPJ.
+#[derive(Serialize, Debug, Clone)]
+pub struct Store {
+    id: String,
+    name: String,
+    document: CNPJ,
+    /// Rates charged on this store's overdue accounts, the platform default applies when missing.
+    fee_policy: Option<FeePolicy>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>
+}
+
+impl Store {
+    pub fn new(name: String, document: CNPJ) -> Store {
+        Store {
+            id: String::new(),
+            name,
+            document,
+            fee_policy: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn get_document(&self) -> &CNPJ {
+        &self.document
+    }
+
+    pub fn get_fee_policy(&self) -> Option<FeePolicy> {
+        self.fee_policy
+    }
+
+    pub fn set_fee_policy(&mut self, fee_policy: Option<FeePolicy>) {
+        self.fee_policy = fee_policy;
+    }
+
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    pub fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    pub fn set_updated_at(&mut self, updated_at: DateTime<Utc>) {
+        self.updated_at = updated_at;
+    }
+}
+
+impl PartialEq for Store {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.name == other.name &&
+        self.document == other.document &&
+        self.fee_policy == other.fee_policy
+    }
+}
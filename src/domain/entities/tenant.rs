@@ -0,0 +1,17 @@
+/// Customers a caller can reach, resolved from its credentials and handed to every use case
+/// looking customers up so repositories only see the rows of that store.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tenant {
+    /// Platform staff reach the customers of every store.
+    Platform,
+    Store(String),
+}
+
+impl Tenant {
+    pub fn get_store_id(&self) -> Option<&str> {
+        match self {
+            Self::Platform => None,
+            Self::Store(id) => Some(id.as_str())
+        }
+    }
+}
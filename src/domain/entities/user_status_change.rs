@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use super::UserStatus;
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub enum StatusChangeReason {
+    OverdueDebt,
+    Fraud,
+    CustomerRequest,
+    DebtSettled,
+    Other,
+    /// Recorded when a deleted user is brought back, never sent by the caller.
+    Restored,
+    Unknown,
+}
+
+/// Entry of the history of blocks, unblocks and restores of a customer, never changed once written.
+#[derive(Serialize, Debug, Clone)]
+pub struct UserStatusChange {
+    id: String,
+    user_id: String,
+    from_status: UserStatus,
+    to_status: UserStatus,
+    reason: StatusChangeReason,
+    note: Option<String>,
+    changed_by: String,
+    changed_at: DateTime<Utc>
+}
+
+impl UserStatusChange {
+    pub fn new(user_id: String, from_status: UserStatus, to_status: UserStatus, reason: StatusChangeReason, changed_by: String) -> UserStatusChange {
+        UserStatusChange {
+            id: String::new(),
+            user_id,
+            from_status,
+            to_status,
+            reason,
+            note: None,
+            changed_by,
+            changed_at: Utc::now()
+        }
+    }
+
+    pub fn set_uuid(&mut self, uuid: String) {
+        self.id = uuid;
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        self.user_id.as_str()
+    }
+
+    pub fn get_from_status(&self) -> UserStatus {
+        self.from_status
+    }
+
+    pub fn get_to_status(&self) -> UserStatus {
+        self.to_status
+    }
+
+    pub fn get_reason(&self) -> StatusChangeReason {
+        self.reason
+    }
+
+    pub fn get_note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    pub fn set_note(&mut self, note: Option<String>) {
+        self.note = note;
+    }
+
+    pub fn get_changed_by(&self) -> &str {
+        self.changed_by.as_str()
+    }
+
+    pub fn get_changed_at(&self) -> DateTime<Utc> {
+        self.changed_at
+    }
+}
+
+impl PartialEq for UserStatusChange {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+        self.user_id == other.user_id &&
+        self.from_status == other.from_status &&
+        self.to_status == other.to_status &&
+        self.reason == other.reason &&
+        self.note == other.note &&
+        self.changed_by == other.changed_by
+    }
+}
+
+impl StatusChangeReason {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Self::OverdueDebt => "OVERDUE_DEBT",
+            Self::Fraud => "FRAUD",
+            Self::CustomerRequest => "CUSTOMER_REQUEST",
+            Self::DebtSettled => "DEBT_SETTLED",
+            Self::Other => "OTHER",
+            Self::Restored => "RESTORED",
+            Self::Unknown => "UNKNOWN"
+        }
+    }
+
+    pub fn from_string(s: &str) -> StatusChangeReason {
+        match s {
+            "OVERDUE_DEBT" => StatusChangeReason::OverdueDebt,
+            "FRAUD" => StatusChangeReason::Fraud,
+            "CUSTOMER_REQUEST" => StatusChangeReason::CustomerRequest,
+            "DEBT_SETTLED" => StatusChangeReason::DebtSettled,
+            "OTHER" => StatusChangeReason::Other,
+            "RESTORED" => StatusChangeReason::Restored,
+            _ => StatusChangeReason::Unknown,
+        }
+    }
+}
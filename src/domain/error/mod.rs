@@ -10,6 +10,8 @@ pub enum Kind {
     Business,
     NotFound,
     AlreadyExists,
+    Unauthorized,
+    Forbidden,
     Internal
 }
 
@@ -42,6 +44,16 @@ impl Error {
         Error { kind: Kind::Business, code, message: String::from(message)  }
     }
 
+    /// The caller could not be authenticated, such as a missing, invalid or expired token.
+    pub fn new_unauthorized(code: u8) -> Error {
+        Error { kind: Kind::Unauthorized, code, message: String::from("unauthorized") }
+    }
+
+    /// The caller is authenticated but not allowed to perform the request.
+    pub fn new_forbidden(code: u8) -> Error {
+        Error { kind: Kind::Forbidden, code, message: String::from("forbidden") }
+    }
+
     pub fn new_not_found(code: u8, entity: &str) -> Error {
         Error { kind: Kind::NotFound, code, message: format!("{} not found", entity)}
     }
@@ -0,0 +1,70 @@
+use chrono::{Datelike, Months, NaiveDate};
+use serde::Serialize;
+
+/// Cycle days are capped so every month has them.
+pub const MAX_CYCLE_DAY: u32 = 28;
+
+/// Monthly billing cycle of a tab: purchases made up to the closing day are
+/// billed in that cycle and must be paid by the following due day.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+pub struct BillingCycle {
+    closing_day: u32,
+    due_day: u32,
+}
+
+impl BillingCycle {
+    pub fn new(closing_day: u32, due_day: u32) -> Result<BillingCycle, String> {
+        if !(1..=MAX_CYCLE_DAY).contains(&closing_day) {
+            return Err(format!("closing day must be between 1 and {}", MAX_CYCLE_DAY));
+        }
+
+        if !(1..=MAX_CYCLE_DAY).contains(&due_day) || due_day == closing_day {
+            return Err(format!("due day must be between 1 and {} and differ from the closing day", MAX_CYCLE_DAY));
+        }
+
+        Ok(BillingCycle { closing_day, due_day })
+    }
+
+    pub fn get_closing_day(&self) -> u32 {
+        self.closing_day
+    }
+
+    pub fn get_due_day(&self) -> u32 {
+        self.due_day
+    }
+
+    /// Most recent closing date on or before `date`.
+    pub fn last_closing_on_or_before(&self, date: NaiveDate) -> NaiveDate {
+        let month = if date.day() >= self.closing_day { date } else { date - Months::new(1) };
+        with_day(month, self.closing_day)
+    }
+
+    /// Closing date of the cycle a purchase made on `date` belongs to.
+    pub fn next_closing_on_or_after(&self, date: NaiveDate) -> NaiveDate {
+        let month = if date.day() <= self.closing_day { date } else { date + Months::new(1) };
+        with_day(month, self.closing_day)
+    }
+
+    /// Due date of the statement closed on `closing_date`.
+    pub fn due_date_for_closing(&self, closing_date: NaiveDate) -> NaiveDate {
+        let month = if self.due_day > self.closing_day { closing_date } else { closing_date + Months::new(1) };
+        with_day(month, self.due_day)
+    }
+
+    pub fn due_date_for_purchase(&self, purchased_on: NaiveDate) -> NaiveDate {
+        self.due_date_for_closing(self.next_closing_on_or_after(purchased_on))
+    }
+}
+
+impl Default for BillingCycle {
+    fn default() -> Self {
+        BillingCycle { closing_day: 5, due_day: 15 }
+    }
+}
+
+fn with_day(date: NaiveDate, day: u32) -> NaiveDate {
+    // days up to MAX_CYCLE_DAY exist in every month
+    date.with_day(day).unwrap_or(date)
+}
+
+mod tests;
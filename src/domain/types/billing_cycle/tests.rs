@@ -0,0 +1,37 @@
+#[cfg(test)]
+#[test]
+fn it_should_reject_days_missing_from_some_months() {
+    use super::BillingCycle;
+
+    assert!(BillingCycle::new(5, 15).is_ok());
+    assert!(BillingCycle::new(0, 15).is_err());
+    assert!(BillingCycle::new(5, 31).is_err());
+    assert!(BillingCycle::new(10, 10).is_err());
+}
+
+#[test]
+fn it_should_bill_purchases_in_the_cycle_they_were_made() {
+    use chrono::NaiveDate;
+    use super::BillingCycle;
+
+    let cycle = BillingCycle::new(5, 15).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(cycle.due_date_for_purchase(date(2024, 3, 5)), date(2024, 3, 15));
+    assert_eq!(cycle.due_date_for_purchase(date(2024, 3, 6)), date(2024, 4, 15));
+    assert_eq!(cycle.due_date_for_purchase(date(2024, 12, 20)), date(2025, 1, 15));
+    assert_eq!(cycle.last_closing_on_or_before(date(2024, 3, 4)), date(2024, 2, 5));
+    assert_eq!(cycle.last_closing_on_or_before(date(2024, 3, 5)), date(2024, 3, 5));
+}
+
+#[test]
+fn it_should_move_the_due_date_to_the_next_month_when_due_day_comes_first() {
+    use chrono::NaiveDate;
+    use super::BillingCycle;
+
+    let cycle = BillingCycle::new(25, 10).unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(cycle.due_date_for_closing(date(2024, 1, 25)), date(2024, 2, 10));
+    assert_eq!(cycle.due_date_for_purchase(date(2024, 1, 26)), date(2024, 3, 10));
+}
@@ -0,0 +1,85 @@
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Write, Display};
+
+const FIRST_DIGIT_WEIGHTS: [u32; 12] = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const SECOND_DIGIT_WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CNPJ ([u32; 14]);
+
+impl CNPJ {
+
+    pub fn is_valid(&self) -> bool {
+        self.numbers_are_not_repeated() && self.are_verifier_digits_valid()
+    }
+
+    fn numbers_are_not_repeated(&self) -> bool {
+        self.0.iter().any(|n| *n != self.0[0])
+    }
+
+    fn are_verifier_digits_valid(&self) -> bool {
+        self.get_verifier_digit(&FIRST_DIGIT_WEIGHTS) == self.0[12] &&
+        self.get_verifier_digit(&SECOND_DIGIT_WEIGHTS) == self.0[13]
+    }
+
+    fn get_verifier_digit(&self, weights: &[u32]) -> u32 {
+        let sum: u32 = weights.iter().zip(self.0.iter()).map(|(w, n)| w * n).sum();
+        match sum % 11 {
+            0 | 1 => 0,
+            rest => 11 - rest
+        }
+    }
+
+    /// Accepts the 14 digits alone or punctuated as in 11.222.333/0001-81.
+    pub fn from_string(cnpj: String) -> Result<CNPJ, String> {
+        let mut document_numbers: [u32; 14] = [0;14];
+        let mut count: usize = 0;
+
+        for ch in cnpj.chars().filter(|c| !matches!(c, '.' | '/' | '-')) {
+            if count == 14 {
+                return Err(String::from("CNPJ len bigger than 14 digits"));
+            }
+
+            if let Some(number) = ch.to_digit(10) {
+                document_numbers[count] = number;
+            } else {
+                return Err(String::from("CNPJ with invalid digits"));
+            }
+            count += 1;
+        }
+
+        if count < 14 {
+            return Err(String::from("CNPJ shorter than 14 digits"));
+        }
+
+        Ok(CNPJ(document_numbers))
+    }
+
+    /// Formats as 11.222.333/0001-81.
+    pub fn to_formatted_string(&self) -> String {
+        let digits = self.to_string();
+        format!("{}.{}.{}/{}-{}", &digits[0..2], &digits[2..5], &digits[5..8], &digits[8..12], &digits[12..14])
+    }
+}
+
+impl Serialize for CNPJ {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        let mut val: String = String::new();
+        for n in self.0 {
+            let _ = write!(&mut val, "{}", n);
+        }
+        serializer.serialize_str(&val)
+    }
+}
+
+impl Display for CNPJ {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.map(|digit| digit.to_string()).concat())
+    }
+}
+
+
+mod tests;
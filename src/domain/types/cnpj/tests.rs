@@ -0,0 +1,59 @@
+#[cfg(test)]
+#[test]
+fn it_should_check_if_cnpjs_are_valids() {
+    use super::CNPJ;
+
+    let cnpjs = vec![
+        CNPJ::from_string(String::from("11222333000181")).unwrap(),
+        CNPJ::from_string(String::from("11444777000161")).unwrap(),
+        CNPJ::from_string(String::from("45997418000153")).unwrap(),
+        CNPJ::from_string(String::from("33.000.167/0001-01")).unwrap()
+    ];
+
+    for cnpj in cnpjs {
+        assert!(cnpj.is_valid())
+    }
+}
+
+#[test]
+fn it_should_check_if_cnpjs_are_invalids() {
+    use super::CNPJ;
+
+    let cnpjs = vec![
+        CNPJ::from_string(String::from("11222333000182")).unwrap(),
+        CNPJ::from_string(String::from("11444777000171")).unwrap(),
+        CNPJ::from_string(String::from("11111111111111")).unwrap(),
+        CNPJ::from_string(String::from("00000000000000")).unwrap()
+    ];
+
+    for cnpj in cnpjs {
+        assert!(!cnpj.is_valid())
+    }
+}
+
+#[test]
+fn it_should_return_error_when_string_given_has_not_14_digits() {
+    use super::CNPJ;
+
+    assert!(CNPJ::from_string(String::from("1122233300018")).is_err());
+    assert!(CNPJ::from_string(String::from("112223330001811")).is_err());
+}
+
+#[test]
+fn it_should_return_error_when_string_has_non_numeric_characters() {
+    use super::CNPJ;
+
+    assert!(CNPJ::from_string(String::from("11 222 333 0001 81")).is_err());
+    assert!(CNPJ::from_string(String::from("1122233300018a")).is_err());
+}
+
+#[test]
+fn it_should_format_and_serialize_cnpjs() {
+    use super::CNPJ;
+
+    let cnpj = CNPJ::from_string(String::from("11.222.333/0001-81")).unwrap();
+
+    assert_eq!(cnpj.to_string(), "11222333000181");
+    assert_eq!(cnpj.to_formatted_string(), "11.222.333/0001-81");
+    assert_eq!(serde_json::to_string(&cnpj).unwrap(), "\"11222333000181\"");
+}
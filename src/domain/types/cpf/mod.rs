@@ -68,6 +68,12 @@ impl CPF {
 
         Ok(CPF(document_numbers))
     }
+
+    /// Formats as ***.356.260-**, only the middle digits are shown.
+    pub fn to_masked_string(&self) -> String {
+        let digits = self.to_string();
+        format!("***.{}.{}-**", &digits[3..6], &digits[6..9])
+    }
 }
 
 impl Serialize for CPF {
@@ -53,4 +53,12 @@ fn it_should_return_error_when_string_has_non_numeric_characters() {
 
 
     assert!(CPF::from_string(String::from("7593f47702 3")).is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn it_should_only_show_the_middle_digits_when_masked() {
+    use super::CPF;
+
+    let cpf = CPF::from_string(String::from("40735626065")).unwrap();
+    assert_eq!(cpf.to_masked_string(), "***.356.260-**");
+}
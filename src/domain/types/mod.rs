@@ -1,2 +1,5 @@
 pub mod cpf;
-pub mod birth_date;
\ No newline at end of file
+pub mod cnpj;
+pub mod birth_date;
+pub mod money;
+pub mod billing_cycle;
\ No newline at end of file
@@ -0,0 +1,170 @@
+use serde::{Serialize, Serializer, Deserialize, Deserializer, de::{self, Visitor}};
+use std::fmt::{self, Display};
+
+/// Monetary amount in BRL stored as integer centavos.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Money(i64);
+
+impl Money {
+    pub fn from_cents(cents: i64) -> Money {
+        Money(cents)
+    }
+
+    pub fn zero() -> Money {
+        Money(0)
+    }
+
+    pub fn to_cents(&self) -> i64 {
+        self.0
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.0 > 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn abs(&self) -> Money {
+        Money(self.0.saturating_abs())
+    }
+
+    pub fn checked_add(&self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    pub fn checked_sub(&self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+
+    pub fn saturating_sub(&self, other: Money) -> Money {
+        Money(self.0.saturating_sub(other.0))
+    }
+
+    /// Applies a rate given in basis points (1/100 of a percent), rounding half up.
+    pub fn percentage_bps(&self, bps: u32) -> Money {
+        let cents = (self.0 as i128 * bps as i128 + 5000).div_euclid(10000);
+        Money(cents as i64)
+    }
+
+    /// Parses a decimal amount in reais such as `"12.34"`, `"-0.5"` or `"12"`.
+    pub fn from_string(amount: String) -> Result<Money, String> {
+        let (negative, digits) = match amount.trim().strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, amount.trim())
+        };
+
+        let (reais, centavos) = match digits.split_once('.') {
+            Some((r, c)) => (r, c),
+            None => (digits, "")
+        };
+
+        if reais.is_empty() || !reais.chars().all(|c| c.is_ascii_digit()) {
+            return Err(String::from("amount with invalid digits"));
+        }
+
+        if centavos.len() > 2 || !centavos.chars().all(|c| c.is_ascii_digit()) {
+            return Err(String::from("amount with more than two decimal places"));
+        }
+
+        let reais: i64 = match reais.parse() {
+            Ok(r) => r,
+            Err(_) => return Err(String::from("amount too large"))
+        };
+
+        let centavos: i64 = match centavos.len() {
+            0 => 0,
+            1 => centavos.parse::<i64>().unwrap_or(0) * 10,
+            _ => centavos.parse::<i64>().unwrap_or(0)
+        };
+
+        let cents = match reais.checked_mul(100).and_then(|r| r.checked_add(centavos)) {
+            Some(c) => c,
+            None => return Err(String::from("amount too large"))
+        };
+
+        if negative {
+            return Ok(Money(-cents));
+        }
+        Ok(Money(cents))
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let cents = self.0.unsigned_abs();
+        serializer.serialize_str(&format!("{}{}.{:02}", sign, cents / 100, cents % 100))
+    }
+}
+
+struct MoneyVisitor;
+
+impl<'de> Visitor<'de> for MoneyVisitor {
+    type Value = Money;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer amount of centavos or a decimal string such as \"12.34\"")
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Money, E>
+        where
+            E: de::Error,
+    {
+        Ok(Money(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Money, E>
+        where
+            E: de::Error,
+    {
+        match i64::try_from(value) {
+            Ok(v) => Ok(Money(v)),
+            Err(_) => Err(E::custom("amount too large"))
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Money, E>
+        where
+            E: de::Error,
+    {
+        Money::from_string(String::from(value)).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D>(deserializer: D) -> Result<Money, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cents = self.0.unsigned_abs();
+        let reais = (cents / 100).to_string();
+
+        let mut grouped = String::new();
+        for (i, digit) in reais.chars().enumerate() {
+            if i > 0 && (reais.len() - i).is_multiple_of(3) {
+                grouped.push('.');
+            }
+            grouped.push(digit);
+        }
+
+        let sign = if self.0 < 0 { "-" } else { "" };
+        write!(f, "{}R$ {},{:02}", sign, grouped, cents % 100)
+    }
+}
+
+mod tests;
@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[test]
+fn it_should_parse_decimal_strings() {
+    use super::Money;
+
+    assert_eq!(Money::from_string(String::from("12.34")).unwrap(), Money::from_cents(1234));
+    assert_eq!(Money::from_string(String::from("12.3")).unwrap(), Money::from_cents(1230));
+    assert_eq!(Money::from_string(String::from("12")).unwrap(), Money::from_cents(1200));
+    assert_eq!(Money::from_string(String::from("-0.05")).unwrap(), Money::from_cents(-5));
+}
+
+#[test]
+fn it_should_return_error_when_string_is_not_a_valid_amount() {
+    use super::Money;
+
+    assert!(Money::from_string(String::from("12.345")).is_err());
+    assert!(Money::from_string(String::from("12,34")).is_err());
+    assert!(Money::from_string(String::from(".50")).is_err());
+    assert!(Money::from_string(String::from("abc")).is_err());
+    assert!(Money::from_string(String::from("99999999999999999999")).is_err());
+}
+
+#[test]
+fn it_should_deserialize_both_cents_and_decimal_strings() {
+    use super::Money;
+
+    assert_eq!(serde_json::from_str::<Money>("1234").unwrap(), Money::from_cents(1234));
+    assert_eq!(serde_json::from_str::<Money>("\"12.34\"").unwrap(), Money::from_cents(1234));
+    assert!(serde_json::from_str::<Money>("12.34").is_err());
+}
+
+#[test]
+fn it_should_serialize_as_a_decimal_string() {
+    use super::Money;
+
+    assert_eq!(serde_json::to_string(&Money::from_cents(123456)).unwrap(), "\"1234.56\"");
+    assert_eq!(serde_json::to_string(&Money::from_cents(-5)).unwrap(), "\"-0.05\"");
+}
+
+#[test]
+fn it_should_format_in_brazilian_reais() {
+    use super::Money;
+
+    assert_eq!(Money::from_cents(123456).to_string(), "R$ 1.234,56");
+    assert_eq!(Money::from_cents(100000000).to_string(), "R$ 1.000.000,00");
+    assert_eq!(Money::from_cents(5).to_string(), "R$ 0,05");
+    assert_eq!(Money::from_cents(-99950).to_string(), "-R$ 999,50");
+}
+
+#[test]
+fn it_should_return_none_when_arithmetic_overflows() {
+    use super::Money;
+
+    assert_eq!(Money::from_cents(100).checked_add(Money::from_cents(50)), Some(Money::from_cents(150)));
+    assert_eq!(Money::from_cents(100).checked_sub(Money::from_cents(150)), Some(Money::from_cents(-50)));
+    assert_eq!(Money::from_cents(i64::MAX).checked_add(Money::from_cents(1)), None);
+    assert_eq!(Money::from_cents(i64::MIN).checked_sub(Money::from_cents(1)), None);
+}
+
+#[test]
+fn it_should_apply_rates_in_basis_points() {
+    use super::Money;
+
+    assert_eq!(Money::from_cents(10000).percentage_bps(200), Money::from_cents(200));
+    assert_eq!(Money::from_cents(12345).percentage_bps(100), Money::from_cents(123));
+    assert_eq!(Money::from_cents(150).percentage_bps(100), Money::from_cents(2));
+    assert_eq!(Money::from_cents(10000).percentage_bps(0), Money::zero());
+}
@@ -0,0 +1,83 @@
+use chrono::{Utc, DateTime};
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{CreditAccount, AccountStatus, Tenant},
+    error::Error, types::{cpf::CPF, money::Money, billing_cycle::BillingCycle}
+};
+
+pub const ACCOUNT_ALREADY_EXISTS: u8 = 10;
+pub const ACCOUNT_NOT_FOUND: u8 = 11;
+pub const INVALID_CREDIT_LIMIT_ERROR: u8 = 12;
+pub const ACCOUNT_CLOSED_ERROR: u8 = 13;
+pub const OUTSTANDING_BALANCE_ERROR: u8 = 14;
+pub const INVALID_BILLING_CYCLE_ERROR: u8 = 21;
+pub const ACCOUNT_STORE_MISMATCH_ERROR: u8 = 44;
+
+#[async_trait]
+pub trait AccountUseCase {
+    async fn create(&self, tenant: &Tenant, dto: AccountCreateRequestDTO) -> Result<(), Error>;
+    async fn update(&self, tenant: &Tenant, document: &str, dto: AccountUpdateRequestDTO) -> Result<(), Error>;
+    async fn get(&self, tenant: &Tenant, document: &str) -> Result<AccountResponseDTO, Error>;
+    async fn close(&self, tenant: &Tenant, document: &str) -> Result<(), Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AccountCreateRequestDTO {
+    pub document: String,
+    #[serde(default)]
+    pub store_id: Option<String>,
+    pub credit_limit: Money,
+    pub closing_day: Option<u32>,
+    pub due_day: Option<u32>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AccountUpdateRequestDTO {
+    pub credit_limit: Money,
+    pub closing_day: Option<u32>,
+    pub due_day: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct AccountResponseDTO {
+    pub id: String,
+    pub document: CPF,
+    pub store_id: Option<String>,
+    pub balance: Money,
+    pub credit_limit: Money,
+    pub available_credit: Money,
+    pub status: AccountStatus,
+    pub billing_cycle: BillingCycle,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>
+}
+
+impl AccountResponseDTO {
+    pub fn from_account(account: CreditAccount, document: CPF) -> Self {
+        AccountResponseDTO {
+            id: String::from(account.get_id()),
+            document,
+            store_id: account.get_store_id().map(String::from),
+            balance: account.get_balance(),
+            credit_limit: account.get_credit_limit(),
+            available_credit: account.get_available_credit(),
+            status: account.get_status(),
+            billing_cycle: account.get_billing_cycle(),
+            created_at: account.get_created_at(),
+            updated_at: account.get_updated_at()
+        }
+    }
+}
+
+/// Builds the billing cycle requested for an account, keeping the current
+/// day for whichever of the two days is not informed.
+pub fn billing_cycle_from_days(current: BillingCycle, closing_day: Option<u32>, due_day: Option<u32>) -> Result<BillingCycle, Error> {
+    let closing_day = closing_day.unwrap_or(current.get_closing_day());
+    let due_day = due_day.unwrap_or(current.get_due_day());
+    match BillingCycle::new(closing_day, due_day) {
+        Ok(cycle) => Ok(cycle),
+        Err(_) => Err(Error::new_business(INVALID_BILLING_CYCLE_ERROR))
+    }
+}
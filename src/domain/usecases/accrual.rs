@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::domain::{
+    entities::{Fee, FeeKind},
+    error::Error,
+    types::money::Money
+};
+
+pub const INVALID_REFERENCE_DATE_ERROR: u8 = 45;
+
+#[async_trait]
+pub trait AccrualUseCase {
+    /// Charges late fees and interest on purchases overdue at `reference_date`, which can be
+    /// neither after today nor before the last run. A dry run reports the charges without posting them.
+    async fn run(&self, reference_date: NaiveDate, dry_run: bool) -> Result<AccrualReportDTO, Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AccrualRequestDTO {
+    pub reference_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct FeeChargeDTO {
+    pub account_id: String,
+    pub installment_id: Option<String>,
+    pub kind: FeeKind,
+    pub period: String,
+    pub amount: Money,
+}
+
+impl FeeChargeDTO {
+    pub fn from_fee(fee: &Fee) -> FeeChargeDTO {
+        FeeChargeDTO {
+            account_id: String::from(fee.get_account_id()),
+            installment_id: fee.get_installment_id().map(String::from),
+            kind: fee.get_kind(),
+            period: String::from(fee.get_period()),
+            amount: fee.get_amount()
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct AccrualReportDTO {
+    pub reference_date: NaiveDate,
+    pub dry_run: bool,
+    pub charges: Vec<FeeChargeDTO>,
+    pub total: Money,
+}
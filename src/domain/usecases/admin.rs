@@ -1,12 +1,116 @@
-use crate::domain::error::Error;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use crate::domain::{entities::{Role, Permission, Tenant}, error::Error};
 use async_trait::async_trait;
 
 pub const INVALID_TOKEN_ERROR: u8 = 5;
 pub const EXPIRED_TOKEN_ERROR: u8 = 6;
 pub const MISSING_AUTH_TOKEN: u8 = 7;
+pub const PERMISSION_DENIED_ERROR: u8 = 27;
+pub const TENANT_REQUIRED_ERROR: u8 = 35;
 
 #[async_trait]
 pub trait AdminUseCase {
-    async fn validate_token(&self, token: String) -> Result<(), Error>;
+    async fn validate_token(&self, token: String) -> Result<Principal, Error>;
     async fn generate_token(&self) -> Result<String, Error>;
-}
\ No newline at end of file
+    async fn issue_token(&self, dto: TokenRequestDTO) -> Result<String, Error>;
+    async fn inspect_token(&self, token: String) -> Result<TokenClaimsDTO, Error>;
+    /// Issues an access token together with the refresh token used to renew it.
+    async fn start_session(&self, dto: TokenRequestDTO) -> Result<TokenPairResponseDTO, Error>;
+    async fn refresh(&self, dto: RefreshRequestDTO) -> Result<TokenPairResponseDTO, Error>;
+    async fn logout(&self, access_token: String, dto: LogoutRequestDTO) -> Result<(), Error>;
+    /// Public keys verifying staff tokens, empty while tokens are signed with a shared secret.
+    async fn get_jwks(&self) -> Result<JsonWebKeySetDTO, Error>;
+}
+
+/// Overrides for a single staff token, configured values are used for missing fields.
+/// Tokens are issued for the admin role unless another one is given.
+#[derive(Debug, Default, Clone)]
+pub struct TokenRequestDTO {
+    pub duration: Option<Duration>,
+    pub subject: Option<String>,
+    pub role: Option<Role>,
+    /// Binds the token to a store, required for every role but admin.
+    pub store_id: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TokenClaimsDTO {
+    pub subject: String,
+    pub role: String,
+    pub permissions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_id: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub expired: bool,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct RefreshRequestDTO {
+    pub refresh_token: String,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct LogoutRequestDTO {
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TokenPairResponseDTO {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_at: DateTime<Utc>,
+    pub refresh_expires_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct JsonWebKeySetDTO {
+    pub keys: Vec<JsonWebKeyDTO>,
+}
+
+/// A public key as described by RFC 7517, RSA keys carry `n` and `e`, Ed25519 keys carry `crv` and `x`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct JsonWebKeyDTO {
+    pub kty: String,
+    pub kid: String,
+    pub alg: String,
+    #[serde(rename = "use")]
+    pub key_use: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+}
+
+/// Holder of a valid staff token, available to the handlers behind the permission layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Principal {
+    pub token_id: String,
+    pub subject: String,
+    pub role: Role,
+    pub permissions: Vec<Permission>,
+    /// Set for API keys and staff bound to a store, which only act on its behalf.
+    pub store_id: Option<String>,
+}
+
+impl Principal {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// Only admins may act across stores, anyone else without a store is refused.
+    pub fn get_tenant(&self) -> Result<Tenant, Error> {
+        match (&self.store_id, self.role) {
+            (Some(store_id), _) => Ok(Tenant::Store(store_id.clone())),
+            (None, Role::Admin) => Ok(Tenant::Platform),
+            (None, _) => Err(Error::new_forbidden(TENANT_REQUIRED_ERROR))
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{entities::ApiKey, error::Error, usecases::admin::Principal};
+
+pub const API_KEY_NOT_FOUND_ERROR: u8 = 28;
+pub const INVALID_API_KEY_REQUEST_ERROR: u8 = 29;
+
+#[async_trait]
+pub trait ApiKeyUseCase {
+    /// Creates a key for the store, the returned key is the only time its secret is shown.
+    async fn create(&self, dto: CreateApiKeyRequestDTO) -> Result<CreatedApiKeyResponseDTO, Error>;
+    async fn list(&self, store_id: Option<String>) -> Result<Vec<ApiKeyResponseDTO>, Error>;
+    async fn revoke(&self, id: &str) -> Result<(), Error>;
+    async fn validate_key(&self, key: String) -> Result<Principal, Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateApiKeyRequestDTO {
+    pub name: String,
+    pub store_id: String,
+    pub permissions: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct ListApiKeysQueryDTO {
+    pub store_id: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CreatedApiKeyResponseDTO {
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponseDTO,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct ApiKeyResponseDTO {
+    pub id: String,
+    pub prefix: String,
+    pub name: String,
+    pub store_id: String,
+    pub permissions: Vec<String>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKeyResponseDTO {
+    pub fn from_api_key(api_key: &ApiKey) -> ApiKeyResponseDTO {
+        ApiKeyResponseDTO {
+            id: String::from(api_key.get_id()),
+            prefix: String::from(api_key.get_prefix()),
+            name: String::from(api_key.get_name()),
+            store_id: String::from(api_key.get_store_id()),
+            permissions: api_key.get_permissions().iter().map(|p| String::from(p.to_string())).collect(),
+            revoked_at: api_key.get_revoked_at(),
+            created_at: api_key.get_created_at()
+        }
+    }
+}
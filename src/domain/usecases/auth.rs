@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{entities::Tenant, error::Error};
+
+pub const INVALID_CREDENTIALS_ERROR: u8 = 24;
+
+#[async_trait]
+pub trait AuthUseCase {
+    async fn login(&self, dto: LoginRequestDTO) -> Result<LoginResponseDTO, Error>;
+    async fn validate_token(&self, token: String) -> Result<AuthenticatedUser, Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct LoginRequestDTO {
+    pub document: String,
+    pub password: String,
+    /// Store the customer signs in to, missing for customers registered before stores existed.
+    pub store_id: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct LoginResponseDTO {
+    pub token: String,
+    pub token_type: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Customer identified by a user token, loaded again by the token subject on every request and
+/// available to the handlers behind the customer layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedUser {
+    pub id: String,
+    pub document: String,
+    pub store_id: Option<String>,
+}
+
+impl AuthenticatedUser {
+    /// Customers registered before stores existed are not bound to any, they still only reach
+    /// their own document.
+    pub fn get_tenant(&self) -> Tenant {
+        match &self.store_id {
+            Some(store_id) => Tenant::Store(store_id.clone()),
+            None => Tenant::Platform
+        }
+    }
+}
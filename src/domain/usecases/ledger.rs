@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{entities::Tenant, error::Error, types::money::Money};
+
+pub const INVALID_ADJUSTMENT_ERROR: u8 = 20;
+
+#[async_trait]
+pub trait LedgerUseCase {
+    async fn adjust(&self, tenant: &Tenant, document: &str, dto: AdjustmentRequestDTO) -> Result<AdjustmentResponseDTO, Error>;
+    /// Store staff only reconcile the accounts opened in their store.
+    async fn reconcile(&self, tenant: &Tenant) -> Result<ReconciliationResponseDTO, Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AdjustmentRequestDTO {
+    pub amount: Money,
+    pub description: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AdjustmentResponseDTO {
+    pub id: String,
+    pub amount: Money,
+    pub balance: Money,
+}
+
+/// Cached balance of an account next to the receivable totals of its ledger entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceSnapshot {
+    pub account_id: String,
+    pub cached_balance: Money,
+    pub receivable_debits: Money,
+    pub receivable_credits: Money,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct BalanceDriftDTO {
+    pub account_id: String,
+    pub cached_balance: Money,
+    pub ledger_balance: Money,
+    pub drift: Money,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReconciliationResponseDTO {
+    pub checked_accounts: usize,
+    pub drifts: Vec<BalanceDriftDTO>,
+}
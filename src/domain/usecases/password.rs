@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{entities::Tenant, error::Error};
+
+pub const INVALID_RESET_TOKEN_ERROR: u8 = 25;
+pub const INVALID_PASSWORD_ERROR: u8 = 26;
+
+/// bcrypt ignores whatever comes after the 72nd byte.
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const MAX_PASSWORD_LENGTH: usize = 72;
+
+#[async_trait]
+pub trait PasswordUseCase {
+    async fn change(&self, tenant: &Tenant, document: &str, dto: PasswordChangeRequestDTO) -> Result<(), Error>;
+    /// Issues a reset token for the user, invalidating the ones issued before.
+    async fn create_reset_token(&self, tenant: &Tenant, document: &str) -> Result<PasswordResetTokenResponseDTO, Error>;
+    async fn reset(&self, document: &str, dto: PasswordResetRequestDTO) -> Result<(), Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PasswordChangeRequestDTO {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PasswordResetRequestDTO {
+    pub token: String,
+    pub new_password: String,
+    /// Store of the customer, missing for customers registered before stores existed.
+    pub store_id: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PasswordResetTokenResponseDTO {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub fn check_password(password: &str) -> Result<(), Error> {
+    if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&password.len()) {
+        return Err(Error::new_business(INVALID_PASSWORD_ERROR));
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{PaymentAllocation, Tenant},
+    error::Error,
+    types::money::Money
+};
+
+pub const INVALID_PAYMENT_METHOD_ERROR: u8 = 18;
+pub const PAYMENT_CONFLICT_ERROR: u8 = 42;
+
+#[async_trait]
+pub trait PaymentUseCase {
+    /// `operator` is recorded as the one who took the payment.
+    async fn register(&self, tenant: &Tenant, operator: &str, document: &str, dto: PaymentCreateRequestDTO) -> Result<PaymentResponseDTO, Error>;
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PaymentCreateRequestDTO {
+    pub amount: Money,
+    pub method: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PaymentResponseDTO {
+    pub id: String,
+    pub amount: Money,
+    pub allocations: Vec<PaymentAllocation>,
+    pub carried_credit: Money,
+    pub balance: Money,
+}
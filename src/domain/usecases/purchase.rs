@@ -0,0 +1,90 @@
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{Installment, InstallmentStatus, Tenant, MAX_INSTALLMENTS},
+    error::Error,
+    types::{money::Money, billing_cycle::BillingCycle}
+};
+
+pub const INVALID_AMOUNT_ERROR: u8 = 15;
+pub const CREDIT_LIMIT_EXCEEDED_ERROR: u8 = 16;
+pub const INVALID_DESCRIPTION_ERROR: u8 = 17;
+pub const INVALID_INSTALLMENTS_ERROR: u8 = 23;
+
+#[async_trait]
+pub trait PurchaseUseCase {
+    async fn register(&self, tenant: &Tenant, document: &str, dto: PurchaseCreateRequestDTO) -> Result<PurchaseResponseDTO, Error>;
+}
+
+/// Purchases are always dated by the server, they can't be backdated into closed cycles or past due dates.
+#[derive(Deserialize, Clone)]
+pub struct PurchaseCreateRequestDTO {
+    pub amount: Money,
+    pub description: String,
+    pub merchant_reference: String,
+    pub installments: Option<u32>,
+    pub due_dates: Option<Vec<NaiveDate>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PurchaseResponseDTO {
+    pub id: String,
+    pub amount: Money,
+    pub installments: Vec<InstallmentResponseDTO>,
+    pub balance: Money,
+    pub available_credit: Money,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct InstallmentResponseDTO {
+    pub id: String,
+    pub number: u32,
+    pub amount: Money,
+    pub paid_amount: Money,
+    pub due_date: NaiveDate,
+    pub status: InstallmentStatus,
+}
+
+impl InstallmentResponseDTO {
+    pub fn from_installment(installment: &Installment, reference_date: NaiveDate) -> Self {
+        InstallmentResponseDTO {
+            id: String::from(installment.get_id()),
+            number: installment.get_number(),
+            amount: installment.get_amount(),
+            paid_amount: installment.get_paid_amount(),
+            due_date: installment.get_due_date(),
+            status: installment.get_status(reference_date)
+        }
+    }
+}
+
+/// Due dates of the installments of a purchase. Explicit due dates must be in
+/// ascending order and not before the purchase, otherwise the first installment is
+/// due with the purchase's billing cycle and the next ones a month apart.
+pub fn installment_due_dates(cycle: BillingCycle, purchased_on: NaiveDate, installments: Option<u32>, due_dates: Option<Vec<NaiveDate>>) -> Result<Vec<NaiveDate>, Error> {
+    if let Some(due_dates) = due_dates {
+        let count_matches = installments.is_none_or(|n| n as usize == due_dates.len());
+        let in_order = due_dates.windows(2).all(|w| w[0] < w[1]);
+        if due_dates.is_empty() || due_dates.len() > MAX_INSTALLMENTS as usize || !count_matches || !in_order || due_dates[0] < purchased_on {
+            return Err(Error::new_business(INVALID_INSTALLMENTS_ERROR));
+        }
+        return Ok(due_dates);
+    }
+
+    let count = installments.unwrap_or(1);
+    if !(1..=MAX_INSTALLMENTS).contains(&count) {
+        return Err(Error::new_business(INVALID_INSTALLMENTS_ERROR));
+    }
+
+    let first = cycle.due_date_for_purchase(purchased_on);
+    let mut due_dates: Vec<NaiveDate> = Vec::with_capacity(count as usize);
+    for n in 0..count {
+        match first.checked_add_months(Months::new(n)) {
+            Some(d) => due_dates.push(d),
+            None => return Err(Error::new_business(INVALID_INSTALLMENTS_ERROR))
+        }
+    }
+    Ok(due_dates)
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{CreditAccount, Statement, StatementLine, Tenant, TransactionKind},
+    error::Error,
+    types::money::Money
+};
+
+pub const STATEMENT_NOT_FOUND: u8 = 22;
+
+#[async_trait]
+pub trait StatementUseCase {
+    /// Generates the statements of every cycle closed on or before `reference_date`
+    /// that has not been billed yet, returning how many were generated.
+    async fn close_cycles(&self, reference_date: NaiveDate) -> Result<usize, Error>;
+    async fn list(&self, tenant: &Tenant, document: &str) -> Result<Vec<StatementResponseDTO>, Error>;
+    /// Store staff only reach the statements of accounts opened in their store.
+    async fn get(&self, tenant: &Tenant, id: &str) -> Result<StatementResponseDTO, Error>;
+}
+
+/// An open account with the closing date and balance of its last statement, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BillingState {
+    pub account: CreditAccount,
+    pub last_closing_date: Option<NaiveDate>,
+    pub last_closing_balance: Money,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StatementResponseDTO {
+    pub id: String,
+    pub account_id: String,
+    pub period_start: NaiveDate,
+    pub closing_date: NaiveDate,
+    pub due_date: NaiveDate,
+    pub opening_balance: Money,
+    pub purchases: Money,
+    pub payments: Money,
+    pub fees: Money,
+    pub adjustments: Money,
+    pub closing_balance: Money,
+    pub amount_due: Money,
+    pub lines: Vec<StatementLine>,
+    pub created_at: DateTime<Utc>
+}
+
+impl StatementResponseDTO {
+    pub fn from_statement(statement: Statement) -> Result<Self, Error> {
+        let total = |kind: TransactionKind| match statement.get_total(kind) {
+            Some(t) => Ok(t),
+            None => Err(Error::new_internal("statement total overflow"))
+        };
+
+        Ok(StatementResponseDTO {
+            id: String::from(statement.get_id()),
+            account_id: String::from(statement.get_account_id()),
+            period_start: statement.get_period_start(),
+            closing_date: statement.get_closing_date(),
+            due_date: statement.get_due_date(),
+            opening_balance: statement.get_opening_balance(),
+            purchases: total(TransactionKind::Purchase)?,
+            payments: total(TransactionKind::Payment)?,
+            fees: total(TransactionKind::Fee)?,
+            adjustments: total(TransactionKind::Adjustment)?,
+            closing_balance: statement.get_closing_balance(),
+            amount_due: statement.get_amount_due(),
+            lines: statement.get_lines().to_vec(),
+            created_at: statement.get_created_at()
+        })
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{Utc, DateTime};
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{FeePolicy, Store},
+    error::Error, types::cnpj::CNPJ
+};
+
+pub const INVALID_CNPJ_ERROR: u8 = 30;
+pub const STORE_ALREADY_EXISTS: u8 = 31;
+pub const STORE_NOT_FOUND: u8 = 32;
+pub const STORE_IN_USE_ERROR: u8 = 33;
+pub const INVALID_STORE_NAME_ERROR: u8 = 34;
+pub const INVALID_FEE_POLICY_ERROR: u8 = 43;
+
+#[async_trait]
+pub trait StoreUseCase {
+    async fn create(&self, dto: StoreCreateRequestDTO) -> Result<StoreResponseDTO, Error>;
+    async fn update(&self, id: &str, dto: StoreUpdateRequestDTO) -> Result<(), Error>;
+    async fn get(&self, id: &str) -> Result<StoreResponseDTO, Error>;
+    async fn list(&self) -> Result<Vec<StoreResponseDTO>, Error>;
+    /// Stores extending credit to any account cannot be deleted.
+    async fn delete(&self, id: &str) -> Result<(), Error>;
+}
+
+/// Fee rates are given in basis points, both or none. Stores without them charge the platform default.
+#[derive(Deserialize, Clone)]
+pub struct StoreCreateRequestDTO {
+    pub name: String,
+    pub document: String,
+    #[serde(default)]
+    pub late_fee_bps: Option<u32>,
+    #[serde(default)]
+    pub monthly_interest_bps: Option<u32>,
+}
+
+impl StoreCreateRequestDTO {
+    pub fn to_store(self) -> Result<Store, Error> {
+        let name = check_store_name(&self.name)?;
+        let fee_policy = get_fee_policy(self.late_fee_bps, self.monthly_interest_bps)?;
+        match CNPJ::from_string(self.document) {
+            Ok(document) if document.is_valid() => {
+                let mut store = Store::new(name, document);
+                store.set_fee_policy(fee_policy);
+                Ok(store)
+            },
+            _ => Err(Error::new_business(INVALID_CNPJ_ERROR))
+        }
+    }
+}
+
+/// Replaces the name and fee rates, leaving the rates out goes back to the platform default.
+#[derive(Deserialize, Clone)]
+pub struct StoreUpdateRequestDTO {
+    pub name: String,
+    #[serde(default)]
+    pub late_fee_bps: Option<u32>,
+    #[serde(default)]
+    pub monthly_interest_bps: Option<u32>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct StoreResponseDTO {
+    pub id: String,
+    pub name: String,
+    pub document: CNPJ,
+    pub late_fee_bps: Option<u32>,
+    pub monthly_interest_bps: Option<u32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>
+}
+
+impl StoreResponseDTO {
+    pub fn from_store(store: &Store) -> Self {
+        StoreResponseDTO {
+            id: String::from(store.get_id()),
+            name: String::from(store.get_name()),
+            document: *store.get_document(),
+            late_fee_bps: store.get_fee_policy().map(|p| p.get_late_fee_bps()),
+            monthly_interest_bps: store.get_fee_policy().map(|p| p.get_monthly_interest_bps()),
+            created_at: store.get_created_at(),
+            updated_at: store.get_updated_at()
+        }
+    }
+}
+
+pub fn check_store_name(name: &str) -> Result<String, Error> {
+    match name.trim() {
+        "" => Err(Error::new_business(INVALID_STORE_NAME_ERROR)),
+        name => Ok(String::from(name))
+    }
+}
+
+/// Rates above the legal limits are refused, see `FeePolicy::new`.
+pub fn get_fee_policy(late_fee_bps: Option<u32>, monthly_interest_bps: Option<u32>) -> Result<Option<FeePolicy>, Error> {
+    match (late_fee_bps, monthly_interest_bps) {
+        (None, None) => Ok(None),
+        (Some(late_fee_bps), Some(monthly_interest_bps)) => match FeePolicy::new(late_fee_bps, monthly_interest_bps) {
+            Ok(p) => Ok(Some(p)),
+            Err(e) => Err(Error::new_business_with_message(INVALID_FEE_POLICY_ERROR, &e))
+        },
+        _ => Err(Error::new_business_with_message(INVALID_FEE_POLICY_ERROR, "late fee and monthly interest must be set together"))
+    }
+}
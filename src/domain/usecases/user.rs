@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use async_trait::async_trait;
 
 use crate::domain::{
-    entities::{User, UserStatus},
+    entities::{User, UserStatus, Tenant},
     error::Error, types::{cpf::CPF, birth_date::BirthDate, }
 };
 
@@ -11,13 +11,44 @@ pub const INVALID_DOCUMENT_ERROR: u8 = 1;
 pub const UNDERAGE_ERROR: u8 = 2;
 pub const USER_ALREADY_EXISTS: u8 = 3;
 pub const USER_NOT_FOUND: u8 = 4;
+pub const USER_BLOCKED_ERROR: u8 = 8;
+pub const USER_DELETED_ERROR: u8 = 9;
+pub const INVALID_CURSOR_ERROR: u8 = 36;
+pub const INVALID_USER_FILTER_ERROR: u8 = 37;
+pub const INVALID_SEARCH_QUERY_ERROR: u8 = 38;
+pub const INVALID_STATUS_CHANGE_REASON_ERROR: u8 = 39;
+pub const USER_NOT_BLOCKED_ERROR: u8 = 40;
+pub const INVALID_NAME_ERROR: u8 = 41;
+/// The document belongs to customers of several stores, the caller has to act as one of them.
+pub const USER_STORE_REQUIRED_ERROR: u8 = 46;
+
+/// Names are compared by trigrams, shorter queries match nearly everyone.
+pub const MIN_SEARCH_QUERY_LENGTH: usize = 3;
+
+pub const MAX_STATUS_CHANGE_NOTE_LENGTH: usize = 255;
+
+pub const DEFAULT_USER_PAGE_SIZE: u32 = 20;
+pub const MAX_USER_PAGE_SIZE: u32 = 100;
 
 #[async_trait]
 pub trait UserUseCase {
-    async fn create(&self, dto: UserCreateRequestDTO) -> Result<(), Error>;
-    async fn update(&self, dto: UserUpdateRequestDTO) -> Result<(), Error>;
-    async fn get(&self, document: &str) -> Result<PublicUserResponseDTO, Error>;
-    async fn delete(&self, document: &str) -> Result<(), Error>;
+    /// Customers created by store staff belong to their store, admins may pick one.
+    async fn create(&self, tenant: &Tenant, dto: UserCreateRequestDTO) -> Result<(), Error>;
+    async fn update(&self, tenant: &Tenant, dto: UserUpdateRequestDTO) -> Result<(), Error>;
+    /// Changes only the fields sent, the password and status are kept as they are.
+    async fn patch(&self, tenant: &Tenant, document: &str, dto: UserPatchRequestDTO) -> Result<PublicUserResponseDTO, Error>;
+    async fn get(&self, tenant: &Tenant, document: &str) -> Result<PublicUserResponseDTO, Error>;
+    /// Deleted users are kept and left out of every read until restored.
+    async fn delete(&self, tenant: &Tenant, document: &str) -> Result<(), Error>;
+    /// Brings the user back with the status they had when deleted, `actor` is recorded as the one who restored them.
+    async fn restore(&self, tenant: &Tenant, actor: &str, document: &str) -> Result<(), Error>;
+    /// Pages through the customers matching the query, newest first unless sorted otherwise.
+    async fn list(&self, tenant: &Tenant, query: UserListQueryDTO, mask_documents: bool) -> Result<UserListResponseDTO, Error>;
+    /// Best matches first, ignoring accents and small typos in the name.
+    async fn search(&self, tenant: &Tenant, query: UserSearchQueryDTO, mask_documents: bool) -> Result<Vec<PublicUserResponseDTO>, Error>;
+    /// Blocked customers are refused new credit until unblocked, `actor` is recorded as the one who blocked them.
+    async fn block(&self, tenant: &Tenant, actor: &str, document: &str, dto: UserStatusChangeRequestDTO) -> Result<(), Error>;
+    async fn unblock(&self, tenant: &Tenant, actor: &str, document: &str, dto: UserStatusChangeRequestDTO) -> Result<(), Error>;
 }
     
 #[derive(Deserialize, Clone)]
@@ -26,12 +57,16 @@ pub struct UserCreateRequestDTO {
     pub document: String,
     pub birth_date: NaiveDate,
     pub password: String,
+    #[serde(default)]
+    pub store_id: Option<String>,
 }
 
 impl UserCreateRequestDTO {
     pub fn to_user(self) -> Result<User, Error>{
         if let Ok(document) = CPF::from_string(self.document) {
-            return Ok(User::new(self.name, document, BirthDate::from_naive(self.birth_date)));
+            let mut user = User::new(self.name, document, BirthDate::from_naive(self.birth_date));
+            user.set_store_id(self.store_id);
+            return Ok(user);
         }
         Err(Error::new_business(INVALID_DOCUMENT_ERROR))
     }
@@ -55,11 +90,26 @@ impl UserUpdateRequestDTO {
     }
 }
 
+#[derive(Deserialize, Clone, Default)]
+pub struct UserPatchRequestDTO {
+    pub name: Option<String>,
+    pub document: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UserStatusChangeRequestDTO {
+    /// One of OVERDUE_DEBT, FRAUD, CUSTOMER_REQUEST, DEBT_SETTLED or OTHER.
+    pub reason: String,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct PublicUserResponseDTO {
 	pub id: String ,
 	pub name: String,
-	pub document: CPF,
+	pub document: String,
 	pub status: UserStatus,
 	pub birth_date: BirthDate,
 	pub created_at: DateTime<Utc>,
@@ -70,11 +120,54 @@ impl PublicUserResponseDTO {
         PublicUserResponseDTO { 
             id: String::from(user.get_id()),
             name: String::from(user.get_name()),
-            document: *user.get_document(), 
+            document: user.get_document().to_string(), 
             status: user.get_status(), 
             birth_date: user.get_birth_date(), 
             created_at: user.get_created_at(),
             updated_at: user.get_updated_at()
         }
     }
-}
\ No newline at end of file
+
+    /// Store staff only get the middle digits of the CPF, enough to tell namesakes apart.
+    pub fn from_user_masked(user: User) -> Self {
+        let document = user.get_document().to_masked_string();
+        PublicUserResponseDTO { document, ..Self::from_user(user) }
+    }
+}
+
+/// Filters of the customer listing, ranges are inclusive and `name` matches a prefix
+/// ignoring case. `sort` takes `created_at` or `name`, prefixed with `-` for descending order.
+#[derive(Deserialize, Clone, Default)]
+pub struct UserListQueryDTO {
+    pub status: Option<String>,
+    pub name: Option<String>,
+    pub birth_date_from: Option<NaiveDate>,
+    pub birth_date_to: Option<NaiveDate>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub sort: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct UserSearchQueryDTO {
+    pub q: String,
+    pub limit: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct UserListResponseDTO {
+    pub items: Vec<PublicUserResponseDTO>,
+    /// Passed back as `cursor` to fetch the next page, missing on the last one.
+    pub next_cursor: Option<String>,
+}
+
+pub fn check_user_status(status: UserStatus) -> Result<(), Error> {
+    match status {
+        UserStatus::Active => Ok(()),
+        UserStatus::Blocked => Err(Error::new_business(USER_BLOCKED_ERROR)),
+        UserStatus::Deleted => Err(Error::new_business(USER_DELETED_ERROR)),
+        UserStatus::Unknown => Err(Error::new_internal("user with unknown status"))
+    }
+}
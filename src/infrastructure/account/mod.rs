@@ -0,0 +1,206 @@
+use std::str::FromStr;
+use chrono::NaiveDateTime;
+use sqlx::Row;
+use sqlx::postgres::{PgQueryResult, PgRow};
+use sqlx::{Pool, Postgres, Transaction};
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{CreditAccount, AccountStatus, Tenant};
+use crate::data::usecases::account::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::types::{money::Money, billing_cycle::BillingCycle};
+use crate::domain::usecases::account::{ACCOUNT_ALREADY_EXISTS, ACCOUNT_NOT_FOUND};
+use crate::infrastructure::tenant;
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    /// Every query runs in a transaction scoped to the tenant, see the account row level security policy.
+    async fn begin(&self, tenant: &Tenant) -> Result<Transaction<'static, Postgres>, Error> {
+        match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => Ok(tx),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn commit(tx: Transaction<'static, Postgres>) -> Result<(), Error> {
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    fn handle_postgres_error(error: sqlx::Error) -> Error {
+        let raw_error_message: &str = &error.to_string();
+        if let sqlx::Error::Database(dbe) = error {
+            if dbe.is_unique_violation() {
+                return Error::new_already_exists(ACCOUNT_ALREADY_EXISTS, "account");
+            }
+        }
+        Error::new_internal(raw_error_message)
+    }
+
+    fn handle_update_result(res: PgQueryResult) -> Result<(), Error> {
+        if res.rows_affected() == 0 {
+            return Err(Error::new_not_found(ACCOUNT_NOT_FOUND, "account"));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_account_from_pg_row(row: PgRow) -> Result<CreditAccount, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let user_id: Uuid = row.try_get("user_id")?;
+        let store_id: Option<Uuid> = row.try_get("store_id")?;
+        let balance: i64 = row.try_get("balance")?;
+        let credit_limit: i64 = row.try_get("credit_limit")?;
+        let status: &str = row.try_get("status")?;
+        let closing_day: i16 = row.try_get("closing_day")?;
+        let due_day: i16 = row.try_get("due_day")?;
+        let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+        let db_updated_at: NaiveDateTime = row.try_get("updated_at")?;
+
+        let mut account = CreditAccount::new(user_id.to_string(), Money::from_cents(credit_limit));
+        account.set_uuid(id.to_string());
+        account.set_store_id(store_id.map(|id| id.to_string()));
+        account.set_balance(Money::from_cents(balance));
+        account.set_status(AccountStatus::from_string(status));
+        match BillingCycle::new(closing_day as u32, due_day as u32) {
+            Ok(cycle) => account.set_billing_cycle(cycle),
+            Err(e) => return Err(sqlx::Error::Decode(e.into()))
+        }
+        account.set_created_at(db_created_at.and_utc());
+        account.set_updated_at(db_updated_at.and_utc());
+        Ok(account)
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create(&self, tenant: &Tenant, account: CreditAccount) -> Result<(), Error> {
+        let id = match Uuid::from_str(account.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let user_id = match Uuid::from_str(account.get_user_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let store_id = match account.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(err)) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                INSERT INTO account (
+                    id,
+                    user_id,
+                    store_id,
+                    balance,
+                    credit_limit,
+                    status,
+                    closing_day,
+                    due_day,
+                    created_at,
+                    updated_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#
+        ).bind(id)
+        .bind(user_id)
+        .bind(store_id)
+        .bind(account.get_balance().to_cents())
+        .bind(account.get_credit_limit().to_cents())
+        .bind(account.get_status().to_string())
+        .bind(account.get_billing_cycle().get_closing_day() as i16)
+        .bind(account.get_billing_cycle().get_due_day() as i16)
+        .bind(account.get_created_at())
+        .bind(account.get_updated_at())
+        .execute(&mut *tx).await;
+
+        match result {
+            Ok(_) => Self::commit(tx).await,
+            Err(err) => Err(Self::handle_postgres_error(err))
+        }
+    }
+
+    async fn update(&self, tenant: &Tenant, account: CreditAccount) -> Result<(), Error> {
+        let id = match Uuid::from_str(account.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                UPDATE account SET
+                    credit_limit = $1,
+                    status = $2,
+                    closing_day = $3,
+                    due_day = $4,
+                    updated_at = $5
+                WHERE
+                    id = $6
+            "#
+        ).bind(account.get_credit_limit().to_cents())
+        .bind(account.get_status().to_string())
+        .bind(account.get_billing_cycle().get_closing_day() as i16)
+        .bind(account.get_billing_cycle().get_due_day() as i16)
+        .bind(account.get_updated_at())
+        .bind(id)
+        .execute(&mut *tx).await;
+
+        match result {
+            Err(e) => return Err(Self::handle_postgres_error(e)),
+            Ok(r) => Self::handle_update_result(r)?
+        };
+        Self::commit(tx).await
+    }
+
+    async fn get_by_user_id(&self, tenant: &Tenant, user_id: &str) -> Result<CreditAccount, Error> {
+        let user_id = match Uuid::from_str(user_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    user_id,
+                    store_id,
+                    balance,
+                    credit_limit,
+                    status,
+                    closing_day,
+                    due_day,
+                    created_at,
+                    updated_at
+                FROM account
+                WHERE user_id = $1
+            "#
+        ).bind(user_id).fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(ACCOUNT_NOT_FOUND, "account")),
+            Ok(Some(r)) => r
+        };
+        Self::commit(tx).await?;
+
+        match Self::get_account_from_pg_row(row) {
+            Ok(a) => Ok(a),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
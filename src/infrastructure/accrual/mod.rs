@@ -0,0 +1,237 @@
+use std::str::FromStr;
+use chrono::{NaiveDate, Utc};
+use sqlx::{Pool, Postgres, Row};
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{Fee, FeePolicy, Installment, LedgerTransaction, Tenant};
+use crate::data::usecases::accrual::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::infrastructure::purchase::PostgresRepository as PurchasePostgresRepository;
+use crate::infrastructure::{ledger, tenant};
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn get_overdue_installments(&self, tenant: &Tenant, reference_date: NaiveDate) -> Result<Vec<(Installment, Option<FeePolicy>)>, Error> {
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    i.id,
+                    i.purchase_id,
+                    i.account_id,
+                    i.number,
+                    i.amount,
+                    i.paid_amount,
+                    i.due_date,
+                    i.created_at,
+                    s.late_fee_bps,
+                    s.monthly_interest_bps
+                FROM installment i
+                JOIN account a ON a.id = i.account_id
+                LEFT JOIN store s ON s.id = a.store_id
+                WHERE
+                    a.status = 'OPEN'
+                    AND i.paid_amount < i.amount
+                    AND i.due_date < $1
+                ORDER BY i.account_id, i.due_date, i.number
+            "#
+        ).bind(reference_date).fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let mut installments: Vec<(Installment, Option<FeePolicy>)> = Vec::with_capacity(rows.len());
+        for row in rows {
+            let rates: (Option<i32>, Option<i32>) = match (row.try_get("late_fee_bps"), row.try_get("monthly_interest_bps")) {
+                (Ok(late_fee_bps), Ok(monthly_interest_bps)) => (late_fee_bps, monthly_interest_bps),
+                (Err(e), _) | (_, Err(e)) => return Err(Error::new_internal(&e.to_string()))
+            };
+
+            let policy = match rates {
+                (Some(late_fee_bps), Some(monthly_interest_bps)) => match FeePolicy::new(late_fee_bps as u32, monthly_interest_bps as u32) {
+                    Ok(p) => Some(p),
+                    Err(e) => return Err(Error::new_internal(&e))
+                },
+                _ => None
+            };
+
+            match PurchasePostgresRepository::get_installment_from_pg_row(row) {
+                Ok(i) => installments.push((i, policy)),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(installments)
+    }
+
+    async fn get_charged_keys(&self, tenant: &Tenant, keys: Vec<String>) -> Result<Vec<String>, Error> {
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query("SELECT idempotency_key FROM fee WHERE idempotency_key = ANY($1)")
+            .bind(keys)
+            .fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let mut charged: Vec<String> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match row.try_get("idempotency_key") {
+                Ok(k) => charged.push(k),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(charged)
+    }
+
+    async fn create(&self, tenant: &Tenant, fee: Fee, transaction: LedgerTransaction) -> Result<bool, Error> {
+        let id = match Uuid::from_str(fee.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let account_id = match Uuid::from_str(fee.get_account_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let installment_id = match fee.get_installment_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(err)) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        // the unique idempotency key makes concurrent runs charge each fee once
+        let result = sqlx::query(
+            r#"
+                INSERT INTO fee (
+                    id,
+                    account_id,
+                    installment_id,
+                    kind,
+                    period,
+                    amount,
+                    idempotency_key,
+                    created_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (idempotency_key) DO NOTHING
+            "#
+        ).bind(id)
+        .bind(account_id)
+        .bind(installment_id)
+        .bind(fee.get_kind().to_string())
+        .bind(fee.get_period())
+        .bind(fee.get_amount().to_cents())
+        .bind(fee.get_idempotency_key())
+        .bind(fee.get_created_at())
+        .execute(&mut *tx).await;
+
+        match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) if r.rows_affected() == 0 => return Ok(false),
+            Ok(_) => ()
+        }
+
+        ledger::post(&mut tx, &transaction).await?;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE account SET
+                    balance = balance + $1,
+                    updated_at = $2
+                WHERE
+                    id = $3
+            "#
+        ).bind(fee.get_amount().to_cents())
+        .bind(Utc::now())
+        .bind(account_id)
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(true),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_last_reference_date(&self, tenant: &Tenant) -> Result<Option<NaiveDate>, Error> {
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query("SELECT max(reference_date) AS reference_date FROM accrual_run")
+            .fetch_one(&mut *tx).await;
+
+        let row = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        match row.try_get("reference_date") {
+            Ok(d) => Ok(d),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn record_run(&self, tenant: &Tenant, reference_date: NaiveDate) -> Result<(), Error> {
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query("INSERT INTO accrual_run (reference_date, created_at) VALUES ($1, $2) ON CONFLICT (reference_date) DO NOTHING")
+            .bind(reference_date)
+            .bind(Utc::now())
+            .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
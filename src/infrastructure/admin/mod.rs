@@ -0,0 +1,228 @@
+use std::str::FromStr;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::{Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{RefreshToken, Role};
+use crate::data::usecases::admin::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::usecases::admin::INVALID_TOKEN_ERROR;
+
+/// Refresh tokens are looked up by the token the caller sends, before its store is known, so
+/// sessions are platform level data kept out of row level security. Their store is only copied
+/// into the access tokens they issue, which scope every request.
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    fn get_refresh_token_from_pg_row(row: PgRow) -> Result<RefreshToken, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let family_id: Uuid = row.try_get("family_id")?;
+        let subject: String = row.try_get("subject")?;
+        let role: &str = row.try_get("role")?;
+        let store_id: Option<Uuid> = row.try_get("store_id")?;
+        let token_hash: String = row.try_get("token_hash")?;
+        let expires_at: NaiveDateTime = row.try_get("expires_at")?;
+        let revoked_at: Option<NaiveDateTime> = row.try_get("revoked_at")?;
+        let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+
+        let mut token = RefreshToken::new(family_id.to_string(), subject, Role::from_string(role), token_hash, expires_at.and_utc());
+        token.set_uuid(id.to_string());
+        token.set_store_id(store_id.map(|id| id.to_string()));
+        token.set_revoked_at(revoked_at.map(|r| r.and_utc()));
+        token.set_created_at(db_created_at.and_utc());
+        Ok(token)
+    }
+
+    async fn insert_refresh_token<'c, E>(executor: E, token: &RefreshToken) -> Result<(), Error>
+        where E: sqlx::Executor<'c, Database = Postgres>
+    {
+        let id = match Uuid::from_str(token.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let family_id = match Uuid::from_str(token.get_family_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let store_id = match token.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(err)) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO refresh_token (
+                    id,
+                    family_id,
+                    subject,
+                    role,
+                    store_id,
+                    token_hash,
+                    expires_at,
+                    revoked_at,
+                    created_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#
+        ).bind(id)
+        .bind(family_id)
+        .bind(token.get_subject())
+        .bind(token.get_role().to_string())
+        .bind(store_id)
+        .bind(token.get_token_hash())
+        .bind(token.get_expires_at())
+        .bind(token.get_revoked_at())
+        .bind(token.get_created_at())
+        .execute(executor).await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create_refresh_token(&self, token: RefreshToken) -> Result<(), Error> {
+        PostgresRepository::insert_refresh_token(&self.pool, &token).await
+    }
+
+    async fn get_refresh_token(&self, id: &str) -> Result<RefreshToken, Error> {
+        // the id comes from the token sent by the caller
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(INVALID_TOKEN_ERROR, "refresh token"))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    family_id,
+                    subject,
+                    role,
+                    store_id,
+                    token_hash,
+                    expires_at,
+                    revoked_at,
+                    created_at
+                FROM refresh_token
+                WHERE id = $1
+            "#
+        ).bind(id).fetch_optional(&self.pool).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(INVALID_TOKEN_ERROR, "refresh token")),
+            Ok(Some(r)) => r
+        };
+
+        match PostgresRepository::get_refresh_token_from_pg_row(row) {
+            Ok(t) => Ok(t),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn rotate_refresh_token(&self, id: &str, replacement: RefreshToken) -> Result<bool, Error> {
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match self.pool.begin().await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                UPDATE refresh_token SET
+                    revoked_at = $1
+                WHERE
+                    id = $2
+                    AND revoked_at IS NULL
+            "#
+        ).bind(Utc::now())
+        .bind(id)
+        .execute(&mut *tx).await;
+
+        match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) if r.rows_affected() == 0 => return Ok(false),
+            Ok(_) => ()
+        }
+
+        PostgresRepository::insert_refresh_token(&mut *tx, &replacement).await?;
+
+        match tx.commit().await {
+            Ok(()) => Ok(true),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn revoke_refresh_token_family(&self, family_id: &str) -> Result<(), Error> {
+        let family_id = match Uuid::from_str(family_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                UPDATE refresh_token SET
+                    revoked_at = $1
+                WHERE
+                    family_id = $2
+                    AND revoked_at IS NULL
+            "#
+        ).bind(Utc::now())
+        .bind(family_id)
+        .execute(&self.pool).await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn revoke_access_token(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), Error> {
+        let result = sqlx::query(
+            r#"
+                INSERT INTO revoked_token (
+                    jti,
+                    expires_at,
+                    revoked_at
+                ) VALUES ($1, $2, $3)
+                ON CONFLICT (jti) DO NOTHING
+            "#
+        ).bind(jti)
+        .bind(expires_at)
+        .bind(Utc::now())
+        .execute(&self.pool).await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn is_access_token_revoked(&self, jti: &str) -> Result<bool, Error> {
+        let result = sqlx::query("SELECT 1 FROM revoked_token WHERE jti = $1")
+            .bind(jti)
+            .fetch_optional(&self.pool).await;
+
+        match result {
+            Ok(row) => Ok(row.is_some()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::str::FromStr;
+use chrono::{NaiveDateTime, Utc};
+use sqlx::{Pool, Postgres, Row, Transaction};
+use sqlx::postgres::PgRow;
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{ApiKey, Permission, Tenant};
+use crate::data::usecases::api_key::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::usecases::api_key::API_KEY_NOT_FOUND_ERROR;
+use crate::infrastructure::tenant;
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    /// Every query runs in a transaction scoped to the tenant, see the api key row level security policy.
+    async fn begin(&self, tenant: &Tenant) -> Result<Transaction<'static, Postgres>, Error> {
+        match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => Ok(tx),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn commit(tx: Transaction<'static, Postgres>) -> Result<(), Error> {
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    fn get_api_key_from_pg_row(row: PgRow) -> Result<ApiKey, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let prefix: String = row.try_get("prefix")?;
+        let secret_hash: String = row.try_get("secret_hash")?;
+        let name: String = row.try_get("name")?;
+        let store_id: Uuid = row.try_get("store_id")?;
+        let permissions: Vec<String> = row.try_get("permissions")?;
+        let revoked_at: Option<NaiveDateTime> = row.try_get("revoked_at")?;
+        let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+
+        let permissions = permissions.iter()
+            .map(|p| Permission::from_string(p))
+            .filter(|p| *p != Permission::Unknown)
+            .collect();
+
+        let mut api_key = ApiKey::new(prefix, secret_hash, name, store_id.to_string(), permissions);
+        api_key.set_uuid(id.to_string());
+        api_key.set_revoked_at(revoked_at.map(|r| r.and_utc()));
+        api_key.set_created_at(db_created_at.and_utc());
+        Ok(api_key)
+    }
+
+    fn get_api_keys_from_pg_rows(rows: Vec<PgRow>) -> Result<Vec<ApiKey>, Error> {
+        let mut api_keys: Vec<ApiKey> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match PostgresRepository::get_api_key_from_pg_row(row) {
+                Ok(k) => api_keys.push(k),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(api_keys)
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create_api_key(&self, tenant: &Tenant, api_key: ApiKey) -> Result<(), Error> {
+        let id = match Uuid::from_str(api_key.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let store_id = match Uuid::from_str(api_key.get_store_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let permissions: Vec<String> = api_key.get_permissions().iter().map(|p| String::from(p.to_string())).collect();
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                INSERT INTO api_key (
+                    id,
+                    prefix,
+                    secret_hash,
+                    name,
+                    store_id,
+                    permissions,
+                    revoked_at,
+                    created_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#
+        ).bind(id)
+        .bind(api_key.get_prefix())
+        .bind(api_key.get_secret_hash())
+        .bind(api_key.get_name())
+        .bind(store_id)
+        .bind(permissions)
+        .bind(api_key.get_revoked_at())
+        .bind(api_key.get_created_at())
+        .execute(&mut *tx).await;
+
+        match result {
+            Ok(_) => Self::commit(tx).await,
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_api_key_by_prefix(&self, tenant: &Tenant, prefix: &str) -> Result<ApiKey, Error> {
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    prefix,
+                    secret_hash,
+                    name,
+                    store_id,
+                    permissions,
+                    revoked_at,
+                    created_at
+                FROM api_key
+                WHERE prefix = $1
+            "#
+        ).bind(prefix).fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(API_KEY_NOT_FOUND_ERROR, "api key")),
+            Ok(Some(r)) => r
+        };
+        Self::commit(tx).await?;
+
+        match PostgresRepository::get_api_key_from_pg_row(row) {
+            Ok(k) => Ok(k),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn list_api_keys(&self, tenant: &Tenant) -> Result<Vec<ApiKey>, Error> {
+        // a malformed store id is an unknown store, which has no keys
+        let store_id = match tenant.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(_)) => return Ok(Vec::new())
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    prefix,
+                    secret_hash,
+                    name,
+                    store_id,
+                    permissions,
+                    revoked_at,
+                    created_at
+                FROM api_key
+                WHERE $1::uuid IS NULL OR store_id = $1
+                ORDER BY created_at DESC
+            "#
+        ).bind(store_id).fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(rows) => rows,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+        Self::commit(tx).await?;
+        PostgresRepository::get_api_keys_from_pg_rows(rows)
+    }
+
+    async fn revoke_api_key(&self, tenant: &Tenant, id: &str) -> Result<(), Error> {
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(API_KEY_NOT_FOUND_ERROR, "api key"))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                UPDATE api_key SET
+                    revoked_at = COALESCE(revoked_at, $1)
+                WHERE id = $2
+            "#
+        ).bind(Utc::now().naive_utc())
+        .bind(id)
+        .execute(&mut *tx).await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(Error::new_not_found(API_KEY_NOT_FOUND_ERROR, "api key")),
+            Ok(_) => Self::commit(tx).await,
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
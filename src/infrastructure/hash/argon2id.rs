@@ -0,0 +1,87 @@
+use argon2::{Algorithm, Argon2, Params, Version, PasswordHasher, PasswordVerifier};
+use argon2::password_hash::{self, PasswordHash, Salt, SaltString};
+use ring::rand::{SecureRandom, SystemRandom};
+use crate::data::usecases::user::protocols::hash::Hash;
+use super::{Pepper, PEPPER_SEPARATOR, split_pepper_id, verify_hash};
+
+pub(super) const ARGON2ID_PREFIX: &str = "$argon2id$";
+
+/// Argon2id implementation of `Hash`, peppered the same way as the bcrypt `Hasher`
+/// so either one verifies the hashes of the other.
+#[derive(Clone)]
+pub struct Argon2Hasher {
+    peppers: Vec<Pepper>,
+    params: Params
+}
+
+impl Argon2Hasher {
+    pub fn new(peppers: Vec<Pepper>, memory_in_kib: u32, iterations: u32, parallelism: u32) -> Result<Argon2Hasher, String> {
+        if peppers.is_empty() {
+            return Err(String::from("At least one pepper is required"));
+        }
+
+        match Params::new(memory_in_kib, iterations, parallelism, None) {
+            Ok(params) => Ok(Argon2Hasher { peppers, params }),
+            Err(e) => Err(format!("Invalid argon2 parameters: {}", e))
+        }
+    }
+
+    fn get_current_pepper(&self) -> &Pepper {
+        &self.peppers[0]
+    }
+}
+
+impl Hash for Argon2Hasher {
+    fn run(&self, plain_text: String) -> Result<String, String> {
+        let mut salt = [0u8; Salt::RECOMMENDED_LENGTH];
+        if SystemRandom::new().fill(&mut salt).is_err() {
+            return Err(String::from("error generating salt"));
+        }
+
+        let salt = match SaltString::encode_b64(&salt) {
+            Ok(s) => s,
+            Err(e) => return Err(e.to_string())
+        };
+
+        let pepper = self.get_current_pepper();
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone());
+        match argon2.hash_password(pepper.apply(&plain_text).as_bytes(), &salt) {
+            Ok(hash) => Ok(format!("{}{}{}", pepper.id, PEPPER_SEPARATOR, hash)),
+            Err(e) => Err(e.to_string())
+        }
+    }
+
+    fn verify(&self, plain_text: String, hash: String) -> Result<bool, String> {
+        verify_hash(&self.peppers, plain_text, &hash)
+    }
+
+    fn needs_rehash(&self, hash: String) -> bool {
+        let (id, hash) = split_pepper_id(&hash);
+        if id != Some(self.get_current_pepper().id.as_str()) || !hash.starts_with(ARGON2ID_PREFIX) {
+            return true;
+        }
+
+        match PasswordHash::new(hash).and_then(|h| Params::try_from(&h)) {
+            Ok(params) => {
+                params.m_cost() != self.params.m_cost() ||
+                params.t_cost() != self.params.t_cost() ||
+                params.p_cost() != self.params.p_cost()
+            },
+            Err(_) => true
+        }
+    }
+}
+
+pub(super) fn verify(plain_text: &str, hash: &str) -> Result<bool, String> {
+    let parsed = match PasswordHash::new(hash) {
+        Ok(h) => h,
+        Err(e) => return Err(e.to_string())
+    };
+
+    // the algorithm and its parameters are read from the hash itself
+    match Argon2::default().verify_password(plain_text.as_bytes(), &parsed) {
+        Ok(()) => Ok(true),
+        Err(password_hash::Error::Password) => Ok(false),
+        Err(e) => Err(e.to_string())
+    }
+}
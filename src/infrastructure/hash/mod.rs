@@ -1,61 +1,136 @@
 use bcrypt;
-use rand::{Rng, distributions::Alphanumeric};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use ring::hmac;
 use crate::data::usecases::user::protocols::hash::Hash;
 
+mod argon2id;
+pub use argon2id::Argon2Hasher;
+
+const PEPPER_SEPARATOR: char = ':';
+const BCRYPT_PREFIX: &str = "$2";
+const MIN_PEPPER_LENGTH: usize = 8;
+
+/// Secret mixed into every password before hashing. Its id is stored in front of
+/// the hash so passwords hashed with an old pepper still verify after a rotation.
+#[derive(Clone)]
+pub struct Pepper {
+    id: String,
+    value: String
+}
+
+impl Pepper {
+    pub fn new(id: String, value: String) -> Result<Pepper, String> {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("Pepper id '{}' must be alphanumeric", id));
+        }
+
+        if value.len() < MIN_PEPPER_LENGTH {
+            return Err(format!("Pepper '{}' must have at least {} characters", id, MIN_PEPPER_LENGTH));
+        }
+
+        Ok(Pepper { id, value })
+    }
+
+    /// Parses `id:value` pairs separated by commas, the first one is the current pepper.
+    pub fn parse_list(peppers: &str) -> Result<Vec<Pepper>, String> {
+        let mut parsed: Vec<Pepper> = Vec::new();
+        for entry in peppers.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+            let pepper = match entry.split_once(PEPPER_SEPARATOR) {
+                Some((id, value)) => Pepper::new(String::from(id), String::from(value))?,
+                None => return Err(String::from("Peppers must be given as id:value"))
+            };
+
+            if parsed.iter().any(|p| p.id == pepper.id) {
+                return Err(format!("Pepper id '{}' is repeated", pepper.id));
+            }
+            parsed.push(pepper);
+        }
+        Ok(parsed)
+    }
+
+    fn apply(&self, plain_text: &str) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, self.value.as_bytes());
+        STANDARD.encode(hmac::sign(&key, plain_text.as_bytes()).as_ref())
+    }
+}
+
+/// bcrypt implementation of `Hash`.
+#[derive(Clone)]
 pub struct Hasher {
-    pepper: String,
+    peppers: Vec<Pepper>,
     cost: u32
 }
 
 impl Hash for Hasher {
     fn run(&self, plain_text: String) -> Result<String, String> {
-        let salt_pepper = self.get_salt_with_pepper(); 
-        match bcrypt::hash_with_salt(plain_text, self.cost, salt_pepper) {
-            Ok(hash) =>  Ok(hash.to_string()),
+        let pepper = self.get_current_pepper();
+        match bcrypt::hash(pepper.apply(&plain_text), self.cost) {
+            Ok(hash) => Ok(format!("{}{}{}", pepper.id, PEPPER_SEPARATOR, hash)),
             Err(error) => Err(error.to_string())
         }
     }
 
     fn verify(&self, plain_text: String, hash: String) -> Result<bool, String>{
-        match bcrypt::verify(plain_text, &hash) {
-            Ok(result) => Ok(result),
-            Err(error) => Err(error.to_string())
-        }
+        verify_hash(&self.peppers, plain_text, &hash)
     }
-}
 
+    fn needs_rehash(&self, hash: String) -> bool {
+        let (id, hash) = split_pepper_id(&hash);
+        id != Some(self.get_current_pepper().id.as_str()) ||
+            !hash.starts_with(BCRYPT_PREFIX) ||
+            get_cost(hash) != Some(self.cost)
+    }
+}
 
 impl Hasher {
-    pub fn new(pepper: String, cost: u32) -> Hasher{
-        if pepper.len() < 8 {
-            panic!("Pepper must have at least 8 characters")
+    pub fn new(peppers: Vec<Pepper>, cost: u32) -> Result<Hasher, String> {
+        if peppers.is_empty() {
+            return Err(String::from("At least one pepper is required"));
         }
 
         if !(4..=31).contains(&cost) {
-            panic!("Cost needs to be between 4 and 31")
+            return Err(String::from("Cost needs to be between 4 and 31"));
         }
 
-        Hasher { pepper, cost }
-    }
-
-    fn get_salt_with_pepper(&self) -> [u8;16] {
-        let mut salt: String =  rand::thread_rng()
-            .sample_iter(&Alphanumeric)
-            .take(8)
-            .map(char::from)
-            .collect();
-        salt.push_str(&self.pepper);
-        let mut count: usize = 0;
-        let mut salt_with_pepper: [u8; 16] = [0; 16];
-        for c in salt.as_bytes() {
-            salt_with_pepper[count] = *c;
-            count += 1;
-            if count == 16 {
-                break;
-            }
-        }
-        salt_with_pepper
+        Ok(Hasher { peppers, cost })
     }
-} 
+
+    fn get_current_pepper(&self) -> &Pepper {
+        &self.peppers[0]
+    }
+}
+
+/// Verifies bcrypt and argon2id hashes alike, picking the algorithm from the hash prefix.
+fn verify_hash(peppers: &[Pepper], plain_text: String, hash: &str) -> Result<bool, String> {
+    let (plain_text, hash) = match split_pepper_id(hash) {
+        (Some(id), hash) => match peppers.iter().find(|p| p.id == id) {
+            Some(pepper) => (pepper.apply(&plain_text), hash),
+            None => return Err(format!("Unknown pepper '{}'", id))
+        },
+        // hashes created before peppers were applied to the password
+        (None, hash) => (plain_text, hash)
+    };
+
+    if hash.starts_with(argon2id::ARGON2ID_PREFIX) {
+        return argon2id::verify(&plain_text, hash);
+    }
+
+    match bcrypt::verify(plain_text, hash) {
+        Ok(result) => Ok(result),
+        Err(error) => Err(error.to_string())
+    }
+}
+
+fn split_pepper_id(hash: &str) -> (Option<&str>, &str) {
+    match hash.split_once(PEPPER_SEPARATOR) {
+        Some((id, hash)) if !id.starts_with('$') => (Some(id), hash),
+        _ => (None, hash)
+    }
+}
+
+/// Reads the cost of a bcrypt hash such as `$2b$12$...`.
+fn get_cost(hash: &str) -> Option<u32> {
+    hash.split('$').nth(2).and_then(|c| c.parse::<u32>().ok())
+}
 
 mod tests;
@@ -3,9 +3,9 @@
 fn it_should_generate_diferents_passwords_to_the_same_entry() {
     use crate::data::usecases::user::protocols::hash::Hash;
 
-    use super::Hasher;
+    use super::{Hasher, Pepper};
 
-    let hasher = Hasher::new(String::from("_pepper_"), 4);
+    let hasher = Hasher::new(vec![Pepper::new(String::from("1"), String::from("_pepper_")).unwrap()], 4).unwrap();
     let plaintext = "password";
 
     match hasher.run(String::from(plaintext)) {
@@ -26,9 +26,9 @@ fn it_should_generate_diferents_passwords_to_the_same_entry() {
 fn it_should_return_an_error_if_passwords_does_not_match() {
     use crate::data::usecases::user::protocols::hash::Hash;
 
-    use super::Hasher;
+    use super::{Hasher, Pepper};
 
-    let hasher = Hasher::new(String::from("_pepper_"), 4);
+    let hasher = Hasher::new(vec![Pepper::new(String::from("1"), String::from("_pepper_")).unwrap()], 4).unwrap();
     let plaintext = "password";
 
     match hasher.run(String::from(plaintext)) {
@@ -45,9 +45,9 @@ fn it_should_return_an_error_if_passwords_does_not_match() {
 fn it_should_return_true_if_passwords_match() {
     use crate::data::usecases::user::protocols::hash::Hash;
 
-    use super::Hasher;
+    use super::{Hasher, Pepper};
 
-    let hasher = Hasher::new(String::from("_pepper_"), 4);
+    let hasher = Hasher::new(vec![Pepper::new(String::from("1"), String::from("_pepper_")).unwrap()], 4).unwrap();
     let plaintext = "password";
 
     match hasher.run(String::from(plaintext)) {
@@ -58,4 +58,87 @@ fn it_should_return_true_if_passwords_match() {
     let hash = hasher.run(String::from(plaintext)).unwrap();
 
     assert!(hasher.verify(String::from(plaintext), hash).unwrap());
-}
\ No newline at end of file
+}
+
+#[test]
+fn it_should_verify_hashes_of_previous_peppers_and_ask_for_rehash() {
+    use crate::data::usecases::user::protocols::hash::Hash;
+
+    use super::{Hasher, Pepper};
+
+    let old = Hasher::new(Pepper::parse_list("1:_old_pepper_").unwrap(), 4).unwrap();
+    let rotated = Hasher::new(Pepper::parse_list("2:_new_pepper_, 1:_old_pepper_").unwrap(), 4).unwrap();
+
+    let old_hash = old.run(String::from("password")).unwrap();
+    assert!(old_hash.starts_with("1:"));
+    assert!(rotated.verify(String::from("password"), old_hash.clone()).unwrap());
+    assert!(rotated.needs_rehash(old_hash));
+
+    let new_hash = rotated.run(String::from("password")).unwrap();
+    assert!(new_hash.starts_with("2:"));
+    assert!(!rotated.needs_rehash(new_hash.clone()));
+    assert!(old.verify(String::from("password"), new_hash).is_err());
+}
+
+#[test]
+fn it_should_verify_hashes_created_without_pepper_and_ask_for_rehash() {
+    use crate::data::usecases::user::protocols::hash::Hash;
+
+    use super::{Hasher, Pepper};
+
+    let hasher = Hasher::new(Pepper::parse_list("1:_pepper_").unwrap(), 4).unwrap();
+    let legacy_hash = bcrypt::hash("password", 4).unwrap();
+
+    assert!(hasher.verify(String::from("password"), legacy_hash.clone()).unwrap());
+    assert!(!hasher.verify(String::from("another"), legacy_hash.clone()).unwrap());
+    assert!(hasher.needs_rehash(legacy_hash));
+}
+
+#[test]
+fn it_should_return_errors_for_invalid_configuration() {
+    use super::{Hasher, Pepper};
+
+    assert!(Pepper::parse_list("1:short").is_err());
+    assert!(Pepper::parse_list("missing_separator").is_err());
+    assert!(Pepper::parse_list("1:_pepper_,1:_another_").is_err());
+    assert!(Hasher::new(Vec::new(), 12).is_err());
+    assert!(Hasher::new(Pepper::parse_list("1:_pepper_").unwrap(), 3).is_err());
+    assert!(Hasher::new(Pepper::parse_list("1:_pepper_").unwrap(), 32).is_err());
+}
+
+#[test]
+fn it_should_verify_argon2id_hashes() {
+    use crate::data::usecases::user::protocols::hash::Hash;
+
+    use super::{Argon2Hasher, Pepper};
+
+    let hasher = Argon2Hasher::new(Pepper::parse_list("1:_pepper_").unwrap(), 64, 1, 1).unwrap();
+    let hash = hasher.run(String::from("password")).unwrap();
+
+    assert!(hash.starts_with("1:$argon2id$"));
+    assert!(hasher.verify(String::from("password"), hash.clone()).unwrap());
+    assert!(!hasher.verify(String::from("another"), hash.clone()).unwrap());
+    assert!(!hasher.needs_rehash(hash));
+}
+
+#[test]
+fn it_should_verify_mixed_algorithms_and_upgrade_to_the_configured_one() {
+    use crate::data::usecases::user::protocols::hash::Hash;
+
+    use super::{Argon2Hasher, Hasher, Pepper};
+
+    let peppers = Pepper::parse_list("1:_pepper_").unwrap();
+    let bcrypt = Hasher::new(peppers.clone(), 4).unwrap();
+    let argon2 = Argon2Hasher::new(peppers.clone(), 64, 1, 1).unwrap();
+
+    let bcrypt_hash = bcrypt.run(String::from("password")).unwrap();
+    let argon2_hash = argon2.run(String::from("password")).unwrap();
+
+    assert!(argon2.verify(String::from("password"), bcrypt_hash.clone()).unwrap());
+    assert!(bcrypt.verify(String::from("password"), argon2_hash.clone()).unwrap());
+    assert!(argon2.needs_rehash(bcrypt_hash));
+    assert!(bcrypt.needs_rehash(argon2_hash.clone()));
+
+    let stronger = Argon2Hasher::new(peppers, 128, 1, 1).unwrap();
+    assert!(stronger.needs_rehash(argon2_hash));
+}
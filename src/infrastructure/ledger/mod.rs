@@ -0,0 +1,217 @@
+use std::str::FromStr;
+use chrono::Utc;
+use sqlx::{PgConnection, Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{CreditAccount, LedgerTransaction, Tenant};
+use crate::data::usecases::ledger::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::types::money::Money;
+use crate::domain::usecases::account::ACCOUNT_NOT_FOUND;
+use crate::domain::usecases::ledger::BalanceSnapshot;
+use crate::infrastructure::account::PostgresRepository as AccountPostgresRepository;
+use crate::infrastructure::tenant;
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    fn get_snapshot_from_pg_row(row: PgRow) -> Result<BalanceSnapshot, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let cached_balance: i64 = row.try_get("balance")?;
+        let receivable_debits: i64 = row.try_get("receivable_debits")?;
+        let receivable_credits: i64 = row.try_get("receivable_credits")?;
+
+        Ok(BalanceSnapshot {
+            account_id: id.to_string(),
+            cached_balance: Money::from_cents(cached_balance),
+            receivable_debits: Money::from_cents(receivable_debits),
+            receivable_credits: Money::from_cents(receivable_credits)
+        })
+    }
+}
+
+/// Writes a ledger transaction and its entries using the caller's connection so
+/// it commits or rolls back together with the operation that originated it.
+pub(crate) async fn post(conn: &mut PgConnection, transaction: &LedgerTransaction) -> Result<(), Error> {
+    if !transaction.is_balanced() {
+        return Err(Error::new_internal("unbalanced ledger transaction"));
+    }
+
+    let id = match Uuid::from_str(transaction.get_id()) {
+        Ok(id) => id,
+        Err(err) => return Err(Error::new_internal(&err.to_string()))
+    };
+
+    let account_id = match Uuid::from_str(transaction.get_account_id()) {
+        Ok(id) => id,
+        Err(err) => return Err(Error::new_internal(&err.to_string()))
+    };
+
+    let reference_id = match transaction.get_reference_id().map(Uuid::from_str) {
+        None => None,
+        Some(Ok(id)) => Some(id),
+        Some(Err(err)) => return Err(Error::new_internal(&err.to_string()))
+    };
+
+    let result = sqlx::query(
+        r#"
+            INSERT INTO ledger_transaction (
+                id,
+                account_id,
+                kind,
+                reference_id,
+                description,
+                created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6)
+        "#
+    ).bind(id)
+    .bind(account_id)
+    .bind(transaction.get_kind().to_string())
+    .bind(reference_id)
+    .bind(transaction.get_description())
+    .bind(transaction.get_created_at())
+    .execute(&mut *conn).await;
+
+    if let Err(e) = result {
+        return Err(Error::new_internal(&e.to_string()));
+    }
+
+    for (line, entry) in transaction.get_entries().iter().enumerate() {
+        let result = sqlx::query(
+            r#"
+                INSERT INTO ledger_entry (
+                    transaction_id,
+                    line,
+                    ledger_account,
+                    direction,
+                    amount
+                ) VALUES ($1, $2, $3, $4, $5)
+            "#
+        ).bind(id)
+        .bind(line as i16)
+        .bind(entry.ledger_account.to_string())
+        .bind(entry.direction.to_string())
+        .bind(entry.amount.to_cents())
+        .execute(&mut *conn).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create(&self, tenant: &Tenant, transaction: LedgerTransaction) -> Result<CreditAccount, Error> {
+        let account_id = match Uuid::from_str(transaction.get_account_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let receivable_change = match transaction.get_receivable_change() {
+            Some(change) => change,
+            None => return Err(Error::new_internal("ledger transaction amount overflow"))
+        };
+
+        post(&mut tx, &transaction).await?;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE account SET
+                    balance = balance + $1,
+                    updated_at = $2
+                WHERE
+                    id = $3
+                RETURNING
+                    id,
+                    user_id,
+                    store_id,
+                    balance,
+                    credit_limit,
+                    status,
+                    closing_day,
+                    due_day,
+                    created_at,
+                    updated_at
+            "#
+        ).bind(receivable_change.to_cents())
+        .bind(Utc::now())
+        .bind(account_id)
+        .fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(ACCOUNT_NOT_FOUND, "account")),
+            Ok(Some(r)) => r
+        };
+
+        let account = match AccountPostgresRepository::get_account_from_pg_row(row) {
+            Ok(a) => a,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        match tx.commit().await {
+            Ok(()) => Ok(account),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_balance_snapshots(&self, tenant: &Tenant) -> Result<Vec<BalanceSnapshot>, Error> {
+        let store_id = match tenant.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(err)) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    a.id,
+                    a.balance,
+                    COALESCE(SUM(e.amount) FILTER (WHERE e.direction = 'DEBIT'), 0)::bigint AS receivable_debits,
+                    COALESCE(SUM(e.amount) FILTER (WHERE e.direction = 'CREDIT'), 0)::bigint AS receivable_credits
+                FROM account a
+                LEFT JOIN ledger_transaction t ON t.account_id = a.id
+                LEFT JOIN ledger_entry e ON e.transaction_id = t.id AND e.ledger_account = 'RECEIVABLE'
+                WHERE $1::uuid IS NULL OR a.store_id = $1
+                GROUP BY a.id, a.balance
+            "#
+        ).bind(store_id).fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let mut snapshots: Vec<BalanceSnapshot> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match Self::get_snapshot_from_pg_row(row) {
+                Ok(s) => snapshots.push(s),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(snapshots)
+    }
+}
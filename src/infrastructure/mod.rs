@@ -1,5 +1,16 @@
 pub mod user;
+pub mod admin;
+pub mod api_key;
+pub mod store;
+pub mod account;
+pub mod purchase;
+pub mod payment;
+pub mod ledger;
+pub mod accrual;
+pub mod statement;
+pub mod password;
 pub mod hash;
 pub mod uuid;
 pub mod tracer;
-pub mod logger;
\ No newline at end of file
+pub mod logger;
+pub mod tenant;
\ No newline at end of file
@@ -0,0 +1,200 @@
+use std::str::FromStr;
+use chrono::{NaiveDateTime, Utc};
+use sqlx::{Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{PasswordReset, Tenant};
+use crate::data::usecases::password::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::usecases::password::INVALID_RESET_TOKEN_ERROR;
+use crate::infrastructure::tenant;
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    fn get_reset_from_pg_row(row: PgRow) -> Result<PasswordReset, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let user_id: Uuid = row.try_get("user_id")?;
+        let token_hash: String = row.try_get("token_hash")?;
+        let expires_at: NaiveDateTime = row.try_get("expires_at")?;
+        let used_at: Option<NaiveDateTime> = row.try_get("used_at")?;
+        let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+
+        let mut reset = PasswordReset::new(user_id.to_string(), token_hash, expires_at.and_utc());
+        reset.set_uuid(id.to_string());
+        reset.set_used_at(used_at.map(|u| u.and_utc()));
+        reset.set_created_at(db_created_at.and_utc());
+        Ok(reset)
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create_reset(&self, tenant: &Tenant, reset: PasswordReset) -> Result<(), Error> {
+        let id = match Uuid::from_str(reset.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let user_id = match Uuid::from_str(reset.get_user_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        // only the latest token issued for the user can be used
+        let result = sqlx::query(
+            r#"
+                UPDATE password_reset SET
+                    used_at = $1
+                WHERE
+                    user_id = $2
+                    AND used_at IS NULL
+            "#
+        ).bind(Utc::now())
+        .bind(user_id)
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO password_reset (
+                    id,
+                    user_id,
+                    token_hash,
+                    expires_at,
+                    used_at,
+                    created_at
+                ) VALUES ($1, $2, $3, $4, $5, $6)
+            "#
+        ).bind(id)
+        .bind(user_id)
+        .bind(reset.get_token_hash())
+        .bind(reset.get_expires_at())
+        .bind(reset.get_used_at())
+        .bind(reset.get_created_at())
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_reset(&self, tenant: &Tenant, id: &str) -> Result<PasswordReset, Error> {
+        // the id comes from the token sent by the customer
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(INVALID_RESET_TOKEN_ERROR, "password reset"))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    user_id,
+                    token_hash,
+                    expires_at,
+                    used_at,
+                    created_at
+                FROM password_reset
+                WHERE id = $1
+            "#
+        ).bind(id).fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(INVALID_RESET_TOKEN_ERROR, "password reset")),
+            Ok(Some(r)) => r
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        match PostgresRepository::get_reset_from_pg_row(row) {
+            Ok(r) => Ok(r),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn consume_reset(&self, tenant: &Tenant, id: &str, password: &str) -> Result<bool, Error> {
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let now = Utc::now();
+        let result = sqlx::query(
+            r#"
+                UPDATE password_reset SET
+                    used_at = $1
+                WHERE
+                    id = $2
+                    AND used_at IS NULL
+                    AND expires_at > $1
+                RETURNING user_id
+            "#
+        ).bind(now.naive_utc())
+        .bind(id)
+        .fetch_optional(&mut *tx).await;
+
+        let user_id: Uuid = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Ok(false),
+            Ok(Some(r)) => match r.try_get("user_id") {
+                Ok(u) => u,
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        };
+
+        let result = sqlx::query(
+            r#"
+                UPDATE "user" SET
+                    "password" = $1,
+                    updated_at = $2
+                WHERE id = $3
+            "#
+        ).bind(password)
+        .bind(now)
+        .bind(user_id)
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(true),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::str::FromStr;
+use chrono::Utc;
+use sqlx::{Pool, Postgres, Row, Transaction};
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{CreditAccount, Installment, LedgerTransaction, Payment, PaymentAllocation, Tenant};
+use crate::data::usecases::payment::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::types::money::Money;
+use crate::domain::usecases::account::ACCOUNT_NOT_FOUND;
+use crate::domain::usecases::payment::PAYMENT_CONFLICT_ERROR;
+use crate::infrastructure::account::PostgresRepository as AccountPostgresRepository;
+use crate::infrastructure::purchase::PostgresRepository as PurchasePostgresRepository;
+use crate::infrastructure::{ledger, tenant};
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    /// Locks the account until the payment commits, so purchases and other payments wait for it,
+    /// and checks nothing changed since the allocations were computed.
+    async fn lock_account(tx: &mut Transaction<'static, Postgres>, account_id: Uuid, expected_balance: Money, expected_installments: &[Installment]) -> Result<(), Error> {
+        let result = sqlx::query("SELECT balance FROM account WHERE id = $1 FOR UPDATE")
+            .bind(account_id)
+            .fetch_optional(&mut **tx).await;
+
+        let balance: i64 = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(ACCOUNT_NOT_FOUND, "account")),
+            Ok(Some(r)) => match r.try_get("balance") {
+                Ok(b) => b,
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        };
+
+        if Money::from_cents(balance) != expected_balance {
+            return Err(Error::new_business(PAYMENT_CONFLICT_ERROR));
+        }
+
+        let result = sqlx::query(
+            r#"
+                SELECT id, paid_amount
+                FROM installment
+                WHERE account_id = $1 AND paid_amount < amount
+            "#
+        ).bind(account_id).fetch_all(&mut **tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let mut current: Vec<(String, i64)> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match (row.try_get::<Uuid, _>("id"), row.try_get::<i64, _>("paid_amount")) {
+                (Ok(id), Ok(paid_amount)) => current.push((id.to_string(), paid_amount)),
+                (Err(e), _) | (_, Err(e)) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+
+        let mut expected: Vec<(String, i64)> = expected_installments.iter()
+            .map(|i| (String::from(i.get_id()), i.get_paid_amount().to_cents()))
+            .collect();
+        current.sort();
+        expected.sort();
+
+        if current != expected {
+            return Err(Error::new_business(PAYMENT_CONFLICT_ERROR));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn get_unsettled_installments(&self, tenant: &Tenant, account_id: &str) -> Result<Vec<Installment>, Error> {
+        let account_id = match Uuid::from_str(account_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    purchase_id,
+                    account_id,
+                    number,
+                    amount,
+                    paid_amount,
+                    due_date,
+                    created_at
+                FROM installment
+                WHERE account_id = $1 AND paid_amount < amount
+                ORDER BY due_date, created_at, number
+            "#
+        ).bind(account_id).fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let mut installments: Vec<Installment> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match PurchasePostgresRepository::get_installment_from_pg_row(row) {
+                Ok(i) => installments.push(i),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(installments)
+    }
+
+    async fn create(
+        &self,
+        tenant: &Tenant,
+        payment: Payment,
+        allocations: Vec<PaymentAllocation>,
+        transaction: LedgerTransaction,
+        expected_balance: Money,
+        expected_installments: Vec<Installment>
+    ) -> Result<CreditAccount, Error> {
+        let id = match Uuid::from_str(payment.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let account_id = match Uuid::from_str(payment.get_account_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        Self::lock_account(&mut tx, account_id, expected_balance, &expected_installments).await?;
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO payment (
+                    id,
+                    account_id,
+                    amount,
+                    method,
+                    operator,
+                    carried_credit,
+                    paid_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#
+        ).bind(id)
+        .bind(account_id)
+        .bind(payment.get_amount().to_cents())
+        .bind(payment.get_method().to_string())
+        .bind(payment.get_operator())
+        .bind(payment.get_carried_credit().to_cents())
+        .bind(payment.get_paid_at())
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        for allocation in allocations {
+            let installment_id = match Uuid::from_str(&allocation.installment_id) {
+                Ok(id) => id,
+                Err(err) => return Err(Error::new_internal(&err.to_string()))
+            };
+
+            let purchase_id = match Uuid::from_str(&allocation.purchase_id) {
+                Ok(id) => id,
+                Err(err) => return Err(Error::new_internal(&err.to_string()))
+            };
+
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO payment_allocation (payment_id, installment_id, purchase_id, amount)
+                    VALUES ($1, $2, $3, $4)
+                "#
+            ).bind(id)
+            .bind(installment_id)
+            .bind(purchase_id)
+            .bind(allocation.amount.to_cents())
+            .execute(&mut *tx).await;
+
+            if let Err(e) = result {
+                return Err(Error::new_internal(&e.to_string()));
+            }
+
+            // the paid_amount check constraints reject allocations raced by another payment
+            let result = sqlx::query(
+                r#"
+                    UPDATE installment SET paid_amount = paid_amount + $1
+                    WHERE id = $2
+                "#
+            ).bind(allocation.amount.to_cents())
+            .bind(installment_id)
+            .execute(&mut *tx).await;
+
+            if let Err(e) = result {
+                return Err(Error::new_internal(&e.to_string()));
+            }
+
+            let result = sqlx::query(
+                r#"
+                    UPDATE purchase SET paid_amount = paid_amount + $1
+                    WHERE id = $2
+                "#
+            ).bind(allocation.amount.to_cents())
+            .bind(purchase_id)
+            .execute(&mut *tx).await;
+
+            if let Err(e) = result {
+                return Err(Error::new_internal(&e.to_string()));
+            }
+        }
+
+        ledger::post(&mut tx, &transaction).await?;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE account SET
+                    balance = balance - $1,
+                    updated_at = $2
+                WHERE
+                    id = $3
+                RETURNING
+                    id,
+                    user_id,
+                    store_id,
+                    balance,
+                    credit_limit,
+                    status,
+                    closing_day,
+                    due_day,
+                    created_at,
+                    updated_at
+            "#
+        ).bind(payment.get_amount().to_cents())
+        .bind(Utc::now())
+        .bind(account_id)
+        .fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(ACCOUNT_NOT_FOUND, "account")),
+            Ok(Some(r)) => r
+        };
+
+        let account = match AccountPostgresRepository::get_account_from_pg_row(row) {
+            Ok(a) => a,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        match tx.commit().await {
+            Ok(()) => Ok(account),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::str::FromStr;
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use sqlx::{Pool, Postgres};
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{CreditAccount, Installment, LedgerTransaction, Purchase, Tenant};
+use crate::data::usecases::purchase::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::types::money::Money;
+use crate::domain::usecases::purchase::CREDIT_LIMIT_EXCEEDED_ERROR;
+use crate::infrastructure::account::PostgresRepository as AccountPostgresRepository;
+use crate::infrastructure::{ledger, tenant};
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    pub(crate) fn get_installment_from_pg_row(row: PgRow) -> Result<Installment, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let purchase_id: Uuid = row.try_get("purchase_id")?;
+        let account_id: Uuid = row.try_get("account_id")?;
+        let number: i16 = row.try_get("number")?;
+        let amount: i64 = row.try_get("amount")?;
+        let paid_amount: i64 = row.try_get("paid_amount")?;
+        let due_date: NaiveDate = row.try_get("due_date")?;
+        let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+
+        let mut installment = Installment::new(purchase_id.to_string(), account_id.to_string(), number as u32, Money::from_cents(amount), due_date);
+        installment.set_uuid(id.to_string());
+        installment.set_paid_amount(Money::from_cents(paid_amount));
+        installment.set_created_at(db_created_at.and_utc());
+        Ok(installment)
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create(&self, tenant: &Tenant, mut purchase: Purchase, transaction: LedgerTransaction) -> Result<(Purchase, CreditAccount), Error> {
+        let id = match Uuid::from_str(purchase.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let account_id = match Uuid::from_str(purchase.get_account_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let store_id = match purchase.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(err)) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        // the limit is checked again here so concurrent purchases cannot overdraw the tab
+        let result = sqlx::query(
+            r#"
+                UPDATE account SET
+                    balance = balance + $1,
+                    updated_at = $2
+                WHERE
+                    id = $3
+                    AND status = 'OPEN'
+                    AND balance + $1 <= credit_limit
+                RETURNING
+                    id,
+                    user_id,
+                    store_id,
+                    balance,
+                    credit_limit,
+                    status,
+                    closing_day,
+                    due_day,
+                    created_at,
+                    updated_at
+            "#
+        ).bind(purchase.get_amount().to_cents())
+        .bind(Utc::now())
+        .bind(account_id)
+        .fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_business(CREDIT_LIMIT_EXCEEDED_ERROR)),
+            Ok(Some(r)) => r
+        };
+
+        let account = match AccountPostgresRepository::get_account_from_pg_row(row) {
+            Ok(a) => a,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        // credit carried forward from overpayments settles the purchase right away
+        let previous_account_balance = account.get_balance().saturating_sub(purchase.get_amount());
+        if previous_account_balance.is_negative() {
+            purchase.apply_credit(previous_account_balance.abs());
+        }
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO purchase (
+                    id,
+                    account_id,
+                    store_id,
+                    amount,
+                    paid_amount,
+                    description,
+                    merchant_reference,
+                    purchased_at,
+                    created_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#
+        ).bind(id)
+        .bind(account_id)
+        .bind(store_id)
+        .bind(purchase.get_amount().to_cents())
+        .bind(purchase.get_paid_amount().to_cents())
+        .bind(purchase.get_description())
+        .bind(purchase.get_merchant_reference())
+        .bind(purchase.get_purchased_at())
+        .bind(purchase.get_created_at())
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        for installment in purchase.get_installments() {
+            let installment_id = match Uuid::from_str(installment.get_id()) {
+                Ok(id) => id,
+                Err(err) => return Err(Error::new_internal(&err.to_string()))
+            };
+
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO installment (
+                        id,
+                        purchase_id,
+                        account_id,
+                        number,
+                        amount,
+                        paid_amount,
+                        due_date,
+                        created_at
+                    ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#
+            ).bind(installment_id)
+            .bind(id)
+            .bind(account_id)
+            .bind(installment.get_number() as i16)
+            .bind(installment.get_amount().to_cents())
+            .bind(installment.get_paid_amount().to_cents())
+            .bind(installment.get_due_date())
+            .bind(installment.get_created_at())
+            .execute(&mut *tx).await;
+
+            if let Err(e) = result {
+                return Err(Error::new_internal(&e.to_string()));
+            }
+        }
+
+        ledger::post(&mut tx, &transaction).await?;
+
+        match tx.commit().await {
+            Ok(()) => Ok((purchase, account)),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
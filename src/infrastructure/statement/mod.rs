@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use chrono::{Days, NaiveDate, NaiveDateTime};
+use sqlx::{PgConnection, Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{EntryDirection, LedgerAccount, LedgerEntry, LedgerTransaction, Statement, StatementLine, Tenant, TransactionKind};
+use crate::data::usecases::statement::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::types::money::Money;
+use crate::domain::usecases::statement::{BillingState, STATEMENT_NOT_FOUND};
+use crate::infrastructure::account::PostgresRepository as AccountPostgresRepository;
+use crate::infrastructure::tenant;
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+struct StatementRow {
+    id: Uuid,
+    account_id: Uuid,
+    period_start: NaiveDate,
+    closing_date: NaiveDate,
+    due_date: NaiveDate,
+    opening_balance: i64,
+    created_at: NaiveDateTime
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    fn get_billing_state_from_pg_row(row: PgRow) -> Result<BillingState, sqlx::Error> {
+        let last_closing_date: Option<NaiveDate> = row.try_get("last_closing_date")?;
+        let last_closing_balance: Option<i64> = row.try_get("last_closing_balance")?;
+        let account = AccountPostgresRepository::get_account_from_pg_row(row)?;
+
+        Ok(BillingState {
+            account,
+            last_closing_date,
+            last_closing_balance: Money::from_cents(last_closing_balance.unwrap_or(0))
+        })
+    }
+
+    fn get_statement_row(row: &PgRow) -> Result<StatementRow, sqlx::Error> {
+        Ok(StatementRow {
+            id: row.try_get("id")?,
+            account_id: row.try_get("account_id")?,
+            period_start: row.try_get("period_start")?,
+            closing_date: row.try_get("closing_date")?,
+            due_date: row.try_get("due_date")?,
+            opening_balance: row.try_get("opening_balance")?,
+            created_at: row.try_get("created_at")?
+        })
+    }
+
+    fn get_line_from_pg_row(row: &PgRow) -> Result<(Uuid, StatementLine), sqlx::Error> {
+        let statement_id: Uuid = row.try_get("statement_id")?;
+        let transaction_id: Uuid = row.try_get("transaction_id")?;
+        let kind: &str = row.try_get("kind")?;
+        let description: String = row.try_get("description")?;
+        let amount: i64 = row.try_get("amount")?;
+        let db_posted_at: NaiveDateTime = row.try_get("posted_at")?;
+
+        Ok((statement_id, StatementLine {
+            transaction_id: transaction_id.to_string(),
+            kind: TransactionKind::from_string(kind),
+            description,
+            amount: Money::from_cents(amount),
+            posted_at: db_posted_at.and_utc()
+        }))
+    }
+
+    /// Loads the lines of the given statement rows and builds the statements, keeping the row order.
+    async fn load_statements(conn: &mut PgConnection, rows: Vec<PgRow>) -> Result<Vec<Statement>, Error> {
+        let mut statement_rows: Vec<StatementRow> = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            match Self::get_statement_row(row) {
+                Ok(r) => statement_rows.push(r),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+
+        let ids: Vec<Uuid> = statement_rows.iter().map(|r| r.id).collect();
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    statement_id,
+                    transaction_id,
+                    kind,
+                    description,
+                    amount,
+                    posted_at
+                FROM statement_line
+                WHERE statement_id = ANY($1)
+                ORDER BY statement_id, line
+            "#
+        ).bind(ids).fetch_all(&mut *conn).await;
+
+        let line_rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let mut lines: HashMap<Uuid, Vec<StatementLine>> = HashMap::new();
+        for row in line_rows.iter() {
+            match Self::get_line_from_pg_row(row) {
+                Ok((statement_id, line)) => lines.entry(statement_id).or_default().push(line),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+
+        let mut statements: Vec<Statement> = Vec::with_capacity(statement_rows.len());
+        for row in statement_rows {
+            let mut statement = match Statement::new(
+                row.account_id.to_string(),
+                row.period_start,
+                row.closing_date,
+                row.due_date,
+                Money::from_cents(row.opening_balance),
+                lines.remove(&row.id).unwrap_or_default()
+            ) {
+                Some(s) => s,
+                None => return Err(Error::new_internal("statement balance overflow"))
+            };
+            statement.set_uuid(row.id.to_string());
+            statement.set_created_at(row.created_at.and_utc());
+            statements.push(statement);
+        }
+        Ok(statements)
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn get_billing_states(&self, tenant: &Tenant) -> Result<Vec<BillingState>, Error> {
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    a.id,
+                    a.user_id,
+                    a.store_id,
+                    a.balance,
+                    a.credit_limit,
+                    a.closing_day,
+                    a.due_day,
+                    a.status,
+                    a.created_at,
+                    a.updated_at,
+                    s.closing_date AS last_closing_date,
+                    s.closing_balance AS last_closing_balance
+                FROM account a
+                LEFT JOIN LATERAL (
+                    SELECT closing_date, closing_balance
+                    FROM statement
+                    WHERE account_id = a.id
+                    ORDER BY closing_date DESC
+                    LIMIT 1
+                ) s ON true
+                WHERE a.status = 'OPEN'
+            "#
+        ).fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let mut states: Vec<BillingState> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match Self::get_billing_state_from_pg_row(row) {
+                Ok(s) => states.push(s),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(states)
+    }
+
+    async fn get_transactions(&self, tenant: &Tenant, account_id: &str, from: NaiveDate, to: NaiveDate) -> Result<Vec<LedgerTransaction>, Error> {
+        let account_id = match Uuid::from_str(account_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    t.id,
+                    t.kind,
+                    t.reference_id,
+                    t.description,
+                    t.created_at,
+                    e.ledger_account,
+                    e.direction,
+                    e.amount
+                FROM ledger_transaction t
+                JOIN ledger_entry e ON e.transaction_id = t.id
+                WHERE
+                    t.account_id = $1
+                    AND t.created_at >= $2
+                    AND t.created_at < $3
+                ORDER BY t.created_at, t.id, e.line
+            "#
+        ).bind(account_id)
+        .bind(from.and_hms_opt(0, 0, 0))
+        .bind((to + Days::new(1)).and_hms_opt(0, 0, 0))
+        .fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        if let Err(e) = tx.commit().await {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        // entries come ordered by transaction, so a new id starts a new transaction
+        let mut transactions: Vec<LedgerTransaction> = Vec::new();
+        let mut entries: Vec<LedgerEntry> = Vec::new();
+        let mut header: Option<LedgerTransaction> = None;
+        for row in rows {
+            let (transaction, entry) = match get_transaction_from_pg_row(&row, account_id) {
+                Ok(t) => t,
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            };
+
+            if let Some(h) = header.take() {
+                if h.get_id() == transaction.get_id() {
+                    header = Some(h);
+                } else {
+                    transactions.push(with_entries(h, std::mem::take(&mut entries)));
+                    header = Some(transaction);
+                }
+            } else {
+                header = Some(transaction);
+            }
+            entries.push(entry);
+        }
+
+        if let Some(h) = header {
+            transactions.push(with_entries(h, entries));
+        }
+        Ok(transactions)
+    }
+
+    async fn create(&self, tenant: &Tenant, statement: Statement) -> Result<(), Error> {
+        let id = match Uuid::from_str(statement.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let account_id = match Uuid::from_str(statement.get_account_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO statement (
+                    id,
+                    account_id,
+                    period_start,
+                    closing_date,
+                    due_date,
+                    opening_balance,
+                    closing_balance,
+                    created_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (account_id, closing_date) DO NOTHING
+            "#
+        ).bind(id)
+        .bind(account_id)
+        .bind(statement.get_period_start())
+        .bind(statement.get_closing_date())
+        .bind(statement.get_due_date())
+        .bind(statement.get_opening_balance().to_cents())
+        .bind(statement.get_closing_balance().to_cents())
+        .bind(statement.get_created_at())
+        .execute(&mut *tx).await;
+
+        match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) if r.rows_affected() == 0 => return Ok(()),
+            Ok(_) => ()
+        }
+
+        for (line, statement_line) in statement.get_lines().iter().enumerate() {
+            let transaction_id = match Uuid::from_str(&statement_line.transaction_id) {
+                Ok(id) => id,
+                Err(err) => return Err(Error::new_internal(&err.to_string()))
+            };
+
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO statement_line (
+                        statement_id,
+                        line,
+                        transaction_id,
+                        kind,
+                        description,
+                        amount,
+                        posted_at
+                    ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#
+            ).bind(id)
+            .bind(line as i32)
+            .bind(transaction_id)
+            .bind(statement_line.kind.to_string())
+            .bind(&statement_line.description)
+            .bind(statement_line.amount.to_cents())
+            .bind(statement_line.posted_at)
+            .execute(&mut *tx).await;
+
+            if let Err(e) = result {
+                return Err(Error::new_internal(&e.to_string()));
+            }
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_by_account_id(&self, tenant: &Tenant, account_id: &str) -> Result<Vec<Statement>, Error> {
+        let account_id = match Uuid::from_str(account_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    account_id,
+                    period_start,
+                    closing_date,
+                    due_date,
+                    opening_balance,
+                    created_at
+                FROM statement
+                WHERE account_id = $1
+                ORDER BY closing_date DESC
+            "#
+        ).bind(account_id).fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let statements = Self::load_statements(&mut tx, rows).await?;
+        match tx.commit().await {
+            Ok(()) => Ok(statements),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_by_id(&self, tenant: &Tenant, id: &str) -> Result<Statement, Error> {
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(STATEMENT_NOT_FOUND, "statement"))
+        };
+
+        let store_id = match tenant.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(_)) => return Err(Error::new_not_found(STATEMENT_NOT_FOUND, "statement"))
+        };
+
+        let mut tx = match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => tx,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    s.id,
+                    s.account_id,
+                    s.period_start,
+                    s.closing_date,
+                    s.due_date,
+                    s.opening_balance,
+                    s.created_at
+                FROM statement s
+                JOIN account a ON a.id = s.account_id
+                WHERE
+                    s.id = $1
+                    AND ($2::uuid IS NULL OR a.store_id = $2)
+            "#
+        ).bind(id).bind(store_id).fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(STATEMENT_NOT_FOUND, "statement")),
+            Ok(Some(r)) => r
+        };
+
+        let statement = match Self::load_statements(&mut tx, vec![row]).await?.pop() {
+            Some(s) => s,
+            None => return Err(Error::new_not_found(STATEMENT_NOT_FOUND, "statement"))
+        };
+
+        match tx.commit().await {
+            Ok(()) => Ok(statement),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+}
+
+fn get_transaction_from_pg_row(row: &PgRow, account_id: Uuid) -> Result<(LedgerTransaction, LedgerEntry), sqlx::Error> {
+    let id: Uuid = row.try_get("id")?;
+    let kind: &str = row.try_get("kind")?;
+    let reference_id: Option<Uuid> = row.try_get("reference_id")?;
+    let description: String = row.try_get("description")?;
+    let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+    let ledger_account: &str = row.try_get("ledger_account")?;
+    let direction: &str = row.try_get("direction")?;
+    let amount: i64 = row.try_get("amount")?;
+
+    let mut transaction = LedgerTransaction::new(
+        id.to_string(),
+        account_id.to_string(),
+        TransactionKind::from_string(kind),
+        reference_id.map(|r| r.to_string()),
+        description,
+        Vec::new()
+    );
+    transaction.set_created_at(db_created_at.and_utc());
+
+    let entry = LedgerEntry {
+        ledger_account: LedgerAccount::from_string(ledger_account),
+        direction: EntryDirection::from_string(direction),
+        amount: Money::from_cents(amount)
+    };
+    Ok((transaction, entry))
+}
+
+fn with_entries(transaction: LedgerTransaction, entries: Vec<LedgerEntry>) -> LedgerTransaction {
+    let mut rebuilt = LedgerTransaction::new(
+        String::from(transaction.get_id()),
+        String::from(transaction.get_account_id()),
+        transaction.get_kind(),
+        transaction.get_reference_id().map(String::from),
+        String::from(transaction.get_description()),
+        entries
+    );
+    rebuilt.set_created_at(transaction.get_created_at());
+    rebuilt
+}
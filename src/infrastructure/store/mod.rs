@@ -0,0 +1,218 @@
+use std::str::FromStr;
+use chrono::NaiveDateTime;
+use sqlx::Row;
+use sqlx::postgres::{PgQueryResult, PgRow};
+use sqlx::{Pool, Postgres};
+use sqlx::types::Uuid;
+use async_trait::async_trait;
+use crate::domain::entities::{FeePolicy, Store};
+use crate::data::usecases::store::protocols::repository::Repository;
+use crate::domain::error::Error;
+use crate::domain::types::cnpj::CNPJ;
+use crate::domain::usecases::store::{STORE_ALREADY_EXISTS, STORE_NOT_FOUND, STORE_IN_USE_ERROR};
+
+pub struct PostgresRepository {
+    pool: Pool<Postgres>
+}
+
+impl PostgresRepository {
+    pub fn new(pool: Pool<Postgres>) -> PostgresRepository {
+        PostgresRepository { pool }
+    }
+
+    fn handle_postgres_error(error: sqlx::Error) -> Error {
+        let raw_error_message: &str = &error.to_string();
+        if let sqlx::Error::Database(dbe) = error {
+            if dbe.is_unique_violation() {
+                return Error::new_already_exists(STORE_ALREADY_EXISTS, "store");
+            }
+
+            if dbe.is_foreign_key_violation() {
+                return Error::new_business_with_message(STORE_IN_USE_ERROR, "store has accounts");
+            }
+        }
+        Error::new_internal(raw_error_message)
+    }
+
+    fn handle_changed_rows(res: PgQueryResult) -> Result<(), Error> {
+        if res.rows_affected() == 0 {
+            return Err(Error::new_not_found(STORE_NOT_FOUND, "store"));
+        }
+        Ok(())
+    }
+
+    fn get_store_from_pg_row(row: PgRow) -> Result<Store, sqlx::Error> {
+        let id: Uuid = row.try_get("id")?;
+        let name: String = row.try_get("name")?;
+        let document: String = row.try_get("document")?;
+        let late_fee_bps: Option<i32> = row.try_get("late_fee_bps")?;
+        let monthly_interest_bps: Option<i32> = row.try_get("monthly_interest_bps")?;
+        let db_created_at: NaiveDateTime = row.try_get("created_at")?;
+        let db_updated_at: NaiveDateTime = row.try_get("updated_at")?;
+
+        let document = match CNPJ::from_string(document) {
+            Ok(d) => d,
+            Err(e) => return Err(sqlx::Error::Decode(e.into()))
+        };
+
+        let fee_policy = match (late_fee_bps, monthly_interest_bps) {
+            (Some(late_fee_bps), Some(monthly_interest_bps)) => match FeePolicy::new(late_fee_bps as u32, monthly_interest_bps as u32) {
+                Ok(p) => Some(p),
+                Err(e) => return Err(sqlx::Error::Decode(e.into()))
+            },
+            _ => None
+        };
+
+        let mut store = Store::new(name, document);
+        store.set_uuid(id.to_string());
+        store.set_fee_policy(fee_policy);
+        store.set_created_at(db_created_at.and_utc());
+        store.set_updated_at(db_updated_at.and_utc());
+        Ok(store)
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepository {
+    async fn create(&self, store: Store) -> Result<(), Error> {
+        let id = match Uuid::from_str(store.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO store (
+                    id,
+                    name,
+                    document,
+                    late_fee_bps,
+                    monthly_interest_bps,
+                    created_at,
+                    updated_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#
+        ).bind(id)
+        .bind(store.get_name())
+        .bind(store.get_document().to_string())
+        .bind(store.get_fee_policy().map(|p| p.get_late_fee_bps() as i32))
+        .bind(store.get_fee_policy().map(|p| p.get_monthly_interest_bps() as i32))
+        .bind(store.get_created_at())
+        .bind(store.get_updated_at())
+        .execute(&self.pool).await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Self::handle_postgres_error(err))
+        }
+    }
+
+    async fn update(&self, store: Store) -> Result<(), Error> {
+        let id = match Uuid::from_str(store.get_id()) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                UPDATE store SET
+                    name = $1,
+                    late_fee_bps = $2,
+                    monthly_interest_bps = $3,
+                    updated_at = $4
+                WHERE
+                    id = $5
+            "#
+        ).bind(store.get_name())
+        .bind(store.get_fee_policy().map(|p| p.get_late_fee_bps() as i32))
+        .bind(store.get_fee_policy().map(|p| p.get_monthly_interest_bps() as i32))
+        .bind(store.get_updated_at())
+        .bind(id)
+        .execute(&self.pool).await;
+
+        match result {
+            Err(e) => Err(Self::handle_postgres_error(e)),
+            Ok(r) => Self::handle_changed_rows(r)
+        }
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Store, Error> {
+        // ids come from the path, so malformed ones are just unknown stores
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(STORE_NOT_FOUND, "store"))
+        };
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    name,
+                    document,
+                    late_fee_bps,
+                    monthly_interest_bps,
+                    created_at,
+                    updated_at
+                FROM store
+                WHERE id = $1
+            "#
+        ).bind(id).fetch_optional(&self.pool).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(STORE_NOT_FOUND, "store")),
+            Ok(Some(r)) => r
+        };
+
+        match Self::get_store_from_pg_row(row) {
+            Ok(s) => Ok(s),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn list(&self) -> Result<Vec<Store>, Error> {
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    name,
+                    document,
+                    late_fee_bps,
+                    monthly_interest_bps,
+                    created_at,
+                    updated_at
+                FROM store
+                ORDER BY name
+            "#
+        ).fetch_all(&self.pool).await;
+
+        let rows = match result {
+            Ok(r) => r,
+            Err(e) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let mut stores: Vec<Store> = Vec::with_capacity(rows.len());
+        for row in rows {
+            match Self::get_store_from_pg_row(row) {
+                Ok(s) => stores.push(s),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(stores)
+    }
+
+    async fn delete_by_id(&self, id: &str) -> Result<(), Error> {
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(STORE_NOT_FOUND, "store"))
+        };
+
+        let result = sqlx::query("DELETE FROM store WHERE id = $1")
+            .bind(id).execute(&self.pool).await;
+
+        match result {
+            Err(e) => Err(Self::handle_postgres_error(e)),
+            Ok(r) => Self::handle_changed_rows(r)
+        }
+    }
+}
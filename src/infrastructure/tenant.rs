@@ -0,0 +1,19 @@
+use sqlx::{Pool, Postgres, Transaction};
+use crate::domain::entities::Tenant;
+
+/// Opens a transaction scoped to `tenant`, row level security on store data tables only lets
+/// it reach the rows of its store. The settings are local to the transaction, so they never
+/// leak to the next request borrowing the same pooled connection.
+pub async fn begin(pool: &Pool<Postgres>, tenant: &Tenant) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let platform = match tenant {
+        Tenant::Platform => "on",
+        Tenant::Store(_) => "off"
+    };
+
+    sqlx::query("SELECT set_config('app.platform', $1, true), set_config('app.store_id', $2, true)")
+        .bind(platform)
+        .bind(tenant.get_store_id().unwrap_or_default())
+        .execute(&mut *tx).await?;
+    Ok(tx)
+}
@@ -1,16 +1,21 @@
 use std::str::FromStr;
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
 use sqlx::Row;
 use sqlx::postgres::{PgQueryResult, PgRow};
-use sqlx::{Pool, Postgres};
+use sqlx::{Pool, Postgres, Transaction};
 use sqlx::types::Uuid;
 use async_trait::async_trait;
-use crate::domain::entities::{User, UserStatus};
-use crate::data::usecases::user::protocols::repository::Repository;
+use crate::domain::entities::{User, UserStatus, UserStatusChange, StatusChangeReason, Tenant};
+use crate::data::usecases::user::protocols::repository::{Repository, UserFilter, UserSort};
 use crate::domain::error::{self, Error};
 use crate::domain::types::birth_date::BirthDate;
 use crate::domain::types::cpf::CPF;
-use crate::domain::usecases::user::{USER_ALREADY_EXISTS, USER_NOT_FOUND };
+use crate::domain::usecases::user::{USER_ALREADY_EXISTS, USER_NOT_FOUND, USER_STORE_REQUIRED_ERROR, INVALID_CURSOR_ERROR};
+use crate::domain::usecases::store::STORE_NOT_FOUND;
+use crate::infrastructure::tenant;
+
+/// Lower than the pg_trgm default, customers are often found by a single word of their name.
+const SEARCH_SIMILARITY_THRESHOLD: &str = "0.3";
 
 pub struct PostgresRepository{
     pool: Pool<Postgres>
@@ -27,10 +32,28 @@ impl PostgresRepository {
             if dbe.is_unique_violation() {
                 return error::Error::new_already_exists(USER_ALREADY_EXISTS, "user");
             } 
+            if dbe.is_foreign_key_violation() {
+                return error::Error::new_not_found(STORE_NOT_FOUND, "store");
+            }
         }
         error::Error::new_internal(raw_error_message)
     } 
 
+    /// Every query runs in a transaction scoped to the tenant, see the user row level security policy.
+    async fn begin(&self, tenant: &Tenant) -> Result<Transaction<'static, Postgres>, error::Error> {
+        match tenant::begin(&self.pool, tenant).await {
+            Ok(tx) => Ok(tx),
+            Err(e) => Err(error::Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn commit(tx: Transaction<'static, Postgres>) -> Result<(), error::Error> {
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(e) => Err(error::Error::new_internal(&e.to_string()))
+        }
+    }
+
     fn handle_update_result(res: PgQueryResult) -> Result<(), error::Error> {
         if res.rows_affected() == 0 {
             return Err(error::Error::new_not_found(USER_NOT_FOUND, "user"));
@@ -38,11 +61,24 @@ impl PostgresRepository {
         Ok(()) 
     }
 
-    fn handle_delete_result(res: PgQueryResult) -> Result<(), error::Error> {
-        if res.rows_affected() == 0 {
-            return Err(error::Error::new_not_found(USER_NOT_FOUND, "user"));
+    /// A cpf is only unique within a store, so a tenant reaching several stores may match more than one customer.
+    fn get_single_user_row(mut rows: Vec<PgRow>) -> Result<PgRow, Error> {
+        match rows.len() {
+            0 => Err(Error::new_not_found(USER_NOT_FOUND, "user")),
+            1 => Ok(rows.remove(0)),
+            _ => Err(Error::new_business_with_message(USER_STORE_REQUIRED_ERROR, "document registered in more than one store"))
         }
-        Ok(()) 
+    }
+
+    fn get_users_from_pg_rows(rows: Vec<PgRow>) -> Result<Vec<User>, Error> {
+        let mut users = Vec::with_capacity(rows.len());
+        for row in rows {
+            match PostgresRepository::get_user_from_pg_row(row) {
+                Ok(u) => users.push(u),
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        }
+        Ok(users)
     }
 
     fn get_user_from_pg_row(row: PgRow) -> Result<User, sqlx::Error> {
@@ -50,9 +86,12 @@ impl PostgresRepository {
         let name: String = row.try_get("name")?;
         let document: &str = row.try_get("document")?;
         let status: &str = row.try_get("status")?;
+        let password: String = row.try_get("password")?;
         let birth_date: NaiveDate = row.try_get("birth_date")?;
+        let store_id: Option<Uuid> = row.try_get("store_id")?;
         let db_created_at: NaiveDateTime = row.try_get("created_at")?;
         let db_updated_at: NaiveDateTime  = row.try_get("updated_at")?;
+        let db_deleted_at: Option<NaiveDateTime> = row.try_get("deleted_at")?;
 
 
         let cpf = match CPF::from_string(String::from(document)) {
@@ -63,8 +102,11 @@ impl PostgresRepository {
         let mut user = User::new(name, cpf, BirthDate::from_naive(birth_date));
         user.set_uuid(id.to_string());
         user.set_status(UserStatus::from_string(status));
+        user.set_password(password);
+        user.set_store_id(store_id.map(|id| id.to_string()));
         user.set_created_at(db_created_at.and_utc());
         user.set_updated_at(db_updated_at.and_utc());
+        user.set_deleted_at(db_deleted_at.map(|d| d.and_utc()));
         Ok(user)
     }
 
@@ -72,13 +114,20 @@ impl PostgresRepository {
 
 #[async_trait]
 impl Repository for PostgresRepository {
-    async fn create(&self, user: User) -> Result<(), error::Error> {
+    async fn create(&self, tenant: &Tenant, user: User) -> Result<(), error::Error> {
         
         let user_id = match Uuid::from_str(user.get_id()) {
             Ok(id) => id,
             Err(err) => return Err(error::Error::new_internal(err.to_string().as_str()))
         };
-        
+
+        let store_id = match user.get_store_id().map(Uuid::from_str) {
+            None => None,
+            Some(Ok(id)) => Some(id),
+            Some(Err(err)) => return Err(error::Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = self.begin(tenant).await?;
         let result: Result<sqlx::postgres::PgQueryResult, sqlx::Error> = sqlx::query(
             // language=PostgreSQL
             r#"
@@ -89,9 +138,10 @@ impl Repository for PostgresRepository {
                     status,
                     "password",
                     birth_date,
+                    store_id,
                     created_at,
                     updated_at
-                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         "#)
         .bind(user_id)
         .bind(user.get_name())
@@ -99,50 +149,77 @@ impl Repository for PostgresRepository {
         .bind(user.get_status().to_sring())
         .bind(user.get_password())
         .bind(user.get_birth_date().to_naive_date())
+        .bind(store_id)
         .bind(user.get_created_at())
         .bind(user.get_updated_at())
-        .execute(&self.pool).await;
+        .execute(&mut *tx).await;
 
         match result{
-            Ok(_) => Ok(()),
+            Ok(_) => Self::commit(tx).await,
             Err(err) => return Err(Self::handle_postgres_error(err))
         }
     }
 
-    async fn update(&self, user: User) -> Result<(), error::Error> {
+    async fn update(&self, tenant: &Tenant, user: User) -> Result<(), error::Error> {
         let id =  match Uuid::from_str(user.get_id()) {
             Ok(uuid) => uuid,
             Err(err) => return Err(error::Error::new_internal(&err.to_string()))
         };
 
+        let mut tx = self.begin(tenant).await?;
         let result = sqlx::query(
             r#"
                 UPDATE "user" SET
                     name = $1,
                     document = $2,
-                    status = $3,
-                    "password" = $4,
-                    birth_date  = $5,
-                    updated_at = $6
+                    birth_date  = $3,
+                    updated_at = $4
                 WHERE
-                    id = $7
+                    id = $5
+                    AND deleted_at IS NULL
             "#
         ).bind(user.get_name())
         .bind(user.get_document().to_string())
-        .bind(user.get_status().to_sring())
-        .bind(user.get_password())
         .bind(user.get_birth_date().to_naive_date())
-        .bind(user.get_created_at())
+        .bind(user.get_updated_at())
         .bind(id)
-        .execute(&self.pool).await;
+        .execute(&mut *tx).await;
 
         match result {
             Err(e) => return Err(Self::handle_postgres_error(e)),
-            Ok(r) => return Self::handle_update_result(r)
+            Ok(r) => Self::handle_update_result(r)?
         };
+        Self::commit(tx).await
     }
 
-    async fn get_by_cpf(&self, cpf: &str) -> Result<User, error::Error> {
+    async fn update_password(&self, tenant: &Tenant, user_id: &str, password: &str) -> Result<(), Error> {
+        let user_id = match Uuid::from_str(user_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                UPDATE "user" SET
+                    "password" = $1,
+                    updated_at = $2
+                WHERE id = $3 AND deleted_at IS NULL
+            "#
+        ).bind(password)
+        .bind(Utc::now())
+        .bind(user_id)
+        .execute(&mut *tx).await;
+
+        match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) => Self::handle_update_result(r)?
+        };
+        Self::commit(tx).await
+    }
+
+    async fn get_by_cpf(&self, tenant: &Tenant, cpf: &str) -> Result<User, error::Error> {
+        let mut tx = self.begin(tenant).await?;
         let result = sqlx::query(
             r#"
                 SELECT 
@@ -152,18 +229,21 @@ impl Repository for PostgresRepository {
                     status,
                     "password",
                     birth_date,
+                    store_id,
                     created_at,
-                    updated_at
+                    updated_at,
+                    deleted_at
                 FROM "user"
-                WHERE document = $1
+                WHERE document = $1 AND deleted_at IS NULL
+                LIMIT 2
             "#
-        ).bind(cpf).fetch_optional(&self.pool).await;
+        ).bind(cpf).fetch_all(&mut *tx).await;
 
         let row = match result {
             Err(e) => return Err(Error::new_internal(e.to_string().as_str())),
-            Ok(None) => return Err(Error::new_not_found(USER_NOT_FOUND, "user")),
-            Ok(Some(r)) => r
+            Ok(rows) => Self::get_single_user_row(rows)?
         };
+        Self::commit(tx).await?;
 
         match PostgresRepository::get_user_from_pg_row(row) {
             Ok(u) => return Ok(u),
@@ -171,21 +251,372 @@ impl Repository for PostgresRepository {
         };
     }
 
-    async fn delete_by_cpf(&self, cpf: &str) -> Result<(), Error> {
+    async fn get_by_store_and_cpf(&self, store_id: Option<String>, cpf: &str) -> Result<User, Error> {
+        // the store is named by the caller, so a malformed one is just an unknown store
+        let (tenant, store_id) = match store_id.map(|id| (Uuid::from_str(&id), id)) {
+            None => (Tenant::Platform, None),
+            Some((Ok(uuid), id)) => (Tenant::Store(id), Some(uuid)),
+            Some((Err(_), _)) => return Err(Error::new_not_found(USER_NOT_FOUND, "user"))
+        };
+
+        let mut tx = self.begin(&tenant).await?;
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    name,
+                    document,
+                    status,
+                    "password",
+                    birth_date,
+                    store_id,
+                    created_at,
+                    updated_at,
+                    deleted_at
+                FROM "user"
+                WHERE
+                    document = $1
+                    AND store_id IS NOT DISTINCT FROM $2
+                    AND deleted_at IS NULL
+            "#
+        ).bind(cpf)
+        .bind(store_id)
+        .fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(USER_NOT_FOUND, "user")),
+            Ok(Some(r)) => r
+        };
+        Self::commit(tx).await?;
+
+        match PostgresRepository::get_user_from_pg_row(row) {
+            Ok(u) => Ok(u),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn get_by_id(&self, tenant: &Tenant, id: &str) -> Result<User, Error> {
+        let id = match Uuid::from_str(id) {
+            Ok(id) => id,
+            Err(_) => return Err(Error::new_not_found(USER_NOT_FOUND, "user"))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    name,
+                    document,
+                    status,
+                    "password",
+                    birth_date,
+                    store_id,
+                    created_at,
+                    updated_at,
+                    deleted_at
+                FROM "user"
+                WHERE id = $1 AND deleted_at IS NULL
+            "#
+        ).bind(id).fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(None) => return Err(Error::new_not_found(USER_NOT_FOUND, "user")),
+            Ok(Some(r)) => r
+        };
+        Self::commit(tx).await?;
+
+        match PostgresRepository::get_user_from_pg_row(row) {
+            Ok(u) => Ok(u),
+            Err(e) => Err(Error::new_internal(&e.to_string()))
+        }
+    }
+
+    async fn delete_by_cpf(&self, tenant: &Tenant, cpf: &str) -> Result<(), Error> {
+        let now = Utc::now();
+        let mut tx = self.begin(tenant).await?;
         let query = r#"
-            DELETE FROM "user"
-            WHERE document = $1 
+            UPDATE "user" SET
+                status_before_delete = status,
+                status = $1,
+                updated_at = $2,
+                deleted_at = $2
+            WHERE document = $3 AND deleted_at IS NULL
+            RETURNING id
         "#;
         let result = sqlx::query(query)
-            .bind(cpf).execute(&self.pool).await;
+            .bind(UserStatus::Deleted.to_sring())
+            .bind(now.naive_utc())
+            .bind(cpf).fetch_all(&mut *tx).await;
+
+        // deleting customers of several stores at once is rolled back with the transaction
+        match result {
+            Err(e) => return Err(Error::new_internal(e.to_string().as_str())),
+            Ok(rows) => Self::get_single_user_row(rows)?
+        };
+        Self::commit(tx).await
+    }
+
+    async fn restore_by_cpf(&self, tenant: &Tenant, cpf: &str, change_id: &str, changed_by: &str) -> Result<(), Error> {
+        let change_id = match Uuid::from_str(change_id) {
+            Ok(id) => id,
+            Err(err) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let now = Utc::now().naive_utc();
+        let mut tx = self.begin(tenant).await?;
+
+        // the same cpf may have been deleted more than once, the latest row of its store comes back
+        let result = sqlx::query(
+            r#"
+                SELECT DISTINCT ON (store_id) id
+                FROM "user"
+                WHERE document = $1 AND deleted_at IS NOT NULL
+                ORDER BY store_id, deleted_at DESC
+                LIMIT 2
+            "#
+        ).bind(cpf)
+        .fetch_all(&mut *tx).await;
+
+        let id: Uuid = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(rows) => match Self::get_single_user_row(rows)?.try_get("id") {
+                Ok(id) => id,
+                Err(e) => return Err(Error::new_internal(&e.to_string()))
+            }
+        };
+
+        let result = sqlx::query(
+            r#"
+                UPDATE "user" SET
+                    status = COALESCE(status_before_delete, $1),
+                    status_before_delete = NULL,
+                    updated_at = $2,
+                    deleted_at = NULL
+                WHERE id = $3
+                RETURNING id, status
+            "#
+        ).bind(UserStatus::Active.to_sring())
+        .bind(now)
+        .bind(id)
+        .fetch_optional(&mut *tx).await;
+
+        let row = match result {
+            Err(e) => return Err(Self::handle_postgres_error(e)),
+            Ok(None) => return Err(Error::new_not_found(USER_NOT_FOUND, "user")),
+            Ok(Some(r)) => r
+        };
+
+        let (user_id, status): (Uuid, String) = match (row.try_get("id"), row.try_get("status")) {
+            (Ok(id), Ok(status)) => (id, status),
+            (Err(e), _) | (_, Err(e)) => return Err(Error::new_internal(&e.to_string()))
+        };
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO user_status_change (
+                    id,
+                    user_id,
+                    from_status,
+                    to_status,
+                    reason,
+                    note,
+                    changed_by,
+                    changed_at
+                ) VALUES ($1, $2, $3, $4, $5, NULL, $6, $7)
+            "#
+        ).bind(change_id)
+        .bind(user_id)
+        .bind(UserStatus::Deleted.to_sring())
+        .bind(status)
+        .bind(StatusChangeReason::Restored.to_string())
+        .bind(changed_by)
+        .bind(now)
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
+        }
+        Self::commit(tx).await
+    }
+
+    async fn list(&self, tenant: &Tenant, filter: UserFilter) -> Result<Vec<User>, Error> {
+        let (after_id, after_name, after_created_at) = match &filter.after {
+            None => (None, None, None),
+            Some(cursor) => match Uuid::from_str(&cursor.id) {
+                Ok(id) => (Some(id), Some(cursor.name.as_str()), Some(cursor.created_at.naive_utc())),
+                Err(_) => return Err(Error::new_business(INVALID_CURSOR_ERROR))
+            }
+        };
+
+        // the order comes from a closed set, every value sent by the caller is bound
+        let (order, after) = match filter.sort {
+            UserSort::CreatedAtAsc => ("created_at ASC, id ASC", "(created_at, id) > ($9, $7)"),
+            UserSort::CreatedAtDesc => ("created_at DESC, id DESC", "(created_at, id) < ($9, $7)"),
+            UserSort::NameAsc => ("name ASC, id ASC", "(name, id) > ($8, $7)"),
+            UserSort::NameDesc => ("name DESC, id DESC", "(name, id) < ($8, $7)")
+        };
+
+        let query = format!(r#"
+            SELECT
+                id,
+                name,
+                document,
+                status,
+                "password",
+                birth_date,
+                store_id,
+                created_at,
+                updated_at,
+                deleted_at
+            FROM "user"
+            WHERE
+                ($1::varchar IS NULL OR status = $1)
+                -- deleted customers are only listed when asked for by status
+                AND (CASE WHEN $1::varchar = 'DELETED' THEN deleted_at IS NOT NULL ELSE deleted_at IS NULL END)
+                AND ($2::varchar IS NULL OR lower(name) LIKE (lower($2) || '%'))
+                AND ($3::date IS NULL OR birth_date >= $3)
+                AND ($4::date IS NULL OR birth_date <= $4)
+                AND ($5::timestamp IS NULL OR created_at >= $5)
+                AND ($6::timestamp IS NULL OR created_at <= $6)
+                AND ($7::uuid IS NULL OR {after})
+            ORDER BY {order}
+            LIMIT $10
+        "#);
+
+        let mut tx = self.begin(tenant).await?;
+        let result = sqlx::query(&query)
+            .bind(filter.status.map(|s| s.to_sring()))
+            .bind(filter.name_prefix.as_deref().map(escape_like))
+            .bind(filter.birth_date_from)
+            .bind(filter.birth_date_to)
+            .bind(filter.created_from.map(|d| d.naive_utc()))
+            .bind(filter.created_to.map(|d| d.naive_utc()))
+            .bind(after_id)
+            .bind(after_name)
+            .bind(after_created_at)
+            .bind(filter.limit as i64)
+            .fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) => r
+        };
+        Self::commit(tx).await?;
+        Self::get_users_from_pg_rows(rows)
+    }
+
+    async fn search(&self, tenant: &Tenant, name: &str, limit: u32) -> Result<Vec<User>, Error> {
+        let mut tx = self.begin(tenant).await?;
+
+        // word similarity finds the name inside longer queries such as "dona maria do mercado"
+        let result = sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true)")
+            .bind(SEARCH_SIMILARITY_THRESHOLD)
+            .execute(&mut *tx).await;
 
         if let Err(e) = result {
-            return Err(Error::new_internal(e.to_string().as_str()));
-        } 
+            return Err(Error::new_internal(&e.to_string()));
+        }
+
+        let result = sqlx::query(
+            r#"
+                SELECT
+                    id,
+                    name,
+                    document,
+                    status,
+                    "password",
+                    birth_date,
+                    store_id,
+                    created_at,
+                    updated_at,
+                    deleted_at
+                FROM "user"
+                WHERE
+                    immutable_unaccent(lower($1)) <% immutable_unaccent(lower(name))
+                    AND deleted_at IS NULL
+                ORDER BY
+                    word_similarity(immutable_unaccent(lower($1)), immutable_unaccent(lower(name))) DESC,
+                    similarity(immutable_unaccent(lower($1)), immutable_unaccent(lower(name))) DESC,
+                    name,
+                    id
+                LIMIT $2
+            "#
+        ).bind(name)
+        .bind(limit as i64)
+        .fetch_all(&mut *tx).await;
+
+        let rows = match result {
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) => r
+        };
+        Self::commit(tx).await?;
+        Self::get_users_from_pg_rows(rows)
+    }
+
+    async fn change_status(&self, tenant: &Tenant, change: UserStatusChange) -> Result<(), Error> {
+        let (id, user_id) = match (Uuid::from_str(change.get_id()), Uuid::from_str(change.get_user_id())) {
+            (Ok(id), Ok(user_id)) => (id, user_id),
+            (Err(err), _) | (_, Err(err)) => return Err(Error::new_internal(&err.to_string()))
+        };
+
+        let mut tx = self.begin(tenant).await?;
+
+        // the status guard keeps concurrent blocks from recording the same change twice
+        let result = sqlx::query(
+            r#"
+                UPDATE "user" SET
+                    status = $1,
+                    updated_at = $2
+                WHERE
+                    id = $3
+                    AND status = $4
+                    AND deleted_at IS NULL
+            "#
+        ).bind(change.get_to_status().to_sring())
+        .bind(change.get_changed_at().naive_utc())
+        .bind(user_id)
+        .bind(change.get_from_status().to_sring())
+        .execute(&mut *tx).await;
 
         match result {
-            Err(e) => Err(Error::new_internal(e.to_string().as_str())),
-            Ok(r) => Self::handle_delete_result(r)
+            Err(e) => return Err(Error::new_internal(&e.to_string())),
+            Ok(r) => Self::handle_update_result(r)?
+        };
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO user_status_change (
+                    id,
+                    user_id,
+                    from_status,
+                    to_status,
+                    reason,
+                    note,
+                    changed_by,
+                    changed_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#
+        ).bind(id)
+        .bind(user_id)
+        .bind(change.get_from_status().to_sring())
+        .bind(change.get_to_status().to_sring())
+        .bind(change.get_reason().to_string())
+        .bind(change.get_note())
+        .bind(change.get_changed_by())
+        .bind(change.get_changed_at().naive_utc())
+        .execute(&mut *tx).await;
+
+        if let Err(e) = result {
+            return Err(Error::new_internal(&e.to_string()));
         }
+        Self::commit(tx).await
     }
 }
+
+/// Wildcards typed by the caller are matched literally.
+fn escape_like(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
\ No newline at end of file
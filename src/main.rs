@@ -1,16 +1,29 @@
-use fiadors::app::{http, container::Container};
+use fiadors::app::{http, jobs, container::Container};
+use std::process::ExitCode;
+use std::sync::Arc;
 use tokio::net::TcpListener;
 
 #[tokio::main]
-async fn main() {
-    let container = Container::load_dependencies().await;
+async fn main() -> ExitCode {
+    let container = match Container::load_dependencies().await {
+        Ok(c) => Arc::new(c),
+        Err(e) => {
+            eprintln!("invalid config: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
 
     sqlx::migrate!("./migrations")
     .run(&container.pg_pool.clone())
     .await.unwrap();
 
+    tokio::spawn(jobs::accrual::run(container.clone()));
+    tokio::spawn(jobs::statement::run(container.clone()));
+
     let app = http::build_app(container);
 
     let listener = TcpListener::bind("0.0.0.0:8888").await.unwrap();
     axum::serve(listener, app).await.unwrap();
-}
\ No newline at end of file
+
+    ExitCode::SUCCESS
+}